@@ -0,0 +1,105 @@
+/*
+--check compares the answers against a file of known answers, to catch a refactor that breaks a solution.
+
+The answers file has one answer per line: the day, the part and the answer, separated by spaces.
+Blank lines and lines starting with # are skipped. Multi line answers can be written with \n.
+
+day1 1 1715
+day1 2 1739
+*/
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use advent2021::answer::Answer;
+use crate::color;
+use crate::report::DayResult;
+
+pub type Answers = HashMap<(String, u8), String>;
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String },
+    Unknown
+}
+
+pub fn read_answers(path: &Path) -> Result<Answers, String> {
+    let input = fs::read_to_string(path).map_err(|e| format!("can't read {}: {}", path.display(), e))?;
+    parse_answers(&input).map_err(|message| format!("{}: {}", path.display(), message))
+}
+
+fn parse_answers(input: &str) -> Result<Answers, String> {
+    let mut answers = HashMap::new();
+    for (number, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.splitn(3, ' ');
+        let (Some(day), Some(part), Some(answer)) = (fields.next(), fields.next(), fields.next()) else {
+            return Err(format!("line {}: expected a day, a part and an answer", number + 1));
+        };
+        let part = part.parse().map_err(|_| format!("line {}: '{}' is not a part number", number + 1, part))?;
+        answers.insert((day.to_string(), part), answer.trim().replace("\\n", "\n"));
+    }
+    Ok(answers)
+}
+
+// Answers are compared as they're printed
+pub fn check_part(answers: &Answers, day: &str, part: u8, answer: &Answer) -> Outcome {
+    match answers.get(&(day.to_string(), part)) {
+        None => Outcome::Unknown,
+        Some(expected) if *expected == answer.to_string() => Outcome::Pass,
+        Some(expected) => Outcome::Fail { expected: expected.clone() }
+    }
+}
+
+// A line for every part that ran, and false if any answer was wrong
+pub fn check_lines(results: &[DayResult], answers: &Answers) -> (Vec<String>, bool) {
+    let mut lines = Vec::new();
+    let mut all_passed = true;
+    for result in results {
+        for (part, part_result) in result.parts() {
+            let label = format!("{} part {}", result.day, part);
+            let status = match check_part(answers, &result.day, part, &part_result.answer) {
+                Outcome::Pass => color::answer("pass"),
+                Outcome::Unknown => color::timing("no expected answer"),
+                Outcome::Fail { expected } => {
+                    all_passed = false;
+                    color::failure(&format!("FAIL expected {} got {}", expected, part_result.answer))
+                }
+            };
+            lines.push(format!("{:<15} {}", label, status));
+        }
+    }
+    (lines, all_passed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent2021::grid::Grid;
+
+    #[test]
+    fn test_parse_answers() {
+        let input = "# comment
+
+            day1 1 1715
+            day13 2 # \\n #
+            day24 1 92928914999991";
+        let answers = parse_answers(input).unwrap();
+        assert_eq!(3, answers.len());
+        assert_eq!(Outcome::Pass, check_part(&answers, "day1", 1, &Answer::Int(1715)));
+        let letters = Grid::from_rows(vec![vec![true, false], vec![false, true]]);
+        assert_eq!(Outcome::Pass, check_part(&answers, "day13", 2, &Answer::Grid(letters)));
+        assert_eq!(Outcome::Fail { expected: "1715".to_string() }, check_part(&answers, "day1", 1, &Answer::Int(1716)));
+        assert_eq!(Outcome::Unknown, check_part(&answers, "day1", 2, &Answer::Int(1739)));
+    }
+
+    #[test]
+    fn test_bad_answers() {
+        assert!(parse_answers("day1 1").is_err());
+        assert!(parse_answers("day1 one 1715").is_err());
+    }
+}
@@ -0,0 +1,397 @@
+/*
+Command line interface definition (clap).
+
+advent run day1 day15       run the selected days
+advent day5-day12,day20     same as run, days can be ranges and comma separated lists
+advent 2021 day5            days from a particular year (the default is 2021)
+advent all --year 2021      --year picks the year for commands that don't take days
+advent all                  run every day in order, then print a summary of the answers
+advent bench day5           run the selected days several times and report the average
+advent list                 show every day that can be run
+advent pick                 choose days to run from an interactive list
+advent fetch day5           download the puzzle input using the session token, see fetch.rs
+advent doctor               check every day's input can be read and parsed
+advent new-day 26           create solutions/src/year2021/day26 from a template and register it
+advent all --parallel       run the days on separate threads, output is still in day order
+advent all --check          compare the answers with answers.txt, see check.rs
+advent all --quiet          only the answers, one line per part, for scripts
+advent serve                answer POST /solve/{day}/{part} requests over HTTP, see serve.rs
+
+Input paths and default flags can also be set in advent.toml, see config.rs.
+
+Use --format csv (or tsv) with run or all to print one row per day and part instead,
+or --format markdown for a table to paste into a write-up.
+
+Commands that run solutions take --input to read a different puzzle input:
+advent run day15 --input my-grid.txt
+advent run day12 --example   use the sample input from the puzzle description
+advent all --input day1=a.txt --input day2=b.txt
+
+and --viz to save a picture of a day that draws one (day5, day8, day9, day13, day20), as .svg, .ppm or .txt:
+advent run day13 --viz letters.svg
+
+and --series to save the numbers behind a day's answer over time (day6's population on every day), as .csv or .json:
+advent run day6 --series fish.csv
+
+--animate draws the simulation in the terminal as it runs (day11, day25), 10 frames a second
+unless a rate is given. It's ignored with --parallel and the machine readable formats:
+advent run day25 --animate 30
+*/
+
+use std::path::PathBuf;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use serde::Deserialize;
+use advent2021::{series, solution, viz};
+
+#[derive(Parser)]
+#[command(name = "advent", version, about = "Advent of Code solutions")]
+#[command(args_conflicts_with_subcommands = true, arg_required_else_help = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// Days to run when no command is given, for example: day5-day12,day20 or 2021 day5
+    #[arg(value_parser = parse_selection)]
+    pub days: Vec<Selection>,
+    #[command(flatten)]
+    pub options: RunOptions,
+    #[command(flatten)]
+    pub output: OutputOptions,
+    /// Don't color the output (also turned off by the NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+    /// Settings file to read [default: advent.toml, if there is one]
+    #[arg(long, value_name = "PATH", global = true)]
+    pub config: Option<PathBuf>,
+    /// Print diagnostic output to stderr, -vv for more detail
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+    /// Which year's solutions to use [default: 2021]
+    #[arg(long, global = true, value_parser = parse_year)]
+    pub year: Option<u32>
+}
+
+impl Cli {
+    // With no command, the days on the command line are run just like `advent run`
+    pub fn into_command(self) -> Command {
+        self.command.unwrap_or(Command::Run { days: self.days, options: self.options, output: self.output })
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run the solutions for one or more days
+    Run {
+        /// Days to run, for example: day1 day15 or day5-day12,day20 or 2021 day5
+        #[arg(required = true, value_parser = parse_selection)]
+        days: Vec<Selection>,
+        #[command(flatten)]
+        options: RunOptions,
+        #[command(flatten)]
+        output: OutputOptions
+    },
+    /// Run every day in order and print a summary of all the answers
+    All {
+        #[command(flatten)]
+        options: RunOptions,
+        #[command(flatten)]
+        output: OutputOptions
+    },
+    /// Run each part several times and report the timings
+    Bench {
+        /// Days to benchmark, for example: day5 day6 or day5-day8
+        #[arg(required = true, value_parser = parse_selection)]
+        days: Vec<Selection>,
+        /// How many times to run each part
+        #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        #[command(flatten)]
+        options: RunOptions
+    },
+    /// List the days that can be run
+    List,
+    /// Pick days to run from an interactive list
+    Pick {
+        #[command(flatten)]
+        options: RunOptions
+    },
+    /// Download puzzle inputs from adventofcode.com into inputs/
+    Fetch {
+        /// Days to download, for example: day5 or day1-day25
+        #[arg(required = true, value_parser = parse_selection)]
+        days: Vec<Selection>,
+        /// Download again even if the input was already downloaded
+        #[arg(short, long)]
+        force: bool
+    },
+    /// Check that every day's input file exists and parses, without solving anything
+    Doctor,
+    /// Start an HTTP server that solves the input POSTed to /solve/{day}/{part}
+    Serve {
+        /// Address to listen on, use 0.0.0.0 to accept requests from other machines
+        #[arg(short, long, default_value = "127.0.0.1:8021")]
+        address: String
+    },
+    /// Create the files for a new day and register it in its year (run from the repository root)
+    NewDay {
+        /// The day number, for example: 26
+        #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+        day: u32,
+        /// The puzzle name
+        #[arg(short, long, default_value = "Untitled")]
+        title: String
+    }
+}
+
+// Options shared by the commands that run solutions
+#[derive(Args)]
+pub struct RunOptions {
+    /// Only run one part of each day (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+    /// Read the puzzle input from a file instead of the bundled one.
+    /// Use DAY=PATH to pick the day when running more than one
+    #[arg(long, value_name = "[DAY=]PATH", value_parser = parse_input_override)]
+    pub input: Vec<InputOverride>,
+    /// Use the sample input from the puzzle description (solutions/src/yearYYYY/dayN/example.txt) instead of the real one
+    #[arg(long)]
+    pub example: bool,
+    /// Save a picture of the day to PATH after solving, as an svg or ppm image or as text (going by the extension)
+    #[arg(long, value_name = "PATH", value_parser = parse_viz_path)]
+    pub viz: Option<PathBuf>,
+    /// Save how the day's numbers change over time to PATH after solving, as csv or json (going by the extension)
+    #[arg(long, value_name = "PATH", value_parser = parse_series_path)]
+    pub series: Option<PathBuf>,
+    /// Animate the days that simulate something (day11, day25) in the terminal, at FPS frames a second
+    #[arg(long, value_name = "FPS", num_args = 0..=1, default_missing_value = "10", value_parser = clap::value_parser!(u32).range(1..))]
+    pub animate: Option<u32>
+}
+
+// How run and all work through the days and print the results
+#[derive(Args)]
+pub struct OutputOptions {
+    /// Output format: text prints each day as it finishes, the others print all the results at the end [default: text]
+    #[arg(long, value_enum)]
+    pub format: Option<Format>,
+    /// Only print the answers, one line per part, with no labels or timings
+    #[arg(short, long, conflicts_with = "format")]
+    pub quiet: bool,
+    /// Run the days at the same time on separate threads. Results are still printed in day order
+    #[arg(long)]
+    pub parallel: bool,
+    /// Compare the answers against a file of known answers [default: answers.txt]
+    #[arg(long, value_name = "FILE", num_args = 0..=1, default_missing_value = "answers.txt")]
+    pub check: Option<PathBuf>
+}
+
+impl OutputOptions {
+    pub fn format(&self) -> Format {
+        self.format.unwrap_or(Format::Text)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Text,
+    Csv,
+    Tsv,
+    Markdown
+}
+
+// An --input value, optionally for a single day
+#[derive(Clone)]
+pub struct InputOverride {
+    pub day: Option<String>,
+    pub path: String
+}
+
+impl RunOptions {
+    // The file to read for this day: a DAY=PATH override, then a plain PATH,
+    // then the default (the config file or the bundled input)
+    pub fn input_path<'a>(&'a self, day: &str, default: &'a str) -> &'a str {
+        let for_day = self.input.iter().find(|input| input.day.as_deref() == Some(day));
+        let for_any = self.input.iter().find(|input| input.day.is_none());
+        for_day.or(for_any).map_or(default, |input| &input.path)
+    }
+
+    // A plain PATH only makes sense when there is a single day to give it to
+    pub fn validate_inputs(&self, day_count: usize) -> Result<(), String> {
+        let plain = self.input.iter().filter(|input| input.day.is_none()).count();
+        if plain > 1 || (plain == 1 && day_count != 1) {
+            return Err("--input without a day only works when running one day, use --input DAY=PATH".to_string());
+        }
+        if self.viz.is_some() && day_count != 1 {
+            return Err("--viz only works when running one day".to_string());
+        }
+        if self.series.is_some() && day_count != 1 {
+            return Err("--series only works when running one day".to_string());
+        }
+        Ok(())
+    }
+}
+
+// true if the given part should run - both parts run when --part is not specified
+pub fn runs_part(selected: Option<u8>, part: u8) -> bool {
+    selected.is_none_or(|selected| selected == part)
+}
+
+// "day15=grid.txt" applies to day15 only, a bare path applies to whichever day is being run.
+// Anything before the '=' that isn't a day name is treated as part of the path
+fn parse_input_override(value: &str) -> Result<InputOverride, String> {
+    match value.split_once('=') {
+        Some((day, path)) if parse_day(day).is_ok() => {
+            Ok(InputOverride { day: Some(day.to_string()), path: path.to_string() })
+        }
+        _ => Ok(InputOverride { day: None, path: value.to_string() })
+    }
+}
+
+fn parse_viz_path(value: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(value);
+    match viz::Format::from_path(&path) {
+        Some(_) => Ok(path),
+        None => Err(format!("can't save a picture as '{}', use a .svg, .ppm or .txt file", value))
+    }
+}
+
+fn parse_series_path(value: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(value);
+    match series::Format::from_path(&path) {
+        Some(_) => Ok(path),
+        None => Err(format!("can't save a series as '{}', use a .csv or .json file", value))
+    }
+}
+
+// One selection from the command line: a year, or days. Days can be a single day, a range
+// like "day5-day12", or a comma separated list of either. Ranges include both ends
+#[derive(Clone, Debug, PartialEq)]
+pub enum Selection {
+    Year(u32),
+    Days(Vec<String>)
+}
+
+fn parse_selection(value: &str) -> Result<Selection, String> {
+    if value.chars().all(|c| c.is_ascii_digit()) {
+        return parse_year(value).map(Selection::Year);
+    }
+    parse_days(value).map(Selection::Days)
+}
+
+fn parse_year(value: &str) -> Result<u32, String> {
+    match value.parse() {
+        Ok(year) if solution::YEARS.contains(&year) => Ok(year),
+        _ => Err(format!("no solutions for '{}', the years are: {:?}", value, solution::YEARS))
+    }
+}
+
+fn parse_days(value: &str) -> Result<Vec<String>, String> {
+    let mut days = Vec::new();
+    for selection in value.split(',') {
+        match selection.split_once('-') {
+            Some((first, last)) => {
+                let start = day_number(first)?;
+                let end = day_number(last)?;
+                if start > end {
+                    return Err(format!("range '{}' is backwards, try {}-{}", selection, last, first));
+                }
+                days.extend((start..=end).map(|n| format!("day{}", n)));
+            }
+            None => days.push(parse_day(selection)?)
+        }
+    }
+    Ok(days)
+}
+
+// Combine the selections into a year and a list of days, in the order given, skipping repeats.
+// Every day has to be in that year's registry
+pub fn selected_days(selections: Vec<Selection>, year: Option<u32>) -> Result<(u32, Vec<String>), String> {
+    let mut years: Vec<u32> = year.into_iter().collect();
+    let mut days: Vec<String> = Vec::new();
+    for selection in selections {
+        match selection {
+            Selection::Year(year) => years.push(year),
+            Selection::Days(selected) => {
+                for day in selected {
+                    if !days.contains(&day) {
+                        days.push(day);
+                    }
+                }
+            }
+        }
+    }
+    years.dedup();
+    let year = match years[..] {
+        [] => solution::DEFAULT_YEAR,
+        [year] => year,
+        _ => return Err(format!("pick one year at a time, not {:?}", years))
+    };
+    let registry = solution::registry(year).unwrap();
+    if let Some(missing) = days.iter().find(|day| !registry.iter().any(|(name, _)| name == day)) {
+        return Err(format!("{} has no {} (try `advent list --year {}`)", year, missing, year));
+    }
+    if days.is_empty() {
+        return Err("no days selected".to_string());
+    }
+    Ok((year, days))
+}
+
+// Days are written "day" and the day number, like day15
+fn parse_day(day: &str) -> Result<String, String> {
+    day_number(day).map(|n| format!("day{}", n))
+}
+
+fn day_number(day: &str) -> Result<u32, String> {
+    day.strip_prefix("day")
+        .and_then(|n| n.parse().ok())
+        .filter(|&n| n > 0)
+        .ok_or_else(|| format!("'{}' isn't a day, days look like day15", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_ranges() {
+        assert_eq!(vec!["day5", "day6", "day7", "day20"], parse_days("day5-day7,day20").unwrap());
+        assert_eq!(vec!["day25"], parse_days("day25-day25").unwrap());
+        assert!(parse_days("day12-day5").is_err());
+        assert!(parse_days("day5,").is_err());
+        assert!(parse_days("day0").is_err());
+        assert!(parse_days("five").is_err());
+    }
+
+    #[test]
+    fn test_selected_days() {
+        let selections = vec![parse_selection("day3-day5").unwrap(), parse_selection("day1,day4").unwrap()];
+        assert_eq!((2021, vec!["day3".to_string(), "day4".to_string(), "day5".to_string(), "day1".to_string()]),
+            selected_days(selections, None).unwrap());
+    }
+
+    #[test]
+    fn test_select_year() {
+        assert_eq!(Selection::Year(2021), parse_selection("2021").unwrap());
+        assert!(parse_selection("1999").is_err());
+
+        let selections = vec![parse_selection("2021").unwrap(), parse_selection("day5").unwrap()];
+        assert_eq!((2021, vec!["day5".to_string()]), selected_days(selections, None).unwrap());
+        // every day is checked against the year's registry
+        assert!(selected_days(vec![parse_selection("day5-day26").unwrap()], Some(2021)).is_err());
+        assert!(selected_days(vec![parse_selection("2021").unwrap()], None).is_err());
+    }
+
+    #[test]
+    fn test_viz_path() {
+        assert_eq!(PathBuf::from("out/basins.svg"), parse_viz_path("out/basins.svg").unwrap());
+        assert!(parse_viz_path("letters.ppm").is_ok());
+        assert!(parse_viz_path("letters.png").is_err());
+        assert!(parse_viz_path("letters").is_err());
+    }
+
+    #[test]
+    fn test_series_path() {
+        assert_eq!(PathBuf::from("out/fish.csv"), parse_series_path("out/fish.csv").unwrap());
+        assert!(parse_series_path("fish.json").is_ok());
+        assert!(parse_series_path("fish.svg").is_err());
+    }
+}
@@ -0,0 +1,46 @@
+/*
+Terminal colors for the text output: answers stand out, timings fade into the background
+and failures are red.
+
+Colors are turned off with --no-color, when the NO_COLOR environment variable is set,
+or when stdout isn't a terminal (piping to a file or another program).
+*/
+
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+const BOLD_GREEN: &str = "\x1b[1;32m";
+const DIM: &str = "\x1b[2m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+// see https://no-color.org - any non empty value turns color off
+pub fn init(no_color_flag: bool) {
+    let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    ENABLED.store(!no_color_flag && !no_color_env && std::io::stdout().is_terminal(), Ordering::Relaxed);
+}
+
+pub fn answer(text: &str) -> String {
+    paint(BOLD_GREEN, text)
+}
+
+pub fn timing(text: &str) -> String {
+    paint(DIM, text)
+}
+
+pub fn failure(text: &str) -> String {
+    paint(BOLD_RED, text)
+}
+
+// Multi line text is painted line by line so each line of day 13's letters keeps its color
+fn paint(color: &str, text: &str) -> String {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return text.to_string();
+    }
+    text.lines()
+        .map(|line| format!("{}{}{}", color, line, RESET))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
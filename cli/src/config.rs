@@ -0,0 +1,147 @@
+/*
+Settings from an advent.toml file, read at startup so inputs can live anywhere.
+Anything given on the command line wins over the config file.
+
+session_file = "~/.config/advent/session"   # where the adventofcode.com session token is kept
+
+[defaults]
+format = "markdown"     # text, csv, tsv or markdown
+parallel = true
+
+[inputs]
+day1 = "inputs/day1.txt"
+day15 = "/home/me/aoc/2021/15.txt"
+"2021/day16" = "inputs/packets.txt"     # days can be given with their year, plain days are 2021
+
+Relative input paths are relative to the directory the config file is in.
+*/
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use crate::cli::{Format, OutputOptions};
+use advent2021::solution;
+
+pub const DEFAULT_PATH: &str = "advent.toml";
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session_file: Option<PathBuf>,
+    #[serde(default)]
+    pub defaults: Defaults,
+    #[serde(default)]
+    inputs: HashMap<String, PathBuf>
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Defaults {
+    pub format: Option<Format>,
+    #[serde(default)]
+    pub parallel: bool
+}
+
+impl Config {
+    // With no --config, a missing advent.toml just means there is nothing configured
+    pub fn load(path: Option<&Path>) -> Result<Config, String> {
+        let (path, required) = match path {
+            Some(path) => (path, true),
+            None => (Path::new(DEFAULT_PATH), false)
+        };
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) if !required => return Ok(Config::default()),
+            Err(e) => return Err(format!("can't read config file {}: {}", path.display(), e))
+        };
+        let mut config = Config::parse(&contents)
+            .map_err(|e| format!("invalid config file {}: {}", path.display(), e))?;
+        config.resolve_inputs(path.parent().unwrap_or(Path::new("")));
+        for day in config.inputs.keys().filter(|day| !is_day(day)) {
+            log::warn!("{}: [inputs] has an entry for {}, which isn't a day", path.display(), day);
+        }
+        log::info!("config: {} ({} inputs, session token in {})",
+            path.display(),
+            config.inputs.len(),
+            config.session_file.as_ref().map_or("(not set)".to_string(), |file| file.display().to_string())
+        );
+        Ok(config)
+    }
+
+    fn parse(contents: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    fn resolve_inputs(&mut self, dir: &Path) {
+        for path in self.inputs.values_mut() {
+            if path.is_relative() {
+                *path = dir.join(&*path);
+            }
+        }
+    }
+
+    // The input file configured for a day, if there is one
+    pub fn input(&self, year: u32, day: &str) -> Option<&str> {
+        let path = self.inputs.get(&format!("{}/{}", year, day))
+            .or_else(|| self.inputs.get(day).filter(|_| year == solution::DEFAULT_YEAR));
+        path.and_then(|path| path.to_str())
+    }
+
+    // Fill in the output settings that weren't given on the command line
+    pub fn apply_defaults(&self, output: &mut OutputOptions) {
+        output.format = output.format.or(self.defaults.format);
+        output.parallel |= self.defaults.parallel;
+    }
+}
+
+// An [inputs] key, either "day5" or "2021/day5"
+fn is_day(key: &str) -> bool {
+    let (year, day) = match key.split_once('/') {
+        Some((year, day)) => (year.parse().ok(), day),
+        None => (Some(solution::DEFAULT_YEAR), key)
+    };
+    year.and_then(solution::registry).is_some_and(|registry| registry.iter().any(|(name, _)| *name == day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = "session_file = \"session.txt\"
+
+            [defaults]
+            format = \"csv\"
+            parallel = true
+
+            [inputs]
+            day1 = \"inputs/day1.txt\"
+            day15 = \"/aoc/15.txt\"
+            \"2021/day15\" = \"/aoc/2021/15.txt\"";
+        let mut config = Config::parse(config).unwrap();
+        config.resolve_inputs(Path::new("config"));
+        assert_eq!(Some(PathBuf::from("session.txt")), config.session_file);
+        assert!(config.defaults.format == Some(Format::Csv));
+        assert!(config.defaults.parallel);
+        assert_eq!(Some("config/inputs/day1.txt"), config.input(2021, "day1"));
+        assert_eq!(Some("/aoc/2021/15.txt"), config.input(2021, "day15"));
+        assert_eq!(None, config.input(2021, "day2"));
+        assert_eq!(None, config.input(2020, "day1"));
+        assert!(is_day("2021/day25") && !is_day("day26") && !is_day("2020/day1"));
+    }
+
+    #[test]
+    fn test_command_line_wins() {
+        let config = Config::parse("[defaults]\nformat = \"markdown\"").unwrap();
+        let mut output = OutputOptions { format: Some(Format::Csv), quiet: false, parallel: false, check: None };
+        config.apply_defaults(&mut output);
+        assert!(output.format == Some(Format::Csv));
+
+        let mut output = OutputOptions { format: None, quiet: false, parallel: false, check: None };
+        config.apply_defaults(&mut output);
+        assert!(output.format == Some(Format::Markdown));
+        assert!(Config::parse("[defaults]\nformat = \"xml\"").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+/*
+`advent fetch day5` downloads the puzzle input from adventofcode.com and saves it to inputs/2021/day5.txt.
+Once a day's input is in inputs/, the runner uses it instead of the one bundled in solutions/src/.
+
+Inputs are different for everyone, so downloading needs the session cookie from a logged in browser.
+It is read from the AOC_SESSION environment variable, or the session_file in advent.toml.
+*/
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::config::Config;
+
+const CACHE_DIR: &str = "inputs";
+// adventofcode.com asks automated tools to identify themselves
+const USER_AGENT: &str = "github.com/jacobhyphenated/advent2021 fetch command";
+
+// The downloaded input for a day, if it has been fetched
+pub fn cached_input(year: u32, day: &str) -> Option<String> {
+    let path = cache_path(year, day);
+    path.is_file().then(|| path.to_string_lossy().into_owned())
+}
+
+fn cache_path(year: u32, day: &str) -> PathBuf {
+    Path::new(CACHE_DIR).join(year.to_string()).join(format!("{}.txt", day))
+}
+
+// Download a day's input, unless it's already been downloaded (or force is set)
+pub fn fetch(year: u32, day: &str, config: &Config, force: bool) -> Result<(), String> {
+    let path = cache_path(year, day);
+    if path.is_file() && !force {
+        println!("{}: already downloaded to {}", day, path.display());
+        return Ok(());
+    }
+    let session = session_token(config)?;
+    let url = input_url(year, day)?;
+    log::info!("{}: downloading {}", day, url);
+    let input = ureq::get(&url)
+        .header("Cookie", &format!("session={}", session))
+        .header("User-Agent", USER_AGENT)
+        .call()
+        .and_then(|mut response| response.body_mut().read_to_string())
+        .map_err(|e| download_error(&url, e))?;
+    let dir = path.parent().unwrap();
+    fs::create_dir_all(dir).map_err(|e| format!("can't create {}: {}", dir.display(), e))?;
+    fs::write(&path, input).map_err(|e| format!("can't write {}: {}", path.display(), e))?;
+    println!("{}: saved to {}", day, path.display());
+    Ok(())
+}
+
+// adventofcode.com answers a bad or expired session token with a 400
+fn download_error(url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::StatusCode(400) => format!("{} rejected the session token, it may have expired", url),
+        ureq::Error::StatusCode(404) => format!("{} not found, has the puzzle unlocked yet?", url),
+        e => format!("download from {} failed: {}", url, e)
+    }
+}
+
+fn input_url(year: u32, day: &str) -> Result<String, String> {
+    let number: u32 = day.trim_start_matches("day").parse()
+        .map_err(|_| format!("can't tell which puzzle {} is", day))?;
+    Ok(format!("https://adventofcode.com/{}/day/{}/input", year, number))
+}
+
+// AOC_SESSION wins over the config file, so a token can be tried out without editing anything
+fn session_token(config: &Config) -> Result<String, String> {
+    if let Ok(token) = env::var("AOC_SESSION") {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+    let file = config.session_file.as_ref()
+        .ok_or("no session token: set AOC_SESSION or session_file in advent.toml")?;
+    let file = expand_home(file);
+    let token = fs::read_to_string(&file)
+        .map_err(|e| format!("can't read the session token from {}: {}", file.display(), e))?;
+    Ok(token.trim().to_string())
+}
+
+// session_file = "~/.config/advent/session" is the natural way to write it, but ~ is a shell thing
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => Path::new(&home).join(rest),
+        _ => path.to_path_buf()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_url() {
+        assert_eq!("https://adventofcode.com/2021/day/5/input", input_url(2021, "day5").unwrap());
+        assert_eq!("https://adventofcode.com/2021/day/25/input", input_url(2021, "day25").unwrap());
+        assert!(input_url(2021, "dayfive").is_err());
+    }
+
+    #[test]
+    fn test_expand_home() {
+        let home = PathBuf::from(env::var_os("HOME").unwrap());
+        assert_eq!(home.join(".config/advent/session"), expand_home(Path::new("~/.config/advent/session")));
+        assert_eq!(PathBuf::from("session.txt"), expand_home(Path::new("session.txt")));
+    }
+}
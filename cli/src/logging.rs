@@ -0,0 +1,44 @@
+/*
+Diagnostic logging for the solutions, off unless asked for with -v.
+
+-v shows what the runner is doing (which input file each day reads),
+-vv adds debug output from inside the solutions (solutions found while searching, step counts).
+Everything goes to stderr so the answers on stdout stay clean.
+*/
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use crate::color;
+
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let line = format!("[{} {}] {}", record.level(), record.target(), record.args());
+            match record.level() {
+                Level::Error => eprintln!("{}", color::failure(&line)),
+                _ => eprintln!("{}", color::timing(&line))
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+// verbosity is the number of times -v was given
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace
+    };
+    log::set_logger(&LOGGER).expect("logger already set");
+    log::set_max_level(level);
+}
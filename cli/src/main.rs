@@ -0,0 +1,390 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::Instant;
+
+use clap::{CommandFactory, Parser};
+use check::Answers;
+use cli::{Cli, Command, Format, OutputOptions, RunOptions, Selection};
+use config::Config;
+use report::{DayResult, PartResult};
+use timing::elapsed_ms;
+use advent2021::{animate, progress, series, solution, viz};
+use advent2021::answer::Answer;
+use advent2021::error::AdventError;
+use advent2021::solution::Solution;
+
+mod check;
+mod cli;
+mod color;
+mod config;
+mod fetch;
+mod logging;
+mod report;
+mod scaffold;
+mod serve;
+mod timing;
+mod tui;
+
+fn main() {
+    let cli = Cli::parse();
+    color::init(cli.no_color);
+    logging::init(cli.verbose);
+    set_panic_hook();
+    let config = Config::load(cli.config.as_deref()).unwrap_or_else(|message| {
+        Cli::command().error(clap::error::ErrorKind::Io, message).exit()
+    });
+    // --year is checked by the CLI parser. Commands that take days can also give the year with them
+    let year_flag = cli.year;
+    let year = year_flag.unwrap_or(solution::DEFAULT_YEAR);
+    let mut registry = solution::registry(year).unwrap();
+    match cli.into_command() {
+        Command::Run { days, options, mut output } => {
+            config.apply_defaults(&mut output);
+            let (year, days) = select(days, year_flag);
+            let mut registry = solution::registry(year).unwrap();
+            check_inputs(&options, days.len());
+            let answers = read_answers(&output);
+            let mut selected: Vec<_> = registry.iter_mut()
+                .filter(|(name, _)| days.iter().any(|day| day == name))
+                .collect();
+            selected.sort_by_key(|(name, _)| days.iter().position(|day| day == name));
+            let results = run_days(year, selected, &options, &output, &config);
+            finish(results, &output, answers.as_ref(), false);
+        }
+        Command::All { options, mut output } => {
+            config.apply_defaults(&mut output);
+            check_inputs(&options, registry.len());
+            let answers = read_answers(&output);
+            let results = run_days(year, registry.iter_mut().collect(), &options, &output, &config);
+            finish(results, &output, answers.as_ref(), true);
+        }
+        Command::Bench { days, iterations, options } => {
+            let (year, days) = select(days, year_flag);
+            let mut registry = solution::registry(year).unwrap();
+            check_inputs(&options, days.len());
+            let mut failed = false;
+            for day in days {
+                if let Err(message) = bench(year, &day, find(&mut registry, &day), iterations, &options, &config) {
+                    report::print_failure(&day, &message);
+                    failed = true;
+                }
+            }
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::List => {
+            for (name, solution) in registry {
+                println!("{:<6} {}", name, solution.title());
+            }
+        }
+        Command::Pick { options } => {
+            check_inputs(&options, registry.len());
+            let days: Vec<_> = registry.iter().map(|(name, solution)| (*name, solution.title())).collect();
+            let outcome = tui::pick(&days, |index| {
+                let (name, solution) = &mut registry[index];
+                run(year, name, solution.as_mut(), &options, &config)
+            });
+            if let Err(e) = outcome {
+                eprintln!("{}", color::failure(&format!("error: {}", e)));
+            }
+        }
+        Command::Fetch { days, force } => {
+            let mut failed = false;
+            let (year, days) = select(days, year_flag);
+            for day in days {
+                if let Err(message) = fetch::fetch(year, &day, &config, force) {
+                    report::print_failure(&day, &message);
+                    failed = true;
+                }
+            }
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Doctor => {
+            if !doctor(year, &mut registry, &config) {
+                process::exit(1);
+            }
+        }
+        Command::Serve { address } => {
+            if let Err(message) = serve::serve(year, &address) {
+                eprintln!("{}", color::failure(&format!("error: {}", message)));
+                process::exit(1);
+            }
+        }
+        Command::NewDay { day, title } => {
+            if let Err(message) = scaffold::new_day(year, day, &title) {
+                eprintln!("{}", color::failure(&format!("error: {}", message)));
+                process::exit(1);
+            }
+        }
+    }
+}
+
+// Print the results of the days that worked, then exit with an error if any didn't,
+// or if --check found a wrong answer
+fn finish(results: Vec<Result<DayResult, String>>, output: &OutputOptions, answers: Option<&Answers>, summary: bool) {
+    let total = results.len();
+    let results: Vec<DayResult> = results.into_iter().filter_map(Result::ok).collect();
+    print_results(&results, output, summary);
+    let passed = answers.is_none_or(|answers| print_check(&results, answers, output));
+    if results.len() < total {
+        eprintln!("{}", color::failure(&format!("{} of {} days failed", total - results.len(), total)));
+    }
+    if results.len() < total || !passed {
+        process::exit(1);
+    }
+}
+
+// The check goes to stderr for the machine readable formats and --quiet, to keep their output clean
+fn print_check(results: &[DayResult], answers: &Answers, output: &OutputOptions) -> bool {
+    let (lines, passed) = check::check_lines(results, answers);
+    if output.format() == Format::Text && !output.quiet {
+        println!();
+        lines.iter().for_each(|line| println!("{}", line));
+    } else {
+        lines.iter().for_each(|line| eprintln!("{}", line));
+    }
+    passed
+}
+
+// Read the --check answers before running anything, so a bad answers file doesn't waste a full run
+fn read_answers(output: &OutputOptions) -> Option<Answers> {
+    output.check.as_ref().map(|path| check::read_answers(path).unwrap_or_else(|message| {
+        Cli::command().error(clap::error::ErrorKind::Io, message).exit()
+    }))
+}
+
+// Text output has already printed each day as it ran, so it finishes with the summary tables.
+// The other formats print everything at the end - and --quiet has nothing more to print
+fn print_results(results: &[DayResult], output: &OutputOptions, summary: bool) {
+    if output.quiet {
+        return;
+    }
+    match output.format() {
+        Format::Text => {
+            if summary {
+                report::print_summary(results);
+            }
+            report::print_timings(results);
+        }
+        Format::Csv => report::print_delimited(results, ','),
+        Format::Tsv => report::print_delimited(results, '\t'),
+        Format::Markdown => report::print_markdown(results)
+    }
+}
+
+thread_local! {
+    // Set while a day is running, the runner reports those panics itself
+    static IN_DAY: Cell<bool> = const { Cell::new(false) };
+}
+
+// Anything else that panics is reported in red, along with where it happened
+fn set_panic_hook() {
+    panic::set_hook(Box::new(|info| {
+        if IN_DAY.get() {
+            return;
+        }
+        let message = panic_message(info.payload());
+        let location = info.location().map_or(String::new(), |location| format!(" ({})", location));
+        eprintln!("{}{}", color::failure(&format!("error: {}", message)), location);
+    }));
+}
+
+// A day that panics (bad input it can't parse, for example) becomes an error for that day
+// so the rest of the days can still run
+fn catch_day<T>(run: impl FnOnce() -> T) -> Result<T, String> {
+    IN_DAY.set(true);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(run));
+    IN_DAY.set(false);
+    result.map_err(|payload| panic_message(payload.as_ref()))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown error".to_string())
+}
+
+// The year and days to run, exits with a usage error for days the year doesn't have
+fn select(days: Vec<Selection>, year: Option<u32>) -> (u32, Vec<String>) {
+    cli::selected_days(days, year).unwrap_or_else(|message| {
+        Cli::command().error(clap::error::ErrorKind::InvalidValue, message).exit()
+    })
+}
+
+// Exits with a usage error if the --input or --viz flags don't fit the days being run
+fn check_inputs(options: &RunOptions, day_count: usize) {
+    if let Err(message) = options.validate_inputs(day_count) {
+        Cli::command().error(clap::error::ErrorKind::ArgumentConflict, message).exit();
+    }
+}
+
+// Day names are validated by the CLI parser, so the lookup can't miss
+fn find<'a>(registry: &'a mut [(&'static str, Box<dyn Solution>)], day: &str) -> &'a mut dyn Solution {
+    registry.iter_mut()
+        .find(|(name, _)| *name == day)
+        .map(|(_, solution)| solution.as_mut())
+        .unwrap()
+}
+
+// Run each day in order, or all at once on separate threads with --parallel.
+// Text output is printed as each day finishes - in parallel, a day waits for the ones before it
+// so the output stays in order
+fn run_days(
+    year: u32,
+    days: Vec<&mut (&'static str, Box<dyn Solution>)>,
+    options: &RunOptions,
+    output: &OutputOptions,
+    config: &Config
+) -> Vec<Result<DayResult, String>> {
+    // errors go to stderr whatever the format, so a csv file doesn't get them mixed in
+    let print = |result: Result<DayResult, String>, name: &str| {
+        match &result {
+            Ok(day) if output.quiet => report::print_answers(day),
+            Ok(day) if output.format() == Format::Text => report::print_day(day),
+            Ok(_) => (),
+            Err(message) => report::print_failure(name, message)
+        }
+        result
+    };
+    if !output.parallel {
+        // Progress bars would draw over each other with several days running at once,
+        // and over an animation
+        let text = output.format() == Format::Text && !output.quiet;
+        progress::set_enabled(text && options.animate.is_none());
+        animate::set_frame_rate(options.animate.filter(|_| text).unwrap_or(0));
+        return days.into_iter()
+            .map(|(name, solution)| print(run(year, name, solution.as_mut(), options, config), name))
+            .collect();
+    }
+    thread::scope(|scope| {
+        let handles: Vec<_> = days.into_iter()
+            .map(|(name, solution)| (*name, scope.spawn(move || run(year, name, solution.as_mut(), options, config))))
+            .collect();
+        handles.into_iter()
+            // run catches panics from the day, so joining only fails if the runner itself is broken
+            .map(|(name, handle)| print(handle.join().unwrap_or_else(|error| panic::resume_unwind(error)), name))
+            .collect()
+    })
+}
+
+// --input on the command line, then --example, then the config file, then a downloaded input,
+// then the input bundled in solutions/src/
+fn input_path(year: u32, name: &str, solution: &dyn Solution, options: &RunOptions, config: &Config) -> String {
+    let default = if options.example {
+        solution.example_input()
+    } else {
+        configured_input(year, name, solution, config)
+    };
+    options.input_path(name, &default).to_string()
+}
+
+fn configured_input(year: u32, name: &str, solution: &dyn Solution, config: &Config) -> String {
+    config.input(year, name).map(|path| path.to_string())
+        .or_else(|| fetch::cached_input(year, name))
+        .unwrap_or_else(|| solution.default_input().to_string())
+}
+
+// Parse the input, then run and time each selected part.
+// A missing or invalid input is reported as an error before anything is solved
+fn run(year: u32, name: &str, solution: &mut dyn Solution, options: &RunOptions, config: &Config) -> Result<DayResult, String> {
+    let path = input_path(year, name, solution, options, config);
+    catch_day(|| {
+        let start = Instant::now();
+        log::info!("{}: reading input from {}", name, path);
+        let (read_ms, parse_ms) = timing::parse_input(solution, &path)?;
+
+        let part1 = cli::runs_part(options.part, 1).then(|| run_part(|| solution.part1()));
+        let part2 = cli::runs_part(options.part, 2).then(|| run_part(|| solution.part2()));
+        if let Some(viz_path) = &options.viz {
+            save_picture(name, solution, viz_path)?;
+        }
+        if let Some(series_path) = &options.series {
+            save_series(name, solution, series_path)?;
+        }
+
+        Ok(DayResult { day: name.to_string(), read_ms, parse_ms, part1, part2, elapsed_ms: elapsed_ms(start) })
+    })?.map_err(|error: AdventError| error.to_string())
+}
+
+fn save_picture(name: &str, solution: &dyn Solution, path: &Path) -> Result<(), AdventError> {
+    match solution.render() {
+        Some(picture) => {
+            viz::save(picture.as_ref(), path)?;
+            log::info!("{}: saved a picture to {}", name, path.display());
+        }
+        None => log::warn!("{} doesn't draw a picture, nothing saved to {}", name, path.display())
+    }
+    Ok(())
+}
+
+fn save_series(name: &str, solution: &dyn Solution, path: &Path) -> Result<(), AdventError> {
+    match solution.series() {
+        Some(numbers) => {
+            series::save(&numbers, path)?;
+            log::info!("{}: saved a series to {}", name, path.display());
+        }
+        None => log::warn!("{} doesn't have a series, nothing saved to {}", name, path.display())
+    }
+    Ok(())
+}
+
+fn run_part(solve: impl FnOnce() -> Answer) -> PartResult {
+    let now = Instant::now();
+    let answer = solve();
+    PartResult { answer, elapsed_ms: elapsed_ms(now) }
+}
+
+// Parse once, then run each part repeatedly and report the average and best times
+fn bench(year: u32, name: &str, solution: &mut dyn Solution, iterations: u32, options: &RunOptions, config: &Config) -> Result<(), String> {
+    let path = input_path(year, name, solution, options, config);
+    catch_day(|| bench_parts(name, solution, &path, iterations, options))?.map_err(|error| error.to_string())
+}
+
+fn bench_parts(name: &str, solution: &mut dyn Solution, path: &str, iterations: u32, options: &RunOptions) -> Result<(), AdventError> {
+    solution.parse(path)?;
+    println!("{} ({} iterations)", name, iterations);
+    let parts: [(u8, &dyn Fn() -> Answer); 2] = [(1, &|| solution.part1()), (2, &|| solution.part2())];
+    for (part, run_part) in parts {
+        if !cli::runs_part(options.part, part) {
+            continue;
+        }
+        let mut times = Vec::new();
+        for _ in 0..iterations {
+            let now = Instant::now();
+            run_part();
+            times.push(elapsed_ms(now));
+        }
+        let average = times.iter().sum::<f64>() / times.len() as f64;
+        let best = times.iter().cloned().fold(f64::MAX, f64::min);
+        println!("Part {}: average {:.3}ms, best {:.3}ms", part, average, best);
+    }
+    Ok(())
+}
+
+// Parse every day's input and report which days are ready to run. Returns false if any aren't
+fn doctor(year: u32, registry: &mut [(&'static str, Box<dyn Solution>)], config: &Config) -> bool {
+    let mut ready = 0;
+    for (name, solution) in registry.iter_mut() {
+        let path = configured_input(year, name, solution.as_ref(), config);
+        match catch_day(|| solution.parse(&path)) {
+            Ok(Ok(())) => {
+                ready += 1;
+                println!("{:<6} {} {}", name, color::answer("ok     "), path);
+            }
+            Ok(Err(error @ AdventError::MissingInput { .. })) => println!("{:<6} {} {}", name, color::failure("missing"), error),
+            Ok(Err(error)) => println!("{:<6} {} {}: {}", name, color::failure("broken "), path, error),
+            Err(message) => println!("{:<6} {} {}: {}", name, color::failure("broken "), path, message)
+        }
+    }
+    println!();
+    println!("{} of {} days are ready to run", ready, registry.len());
+    ready == registry.len()
+}
+
@@ -0,0 +1,232 @@
+/*
+Results collected from running each day.
+The runner only collects results, everything printed about a run comes from here.
+*/
+
+use advent2021::answer::Answer;
+use crate::color;
+
+pub struct DayResult {
+    pub day: String,
+    // Reading the input file, then turning it into the day's input
+    pub read_ms: f64,
+    pub parse_ms: f64,
+    // None when the part was skipped with --part
+    pub part1: Option<PartResult>,
+    pub part2: Option<PartResult>,
+    pub elapsed_ms: f64
+}
+
+pub struct PartResult {
+    pub answer: Answer,
+    pub elapsed_ms: f64
+}
+
+impl DayResult {
+    // The parts that were run, with their part number
+    pub fn parts(&self) -> impl Iterator<Item = (u8, &PartResult)> {
+        [(1, &self.part1), (2, &self.part2)].into_iter()
+            .filter_map(|(part, result)| result.as_ref().map(|result| (part, result)))
+    }
+}
+
+// The full output for one day: answers and how long each step took
+pub fn print_day(result: &DayResult) {
+    println!("{}", result.day);
+    println!("{}", color::timing(&format!("Read input in {}ms", result.read_ms)));
+    println!("{}", color::timing(&format!("Parsed input in {}ms", result.parse_ms)));
+    for (part, part_result) in result.parts() {
+        print_answer(part, &part_result.answer);
+        println!("{}", color::timing(&format!("Part {} in {}ms", part, part_result.elapsed_ms)));
+    }
+}
+
+// Just the answers for --quiet, one per line in part order.
+// A multi line answer is printed as it is, so it takes up more than one line
+pub fn print_answers(result: &DayResult) {
+    for (_, part_result) in result.parts() {
+        println!("{}", part_result.answer);
+    }
+}
+
+// Failures go to stderr, so they show up even when the results are redirected to a file
+pub fn print_failure(day: &str, message: &str) {
+    eprintln!("{}", color::failure(&format!("{}: {}", day, message)));
+}
+
+// Multi line answers (like day 13 part 2) start on their own line
+fn print_answer(part: u8, answer: &Answer) {
+    if answer.is_multiline() {
+        println!("Part {}:\n{}", part, color::answer(&answer.to_string()));
+    } else {
+        println!("Part {}: {}", part, color::answer(&answer.to_string()));
+    }
+}
+
+// Print one row per day with both answers.
+// Multi line answers (day 13) don't fit in a table, those were printed in full above
+pub fn print_summary(results: &[DayResult]) {
+    println!();
+    println!("{:<6} {:<20} {:<20}", "Day", "Part 1", "Part 2");
+    for result in results {
+        println!("{:<6} {} {}",
+            result.day,
+            color::answer(&format!("{:<20}", summary_answer(result.part1.as_ref()))),
+            color::answer(&format!("{:<20}", summary_answer(result.part2.as_ref())))
+        );
+    }
+}
+
+// Where the time went: read, parse and part timings for each day, the share of the whole run, and a total row
+// Nothing is printed if every day failed, there's no time to show
+pub fn print_timings(results: &[DayResult]) {
+    if results.is_empty() {
+        return;
+    }
+    let total: f64 = results.iter().map(|result| result.elapsed_ms).sum();
+    println!();
+    println!("{:<6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>6}", "Day", "Read", "Parse", "Part 1", "Part 2", "Total", "Share");
+    for result in results {
+        println!("{:<6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>5.1}%",
+            result.day,
+            format_ms(Some(result.read_ms)),
+            format_ms(Some(result.parse_ms)),
+            format_ms(result.part1.as_ref().map(|part| part.elapsed_ms)),
+            format_ms(result.part2.as_ref().map(|part| part.elapsed_ms)),
+            format_ms(Some(result.elapsed_ms)),
+            share(result.elapsed_ms, total)
+        );
+    }
+    println!("{:<6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>5.1}%",
+        "Total",
+        format_ms(Some(results.iter().map(|result| result.read_ms).sum())),
+        format_ms(Some(results.iter().map(|result| result.parse_ms).sum())),
+        format_ms(part_total(results, 1)),
+        format_ms(part_total(results, 2)),
+        format_ms(Some(total)),
+        share(total, total)
+    );
+}
+
+fn format_ms(ms: Option<f64>) -> String {
+    ms.map_or("-".to_string(), |ms| format!("{:.3}ms", ms))
+}
+
+// None if the part was skipped for every day
+fn part_total(results: &[DayResult], part: u8) -> Option<f64> {
+    results.iter()
+        .flat_map(|result| result.parts())
+        .filter(|(n, _)| *n == part)
+        .map(|(_, part_result)| part_result.elapsed_ms)
+        .reduce(|a, b| a + b)
+}
+
+fn share(ms: f64, total: f64) -> f64 {
+    if total > 0.0 { ms / total * 100.0 } else { 0.0 }
+}
+
+fn summary_answer(result: Option<&PartResult>) -> String {
+    match result {
+        None => "-".to_string(),
+        Some(result) if result.answer.is_multiline() => "(see above)".to_string(),
+        Some(result) => result.answer.to_string()
+    }
+}
+
+// One row per day and part, for spreadsheets. The delimiter is ',' for csv or '\t' for tsv
+pub fn print_delimited(results: &[DayResult], delimiter: char) {
+    println!("{}", ["day", "part", "answer", "time_ms"].join(&delimiter.to_string()));
+    for result in results {
+        for (part, part_result) in result.parts() {
+            let row = [
+                result.day.clone(),
+                part.to_string(),
+                delimited_field(&part_result.answer.to_string(), delimiter),
+                format!("{:.3}", part_result.elapsed_ms)
+            ];
+            println!("{}", row.join(&delimiter.to_string()));
+        }
+    }
+}
+
+// CSV quotes any field that would break the row, doubling quotes inside it.
+// TSV has no quoting, so line breaks and tabs are escaped instead
+fn delimited_field(answer: &str, delimiter: char) -> String {
+    if delimiter == '\t' {
+        answer.replace('\t', "\\t").replace('\n', "\\n")
+    } else if answer.contains([delimiter, '"', '\n']) {
+        format!("\"{}\"", answer.replace('"', "\"\""))
+    } else {
+        answer.to_string()
+    }
+}
+
+// A table with one row per day, ready to paste into a write-up.
+// Multi line answers can't go in a table cell, so they are listed in code blocks after it
+pub fn print_markdown(results: &[DayResult]) {
+    println!("| Day | Part 1 | Part 2 | Time |");
+    println!("| --- | --- | --- | ---: |");
+    for result in results {
+        println!("| {} | {} | {} | {:.3}ms |",
+            result.day,
+            markdown_answer(result, result.part1.as_ref(), 1),
+            markdown_answer(result, result.part2.as_ref(), 2),
+            result.elapsed_ms
+        );
+    }
+    for result in results {
+        for (part, part_result) in result.parts().filter(|(_, part_result)| part_result.answer.is_multiline()) {
+            println!();
+            println!("**{} part {}**", result.day, part);
+            println!("```");
+            println!("{}", part_result.answer);
+            println!("```");
+        }
+    }
+}
+
+fn markdown_answer(day: &DayResult, result: Option<&PartResult>, part: u8) -> String {
+    match result {
+        None => "-".to_string(),
+        Some(result) if result.answer.is_multiline() => format!("see {} part {} below", day.day, part),
+        Some(result) => result.answer.to_string().replace('|', "\\|")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!("1715", delimited_field("1715", ','));
+        assert_eq!("\"#  #\n ## \"", delimited_field("#  #\n ## ", ','));
+        assert_eq!("\"a,\"\"b\"\"\"", delimited_field("a,\"b\"", ','));
+    }
+
+    #[test]
+    fn test_tsv_field() {
+        assert_eq!("#  #\\n ## ", delimited_field("#  #\n ## ", '\t'));
+        assert_eq!("a,b", delimited_field("a,b", '\t'));
+    }
+
+    #[test]
+    fn test_markdown_answer() {
+        let part = |answer: &str| Some(PartResult { answer: Answer::Text(answer.to_string()), elapsed_ms: 1.0 });
+        let result = DayResult { day: "day13".to_string(), read_ms: 0.0, parse_ms: 0.0, part1: part("a|b"), part2: part("#\n#"), elapsed_ms: 1.0 };
+        assert_eq!("a\\|b", markdown_answer(&result, result.part1.as_ref(), 1));
+        assert_eq!("see day13 part 2 below", markdown_answer(&result, result.part2.as_ref(), 2));
+        assert_eq!("-", markdown_answer(&result, None, 1));
+    }
+
+    #[test]
+    fn test_part_total() {
+        let part = |ms: f64| Some(PartResult { answer: Answer::Int(0), elapsed_ms: ms });
+        let results = vec![
+            DayResult { day: "day1".to_string(), read_ms: 1.0, parse_ms: 1.0, part1: part(2.0), part2: None, elapsed_ms: 3.0 },
+            DayResult { day: "day2".to_string(), read_ms: 1.0, parse_ms: 1.0, part1: part(4.0), part2: None, elapsed_ms: 5.0 }
+        ];
+        assert_eq!(Some(6.0), part_total(&results, 1));
+        assert_eq!(None, part_total(&results, 2));
+    }
+}
@@ -0,0 +1,95 @@
+/*
+`advent new-day 26` sets up everything a new day needs:
+solutions/src/year2021/day26/mod.rs from template.txt, empty input.txt and example.txt files next to it for the
+puzzle input and the sample from the puzzle description,
+and `pub mod day26;` plus a registry entry in solutions/src/year2021/mod.rs.
+Use --year to add the day to a different year, that year's module has to exist already.
+
+Run it from the root of the repository.
+*/
+
+use std::fs;
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("template.txt");
+
+pub fn new_day(year: u32, day: u32, title: &str) -> Result<(), String> {
+    let year_rs = format!("solutions/src/year{}/mod.rs", year);
+    let dir = format!("solutions/src/year{}/day{}", year, day);
+    if Path::new(&dir).exists() {
+        return Err(format!("{} already exists", dir));
+    }
+    // Work out the edits before writing anything, so a failure doesn't leave half a day behind
+    let contents = read(&year_rs)?;
+    let contents = register_module(&contents, day).ok_or_else(|| format!("can't find the day modules in {}", year_rs))?;
+    let contents = register_solution(&contents, day).ok_or_else(|| format!("can't find the registry in {}", year_rs))?;
+
+    fs::create_dir(&dir).map_err(|e| format!("can't create {}: {}", dir, e))?;
+    write(&format!("{}/mod.rs", dir), &day_module(year, day, title))?;
+    write(&format!("{}/input.txt", dir), "")?;
+    write(&format!("{}/example.txt", dir), "")?;
+    write(&year_rs, &contents)?;
+    println!("Created {0}/mod.rs, {0}/input.txt and {0}/example.txt, and registered day{1}", dir, day);
+    Ok(())
+}
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("can't read {} (run new-day from the repository root): {}", path, e))
+}
+
+fn write(path: &str, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("can't write {}: {}", path, e))
+}
+
+fn day_module(year: u32, day: u32, title: &str) -> String {
+    // title_str is the title inside a string literal, so quotes need escaping there
+    TEMPLATE.replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{title}", title)
+        .replace("{title_str}", &title.replace('"', "\\\""))
+}
+
+// Add `pub mod dayN;` after the last day module
+fn register_module(year_rs: &str, day: u32) -> Option<String> {
+    insert_after_last(year_rs, |line| line.starts_with("pub mod day"), &format!("pub mod day{};", day))
+}
+
+// Add the new day to the end of the registry
+fn register_solution(year_rs: &str, day: u32) -> Option<String> {
+    let entry = format!("        (\"day{0}\", Box::<Solved<day{0}::Day{0}>>::default()),", day);
+    insert_after_last(year_rs, |line| line.trim_start().starts_with("(\"day"), &entry)
+}
+
+fn insert_after_last(source: &str, matches: impl Fn(&str) -> bool, new_line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let last = lines.iter().rposition(|line| matches(line))?;
+    lines.insert(last + 1, new_line);
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let year_rs = "use crate::solution::Registry;\npub mod day1;\npub mod day2;\n\npub fn registry()";
+        assert_eq!("use crate::solution::Registry;\npub mod day1;\npub mod day2;\npub mod day3;\n\npub fn registry()\n",
+            register_module(year_rs, 3).unwrap());
+
+        let year_rs = "    vec![\n        (\"day1\", Box::<Solved<day1::Day1>>::default()),\n    ]\n";
+        let expected = "    vec![\n        (\"day1\", Box::<Solved<day1::Day1>>::default()),\n        (\"day2\", Box::<Solved<day2::Day2>>::default()),\n    ]\n";
+        assert_eq!(expected, register_solution(year_rs, 2).unwrap());
+        assert!(register_solution("fn main() {}", 2).is_none());
+    }
+
+    #[test]
+    fn test_day_module() {
+        let module = day_module(2021, 26, "Some \"Quoted\" Title");
+        assert!(module.contains("Day 26: Some \"Quoted\" Title"));
+        assert!(module.contains("\"Some \\\"Quoted\\\" Title\""));
+        assert!(module.contains("impl Solver for Day26 {"));
+        assert!(module.contains("\"solutions/src/year2021/day26/input.txt\""));
+        assert!(!module.contains("{day}"));
+    }
+}
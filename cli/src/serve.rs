@@ -0,0 +1,133 @@
+/*
+`advent serve` answers puzzles over HTTP, so the solutions can be used without installing Rust.
+
+POST the raw puzzle input to /solve/{day}/{part}, where day is a number or a name like day5:
+
+    curl --data-binary @input.txt http://127.0.0.1:8021/solve/5/1
+    {"day":"day5","part":1,"answer":7269,"parse_ms":1.2,"solve_ms":3.4}
+
+A request that can't be answered gets {"error": "..."} back, with 404 for a day or part
+that doesn't exist, 400 for input the day can't parse, and 500 if the solver panicked.
+Each request is solved on its own thread, so a slow day doesn't hold up the others.
+*/
+
+use std::io::Read;
+use std::thread;
+use std::time::Instant;
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+use advent2021::answer::Answer;
+use advent2021::error::AdventError;
+use advent2021::solution;
+use crate::catch_day;
+use crate::timing::elapsed_ms;
+
+// Far more than any puzzle input, it just stops a runaway upload
+const MAX_INPUT: u64 = 1 << 20;
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Solved {
+    day: String,
+    part: u8,
+    answer: Answer,
+    parse_ms: f64,
+    solve_ms: f64
+}
+
+#[derive(Serialize)]
+struct Failure {
+    error: String
+}
+
+pub fn serve(year: u32, address: &str) -> Result<(), String> {
+    let server = Server::http(address).map_err(|error| format!("can't listen on {}: {}", address, error))?;
+    println!("Listening on http://{}", server.server_addr());
+    for request in server.incoming_requests() {
+        thread::spawn(move || respond(year, request));
+    }
+    Ok(())
+}
+
+fn respond(year: u32, mut request: Request) {
+    let now = Instant::now();
+    let (status, body) = match handle(year, &mut request) {
+        Ok(solved) => (200, serde_json::to_string(&solved)),
+        Err((status, error)) => (status, serde_json::to_string(&Failure { error }))
+    };
+    log::info!("{} {} {} ({}ms)", request.method(), request.url(), status, elapsed_ms(now));
+    let response = Response::from_string(body.unwrap())
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+    // The client hanging up early isn't the server's problem
+    let _ = request.respond(response);
+}
+
+fn handle(year: u32, request: &mut Request) -> Result<Solved, (u16, String)> {
+    let (day, part) = route(request.url()).ok_or((404, format!("no such page {}, try POST /solve/{{day}}/{{part}}", request.url())))?;
+    if *request.method() != Method::Post {
+        return Err((405, "POST the puzzle input".to_string()));
+    }
+    let mut input = String::new();
+    let read = request.as_reader().take(MAX_INPUT + 1).read_to_string(&mut input)
+        .map_err(|error| (400, format!("can't read the input: {}", error)))?;
+    if read as u64 > MAX_INPUT {
+        return Err((413, format!("the input is over {} bytes", MAX_INPUT)));
+    }
+    solve(year, day, part, &input)
+}
+
+// The day and part from /solve/{day}/{part}. The day can be 5 or day5
+fn route(url: &str) -> Option<(String, u8)> {
+    let path = url.split('?').next().unwrap();
+    let mut segments = path.trim_matches('/').split('/');
+    if segments.next()? != "solve" {
+        return None;
+    }
+    let day = segments.next()?;
+    let part = segments.next()?.parse().ok()?;
+    if segments.next().is_some() {
+        return None;
+    }
+    let day = if day.starts_with("day") { day.to_string() } else { format!("day{}", day) };
+    Some((day, part))
+}
+
+fn solve(year: u32, day: String, part: u8, input: &str) -> Result<Solved, (u16, String)> {
+    let mut registry = solution::registry(year).unwrap();
+    let solution = registry.iter_mut()
+        .find(|(name, _)| *name == day)
+        .map(|(_, solution)| solution)
+        .filter(|_| part == 1 || part == 2)
+        .ok_or_else(|| (404, AdventError::NoSolution { year, day: day.clone(), part }.to_string()))?;
+    catch_day(|| {
+        let now = Instant::now();
+        solution.parse_input(input).map_err(|error| (400, error.to_string()))?;
+        let parse_ms = elapsed_ms(now);
+        let now = Instant::now();
+        let answer = if part == 1 { solution.part1() } else { solution.part2() };
+        Ok(Solved { day, part, answer, parse_ms, solve_ms: elapsed_ms(now) })
+    }).unwrap_or_else(|message| Err((500, message)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route() {
+        assert_eq!(Some(("day5".to_string(), 1)), route("/solve/5/1"));
+        assert_eq!(Some(("day12".to_string(), 2)), route("/solve/day12/2/?verbose"));
+        assert_eq!(None, route("/solve/5"));
+        assert_eq!(None, route("/solve/5/one"));
+        assert_eq!(None, route("/answers/5/1"));
+    }
+
+    #[test]
+    fn test_solve() {
+        let solved = solve(2021, "day1".to_string(), 1, "199\n200\n208\n210").unwrap();
+        assert_eq!(Answer::Int(3), solved.answer);
+        assert_eq!((404, "2021 has no day26 part 1".to_string()), solve(2021, "day26".to_string(), 1, "1").unwrap_err());
+        assert_eq!(404, solve(2021, "day1".to_string(), 3, "1").unwrap_err().0);
+        assert_eq!(400, solve(2021, "day1".to_string(), 1, "fish").unwrap_err().0);
+    }
+}
@@ -0,0 +1,88 @@
+/*
+Timing each phase of a day: reading the input file, parsing it, then each part.
+
+Some days parse their input as it's read (see error::stream_lines), so reading and parsing
+can't just be timed one after the other. Instead the file goes through a TimedReader,
+which adds up the time spent waiting on the file, and parsing gets the rest.
+*/
+
+use std::io::{self, BufRead, Read};
+use std::time::{Duration, Instant};
+use advent2021::error::{self, Result};
+use advent2021::solution::{self, Solution};
+
+pub fn elapsed_ms(start: Instant) -> f64 {
+    duration_ms(start.elapsed())
+}
+
+fn duration_ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+// Parse a day's input from path. Returns (read_ms, parse_ms).
+// A compiled in input (the embed-inputs feature) takes no time to read
+pub fn parse_input(solution: &mut dyn Solution, path: &str) -> Result<(f64, f64)> {
+    let start = Instant::now();
+    if let Some(input) = solution::embedded_input(path) {
+        solution.parse_input(input)?;
+        return Ok((0.0, elapsed_ms(start)));
+    }
+    let mut reader = TimedReader::new(error::open_input(path)?);
+    let opened = start.elapsed();
+    solution.read_input(&mut reader, path)?;
+    let read = opened + reader.spent;
+    Ok((duration_ms(read), duration_ms(start.elapsed().saturating_sub(read))))
+}
+
+// Counts the time spent inside the wrapped reader
+pub struct TimedReader<R> {
+    inner: R,
+    spent: Duration
+}
+
+impl<R> TimedReader<R> {
+    pub fn new(inner: R) -> TimedReader<R> {
+        TimedReader { inner, spent: Duration::ZERO }
+    }
+}
+
+impl<R: Read> Read for TimedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let now = Instant::now();
+        let read = self.inner.read(buf);
+        self.spent += now.elapsed();
+        read
+    }
+}
+
+impl<R: BufRead> BufRead for TimedReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let now = Instant::now();
+        let buffer = self.inner.fill_buf();
+        self.spent += now.elapsed();
+        buffer
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.inner.consume(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent2021::solution::registry;
+
+    #[test]
+    fn test_parse_input() {
+        let mut registry = registry(2021).unwrap();
+        let (_, day1) = &mut registry[0];
+        let (read_ms, parse_ms) = parse_input(day1.as_mut(), "../solutions/src/year2021/day1/example.txt").unwrap();
+        assert!(read_ms >= 0.0 && parse_ms >= 0.0);
+        assert_eq!("7", day1.part1().to_string());
+        assert!(parse_input(day1.as_mut(), "../solutions/src/year2021/day1/missing.txt").is_err());
+
+        let mut reader = TimedReader::new("199\n200".as_bytes());
+        assert_eq!(vec!["199", "200"], (&mut reader).lines().map(|line| line.unwrap()).collect::<Vec<_>>());
+    }
+}
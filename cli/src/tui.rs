@@ -0,0 +1,154 @@
+/*
+Interactive day picker, started with `advent pick`.
+
+The list of days is on the left, with the puzzle names. Arrow keys (or j/k) move through the list,
+enter runs the selected day and q or escape quits.
+The answers and timings for the selected day are shown on the right, and stay there when moving
+around the list so several days can be compared.
+*/
+
+use std::io;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Text};
+use ratatui::widgets::{Block, List, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use crate::report::DayResult;
+
+struct Picker<'a> {
+    // (name, title) for each day
+    days: &'a [(&'static str, &'static str)],
+    list: ListState,
+    // The outcome of running each day, an error message if it failed
+    results: Vec<Option<Result<DayResult, String>>>,
+    running: bool
+}
+
+// Takes a function that runs the day at the given index, so the picker doesn't need to know how
+pub fn pick(days: &[(&'static str, &'static str)], run: impl FnMut(usize) -> Result<DayResult, String>) -> io::Result<()> {
+    let mut picker = Picker {
+        days,
+        list: ListState::default().with_selected(Some(0)),
+        results: days.iter().map(|_| None).collect(),
+        running: false
+    };
+    let mut terminal = ratatui::init();
+    let outcome = picker.event_loop(&mut terminal, run);
+    ratatui::restore();
+    outcome
+}
+
+impl Picker<'_> {
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal, mut run: impl FnMut(usize) -> Result<DayResult, String>) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            if self.running {
+                // the "Running..." screen is up, now do the work
+                let selected = self.selected();
+                self.results[selected] = Some(run(selected));
+                self.running = false;
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+                KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+                KeyCode::Home => self.list.select_first(),
+                KeyCode::End => self.list.select_last(),
+                KeyCode::Enter => self.running = true,
+                _ => ()
+            }
+        }
+    }
+
+    // select_next can move past the end of the list until the next draw, so clamp it here
+    fn selected(&self) -> usize {
+        self.list.selected().unwrap_or(0).min(self.days.len() - 1)
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(frame.area());
+        let [left, right] = Layout::horizontal([Constraint::Length(36), Constraint::Fill(1)]).areas(main);
+        let [answers, timings] = Layout::vertical([Constraint::Fill(1), Constraint::Length(8)]).areas(right);
+
+        let items: Vec<Line> = self.days.iter().zip(&self.results)
+            .map(|((name, title), result)| {
+                let marker = match result {
+                    None => "  ",
+                    Some(Ok(_)) => "✓ ",
+                    Some(Err(_)) => "✗ "
+                };
+                Line::from(format!("{}{:<6} {}", marker, name, title))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(" Advent of Code 2021 "))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, left, &mut self.list);
+
+        let selected = self.selected();
+        let (name, title) = self.days[selected];
+        let block_title = format!(" {}: {} ", name, title);
+        let (answer_text, timing_text) = match &self.results[selected] {
+            _ if self.running => (Text::from("Running..."), Text::default()),
+            None => (Text::from("Press enter to run this day").dim(), Text::default()),
+            Some(Err(message)) => (Text::from(message.as_str()).fg(Color::Red), Text::default()),
+            Some(Ok(result)) => (answer_lines(result), timing_lines(result))
+        };
+        frame.render_widget(Paragraph::new(answer_text).block(Block::bordered().title(block_title)), answers);
+        frame.render_widget(Paragraph::new(timing_text).block(Block::bordered().title(" Timing ")), timings);
+        frame.render_widget(Line::from(" ↑/↓ select   enter run   q quit").dim(), help);
+    }
+}
+
+// Multi line answers (day 13) get their own lines under the part heading
+fn answer_lines(result: &DayResult) -> Text<'static> {
+    let mut lines = Vec::new();
+    for (part, part_result) in result.parts() {
+        let answer = part_result.answer.to_string();
+        if part_result.answer.is_multiline() {
+            lines.push(Line::from(format!("Part {}:", part)));
+            lines.extend(answer.lines().map(|line| Line::from(line.to_string()).green().bold()));
+        } else {
+            lines.push(Line::from(vec![format!("Part {}: ", part).into(), answer.green().bold()]));
+        }
+    }
+    Text::from(lines)
+}
+
+fn timing_lines(result: &DayResult) -> Text<'static> {
+    let mut lines = vec![
+        Line::from(format!("Read   {:>12.3}ms", result.read_ms)),
+        Line::from(format!("Parse  {:>12.3}ms", result.parse_ms))
+    ];
+    for (part, part_result) in result.parts() {
+        lines.push(Line::from(format!("Part {} {:>12.3}ms", part, part_result.elapsed_ms)));
+    }
+    lines.push(Line::from(format!("Total  {:>12.3}ms", result.elapsed_ms)).bold());
+    Text::from(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent2021::answer::Answer;
+    use advent2021::grid::Grid;
+    use crate::report::PartResult;
+
+    #[test]
+    fn test_answer_lines() {
+        let part = |answer: Answer| Some(PartResult { answer, elapsed_ms: 1.0 });
+        let letters = Grid::from_rows(vec![vec![true, true], vec![true, false]]);
+        let result = DayResult { day: "day13".to_string(), read_ms: 1.0, parse_ms: 1.0, part1: part(Answer::Int(710)), part2: part(Answer::Grid(letters)), elapsed_ms: 3.0 };
+        let text = answer_lines(&result);
+        let lines: Vec<String> = text.lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(vec!["Part 1: 710", "Part 2:", "##", "# "], lines);
+    }
+}
@@ -0,0 +1,123 @@
+// The advent binary from the outside: which days run, what gets printed, and the exit codes.
+// Usage errors exit with 2 (from clap), a day that fails or a wrong --check answer with 1
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{self, Stdio};
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+fn advent() -> Command {
+    let mut command = Command::cargo_bin("advent2021").unwrap();
+    // the days' input paths are from the top of the workspace
+    command.current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    command.env("NO_COLOR", "1");
+    command
+}
+
+// A file in the test's scratch directory, for answers files and the like
+fn scratch_file(name: &str, contents: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_run_days() {
+    advent().args(["run", "day1", "--quiet"])
+        .assert()
+        .success()
+        .stdout("1715\n1739\n");
+    // `run` is optional, days can be ranges and lists, and --part picks one part
+    advent().args(["2021", "day1-day2", "--part", "1", "--quiet"])
+        .assert()
+        .success()
+        .stdout("1715\n1459206\n");
+    advent().args(["run", "day1", "--example"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("day1\n").and(predicate::str::contains("Part 1: 7\n")));
+}
+
+#[test]
+fn test_formats() {
+    advent().args(["run", "day1", "day2", "--format", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("day,part,answer,time_ms\nday1,1,1715,"))
+        .stdout(predicate::str::contains("\nday2,2,1320534480,"));
+    advent().args(["run", "day1", "--format", "markdown"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("| Day | Part 1 | Part 2 | Time |\n"));
+    advent().args(["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("day1   Sonar Sweep\n").and(predicate::str::contains("day25  Sea Cucumber\n")));
+}
+
+#[test]
+fn test_usage_errors() {
+    advent().args(["run", "day26"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("2021 has no day26"));
+    advent().args(["run", "five"]).assert().code(2);
+    advent().args(["run", "day1", "--part", "3"]).assert().code(2);
+    advent().args(["run", "day1", "day2", "--input", "depths.txt"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("use --input DAY=PATH"));
+    advent().args(["run", "day1", "--viz", "depths.png"]).assert().code(2);
+    // nothing to do prints the usage
+    advent().assert().code(2).stderr(predicate::str::contains("Usage:"));
+}
+
+#[test]
+fn test_failed_day() {
+    // the other days still run, but the exit code says something went wrong
+    advent().args(["run", "day1", "day2", "--input", "day1=no-such-file.txt", "--quiet"])
+        .assert()
+        .code(1)
+        .stdout("1459206\n1320534480\n")
+        .stderr(predicate::str::contains("day1: input file not found at no-such-file.txt"))
+        .stderr(predicate::str::contains("1 of 2 days failed"));
+    let bad_input = scratch_file("cli-bad-depths.txt", "199\n2x0\n");
+    advent().args(["run", "day1", "--quiet", "--input"]).arg(&bad_input)
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("invalid day1 input on line 2"));
+}
+
+#[test]
+fn test_check() {
+    let right = scratch_file("cli-right-answers.txt", "day1 1 1715\nday1 2 1739\n");
+    advent().args(["run", "day1", "--check"]).arg(&right)
+        .assert()
+        .success();
+    let wrong = scratch_file("cli-wrong-answers.txt", "day1 1 1715\nday1 2 1000\n");
+    advent().args(["run", "day1", "--quiet", "--check"]).arg(&wrong)
+        .assert()
+        .code(1)
+        .stdout("1715\n1739\n");
+}
+
+#[test]
+fn test_serve() {
+    let mut server = process::Command::new(env!("CARGO_BIN_EXE_advent2021"))
+        .args(["serve", "--address", "127.0.0.1:0"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // the first line says which port it got
+    let mut listening = String::new();
+    BufReader::new(server.stdout.take().unwrap()).read_line(&mut listening).unwrap();
+    let url = listening.trim().strip_prefix("Listening on ").unwrap().to_string();
+    let answer = ureq::post(format!("{}/solve/1/2", url)).send("199\n200\n208\n210\n200\n207\n240\n269\n260\n263")
+        .map(|mut response| response.body_mut().read_to_string().unwrap());
+    let not_found = ureq::post(format!("{}/solve/26/1", url)).send("1");
+    server.kill().unwrap();
+    server.wait().unwrap();
+    assert!(answer.unwrap().starts_with("{\"day\":\"day1\",\"part\":2,\"answer\":5,\"parse_ms\":"));
+    assert!(matches!(not_found, Err(ureq::Error::StatusCode(404))));
+}
@@ -0,0 +1,155 @@
+/*
+Searches shared between days, so each day only has to say what its states are and how they connect.
+
+bfs walks out from a start state one step at a time, giving every state it reaches once
+along with how many steps it took to get there. It keeps the visited set and the frontier queue,
+the day just gives the states next to a state.
+flood_fill is bfs for when only the area matters: every state reachable from start
+through states that pass the fill predicate.
+
+CycleFinder spots a simulation going round in circles, using Brent's algorithm: it keeps one
+saved state, moved forward each time the number of steps since it was saved reaches a power of 2,
+and compares every new state against it. So it only holds one state at a time, however long the run.
+find_cycle uses it on an iterator of states, then replays the iterator to find where the cycle starts.
+*/
+
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+pub struct Bfs<T, F> {
+    // states that have been reached but not expanded, with their distance from the start
+    frontier: VecDeque<(T, usize)>,
+    // everything that has ever been in the frontier, so nothing is queued twice
+    visited: HashSet<T>,
+    neighbors: F
+}
+
+pub fn bfs<T, I, F>(start: T, neighbors: F) -> Bfs<T, F>
+where T: Clone + Eq + Hash, I: IntoIterator<Item = T>, F: FnMut(&T) -> I {
+    Bfs {
+        frontier: VecDeque::from([(start.clone(), 0)]),
+        visited: HashSet::from([start]),
+        neighbors
+    }
+}
+
+impl<T, I, F> Iterator for Bfs<T, F>
+where T: Clone + Eq + Hash, I: IntoIterator<Item = T>, F: FnMut(&T) -> I {
+    // Closest states first, so the distance is the fewest steps it takes
+    type Item = (T, usize);
+
+    fn next(&mut self) -> Option<(T, usize)> {
+        let (state, distance) = self.frontier.pop_front()?;
+        for next in (self.neighbors)(&state) {
+            if self.visited.insert(next.clone()) {
+                self.frontier.push_back((next, distance + 1));
+            }
+        }
+        Some((state, distance))
+    }
+}
+
+// The start is always filled, even if it wouldn't pass fill itself
+pub fn flood_fill<T, I>(start: T, mut neighbors: impl FnMut(&T) -> I, mut fill: impl FnMut(&T) -> bool) -> HashSet<T>
+where T: Clone + Eq + Hash, I: IntoIterator<Item = T> {
+    bfs(start, |state| neighbors(state).into_iter().filter(|next| fill(next)).collect::<Vec<_>>())
+        .map(|(state, _)| state)
+        .collect()
+}
+
+pub struct CycleFinder<T> {
+    saved: Option<T>,
+    // the saved state moves on once it's been this many steps since it was saved
+    power: usize,
+    since_saved: usize
+}
+
+impl<T: Clone + Eq> CycleFinder<T> {
+    pub fn new() -> CycleFinder<T> {
+        CycleFinder { saved: None, power: 1, since_saved: 0 }
+    }
+
+    // Give it each state in order. Some(length) once a state repeats, where length is how many
+    // steps it takes to come back round. The first time this is Some, length is the shortest cycle
+    pub fn check(&mut self, state: &T) -> Option<usize> {
+        let Some(saved) = &mut self.saved else {
+            self.saved = Some(state.clone());
+            return None;
+        };
+        self.since_saved += 1;
+        if saved == state {
+            return Some(self.since_saved);
+        }
+        if self.since_saved == self.power {
+            *saved = state.clone();
+            self.power *= 2;
+            self.since_saved = 0;
+        }
+        None
+    }
+}
+
+impl<T: Clone + Eq> Default for CycleFinder<T> {
+    fn default() -> CycleFinder<T> {
+        CycleFinder::new()
+    }
+}
+
+// The states from start on repeat every length states forever
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize
+}
+
+// None if the states run out before any repeat.
+// The iterator is cloned to go through the states again, so it has to give the same states each time
+pub fn find_cycle<T: Clone + Eq>(states: impl Iterator<Item = T> + Clone) -> Option<Cycle> {
+    let mut finder = CycleFinder::new();
+    let length = states.clone().find_map(|state| finder.check(&state))?;
+    // the cycle starts at the first state that is the same as the one length steps after it
+    let start = states.clone().zip(states.skip(length)).position(|(state, later)| state == later)?;
+    Some(Cycle { start, length })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs() {
+        // a line of numbers where each one connects to the next two, and 0 to 5 as a shortcut
+        let neighbors = |&n: &i32| {
+            let mut next = vec![n + 1, n + 2];
+            if n == 0 {
+                next.push(5);
+            }
+            next.into_iter().filter(|&next| next <= 8)
+        };
+        let distances: Vec<_> = bfs(0, neighbors).collect();
+        assert_eq!(9, distances.len());
+        assert_eq!(Some(&(5, 1)), distances.iter().find(|(n, _)| *n == 5));
+        assert_eq!(Some(&(8, 3)), distances.iter().find(|(n, _)| *n == 8));
+        assert!(distances.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let walls = [3, 7];
+        let neighbors = |&n: &i32| [n - 1, n + 1];
+        assert_eq!(HashSet::from([4, 5, 6]), flood_fill(5, neighbors, |n| !walls.contains(n)));
+        assert_eq!(HashSet::from([3]), flood_fill(3, neighbors, |n| *n == 3));
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // 3, 9, 81, 6561 % 100 = 61, 21, 41, 81, ... goes round from 81 every 4 steps
+        let squares = std::iter::successors(Some(3u64), |n| Some(n * n % 100));
+        assert_eq!(Some(Cycle { start: 2, length: 4 }), find_cycle(squares));
+
+        // a state that stays the same is a cycle of 1
+        let settles = [5, 4, 3, 3].into_iter().chain(std::iter::repeat(3));
+        assert_eq!(Some(Cycle { start: 2, length: 1 }), find_cycle(settles));
+        assert_eq!(None, find_cycle(1..1000));
+    }
+}
@@ -0,0 +1,143 @@
+/*
+Terminal animation for the days that simulate something on a grid, like day11's flashing octopi
+and day25's sea cucumbers.
+
+Day modules start an Animation and hand it each step of the simulation as text.
+Nothing is drawn unless the runner turned animation on with --animate, which sets the frame rate,
+and stdout is a terminal. The frames are drawn on the alternate screen, so the answers are
+printed as usual once the animation is done.
+While it runs, q or escape skips the rest of the animation (the day still finishes) and Ctrl-C quits.
+*/
+
+use std::io::{self, IsTerminal, Write};
+use std::process;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::crossterm::terminal::{self, ClearType};
+use ratatui::crossterm::style::Stylize;
+use ratatui::crossterm::{cursor, execute, queue, style};
+
+// Frames per second, 0 when animation is off
+static FRAME_RATE: AtomicU32 = AtomicU32::new(0);
+
+pub fn set_frame_rate(fps: u32) {
+    FRAME_RATE.store(fps, Ordering::Relaxed);
+}
+
+pub struct Animation {
+    delay: Duration,
+    running: bool
+}
+
+impl Animation {
+    // Takes over the terminal if animation is on, otherwise frames are ignored
+    pub fn start() -> Animation {
+        let fps = FRAME_RATE.load(Ordering::Relaxed);
+        let running = fps > 0 && io::stdout().is_terminal() && enter().is_ok();
+        Animation { delay: Duration::from_secs(1) / fps.max(1), running }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    // Draw a frame, then wait for the next one to be due.
+    // frame is only called while the animation is running, so building the text costs nothing otherwise
+    pub fn frame(&mut self, frame: impl FnOnce() -> String) {
+        if !self.running {
+            return;
+        }
+        if draw(&frame()).and_then(|_| self.wait()).is_err() {
+            self.stop();
+        }
+    }
+
+    // Sleep until the next frame is due, watching for keys in the meantime
+    fn wait(&mut self) -> io::Result<()> {
+        let due = Instant::now() + self.delay;
+        loop {
+            let left = due.saturating_duration_since(Instant::now());
+            if left.is_zero() || !event::poll(left)? {
+                return Ok(());
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                // raw mode turns Ctrl-C into a key press, so quitting is up to us
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.stop();
+                    process::exit(130);
+                }
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    self.stop();
+                    return Ok(());
+                }
+                _ => ()
+            }
+        }
+    }
+
+    fn stop(&mut self) {
+        if self.running {
+            self.running = false;
+            // Nothing more to do if the terminal can't be put back
+            let _ = leave();
+        }
+    }
+}
+
+// The terminal goes back to normal when the day is done with the animation
+impl Drop for Animation {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn enter() -> io::Result<()> {
+    terminal::enable_raw_mode()?;
+    execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)
+}
+
+fn leave() -> io::Result<()> {
+    execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()
+}
+
+// Text that stands out from the rest of a frame, in bold yellow
+pub fn highlight(text: &str) -> String {
+    text.bold().yellow().to_string()
+}
+
+// Overwrite the last frame line by line rather than clearing the screen first, which flickers
+fn draw(frame: &str) -> io::Result<()> {
+    let mut out = io::stdout();
+    for (row, line) in frame.lines().enumerate() {
+        queue!(out, cursor::MoveTo(0, row as u16), style::Print(line), terminal::Clear(ClearType::UntilNewLine))?;
+    }
+    queue!(out, terminal::Clear(ClearType::FromCursorDown))?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_animation_off() {
+        // the frame rate starts at 0, so frames aren't even built
+        let mut animation = Animation::start();
+        assert!(!animation.is_running());
+        animation.frame(|| panic!("animation is off"));
+    }
+
+    #[test]
+    fn test_highlight() {
+        let highlighted = highlight("0");
+        assert!(highlighted.contains('0') && highlighted.starts_with('\x1b'));
+    }
+}
@@ -0,0 +1,117 @@
+/*
+The answer to a part, whatever kind of thing the puzzle asks for.
+
+Most parts are a number, but day13 part 2 is letters drawn on a grid that have to be read by eye,
+and day24's model numbers are text. Every Solver's Output converts into an Answer,
+so the runner, --check and the JSON from `advent serve` all handle them the same way.
+
+Displayed, an Answer is what gets printed and what the answers file has in it:
+numbers as digits, text as is, and a grid as rows of # and spaces, one row per line.
+As JSON, numbers are numbers, text is a string and a grid is a list of its rows.
+*/
+
+use std::fmt;
+use serde::ser::{Serialize, Serializer};
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    // For answers that could outgrow an i64
+    BigInt(u128),
+    Text(String),
+    // Lit cells are the letters
+    Grid(Grid<bool>)
+}
+
+impl Answer {
+    // Takes up more than one line when printed, so it can't go in a table cell
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Grid(grid) => grid.height() > 1,
+            Answer::Text(text) => text.contains('\n'),
+            _ => false
+        }
+    }
+}
+
+fn grid_rows(grid: &Grid<bool>) -> impl Iterator<Item = String> + '_ {
+    grid.rows().map(|row| row.iter().map(|&lit| if lit { '#' } else { ' ' }).collect())
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::BigInt(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(grid) => write!(f, "{}", grid_rows(grid).collect::<Vec<_>>().join("\n"))
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(value) => serializer.serialize_i64(*value),
+            Answer::BigInt(value) => serializer.serialize_u128(*value),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Grid(grid) => serializer.collect_seq(grid_rows(grid))
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Answer {
+        Answer::Int(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Answer {
+        Answer::Int(value)
+    }
+}
+
+// Counts and sizes. Anything too big for an i64 is kept exact as a BigInt
+impl From<usize> for Answer {
+    fn from(value: usize) -> Answer {
+        i64::try_from(value).map_or(Answer::BigInt(value as u128), Answer::Int)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Answer {
+        Answer::BigInt(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(grid: Grid<bool>) -> Answer {
+        Answer::Grid(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        assert_eq!("1715", Answer::from(1715).to_string());
+        assert_eq!(Answer::Int(7), Answer::from(7usize));
+        assert_eq!("340282366920938463463374607431768211455", Answer::from(u128::MAX).to_string());
+        let letters = Answer::from(Grid::from_rows(vec![vec![true, false, true], vec![true, true, true]]));
+        assert_eq!("# #\n###", letters.to_string());
+        assert!(letters.is_multiline());
+        assert!(!Answer::from("92928914999991".to_string()).is_multiline());
+
+        assert_eq!("[1715,\"abc\",[\"# #\",\"###\"]]", serde_json::to_string(&[Answer::Int(1715), Answer::from("abc".to_string()), letters]).unwrap());
+    }
+}
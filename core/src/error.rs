@@ -0,0 +1,264 @@
+/*
+Errors from reading and parsing puzzle inputs.
+
+Reading and parsing return a Result instead of panicking on bad input,
+so the runner can report which day failed and why, then carry on with the rest.
+Solving still assumes the input made sense once it parsed.
+
+Parsers go through the input with lines() or sections(), which number each line,
+so an error can say which line was wrong, show it, and point at the bad part of it.
+Days whose input is just a list of lines can use stream_lines() instead, which hands over
+the same numbered lines as they're read from the file, so a huge input is never in memory all at once.
+
+Inputs saved on another machine can start with a byte order mark, end their lines with \r\n
+and have blank lines at the end. normalize() takes those out before any day's parser sees the input
+(read_input and the Solver/Solution traits call it), and stream_lines does the same as it goes.
+*/
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::ops::Range;
+
+#[derive(Debug)]
+pub enum AdventError {
+    // The input file exists but couldn't be read
+    Io { path: String, error: io::Error },
+    // The input was read, but isn't what the day expects.
+    // context is the line it went wrong on, if it's down to one line
+    Parse { day: &'static str, detail: String, context: Option<Context> },
+    // There is no input file at the path
+    MissingInput { path: String },
+    // Asked to solve a day or part that doesn't exist, like day26 or part 3
+    NoSolution { year: u32, day: String, part: u8 }
+}
+
+pub type Result<T> = std::result::Result<T, AdventError>;
+
+// The line a parse error is on, and the part of it that's wrong (a byte range of text)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context {
+    pub line: usize,
+    pub text: String,
+    pub highlight: Option<Range<usize>>
+}
+
+// What a parser for one line gives back when it fails: the part of the text that's wrong
+// (a slice of the text it was given, so Line::error_in can point at it) and what's wrong with it
+pub type PartResult<'a, T, D = String> = std::result::Result<T, (&'a str, D)>;
+
+// One line of the input, trimmed, along with its line number (counting from 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str
+}
+
+impl AdventError {
+    // A problem with the input as a whole, rather than any one line
+    pub fn parse(day: &'static str, detail: impl Into<String>) -> AdventError {
+        AdventError::Parse { day, detail: detail.into(), context: None }
+    }
+}
+
+impl<'a> Line<'a> {
+    // A problem with the whole line
+    pub fn error(&self, day: &'static str, detail: impl Into<String>) -> AdventError {
+        self.context(day, detail, None)
+    }
+
+    // A problem with part of the line. part has to be a slice of the line's text
+    // (from split, split_once, char_indices and so on), so its position can be worked out
+    pub fn error_in(&self, day: &'static str, part: &str, detail: impl Into<String>) -> AdventError {
+        let start = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let highlight = start.checked_add(part.len()).filter(|&end| end <= self.text.len()).map(|end| start..end);
+        self.context(day, detail, highlight)
+    }
+
+    // Run a parser that fails with a PartResult over the line, the error points at the part it names
+    pub fn parse_with<T, D: Into<String>>(&self, day: &'static str, parse: impl FnOnce(&'a str) -> PartResult<'a, T, D>) -> Result<T> {
+        parse(self.text).map_err(|(part, detail)| self.error_in(day, part, detail))
+    }
+
+    fn context(&self, day: &'static str, detail: impl Into<String>, highlight: Option<Range<usize>>) -> AdventError {
+        let context = Context { line: self.number, text: self.text.to_string(), highlight };
+        AdventError::Parse { day, detail: detail.into(), context: Some(context) }
+    }
+}
+
+// The same parser on text that isn't a line of an input (for FromStr), so the error is only the detail
+pub fn parse_text<'a, T, D: Into<String>>(day: &'static str, text: &'a str, parse: impl FnOnce(&'a str) -> PartResult<'a, T, D>) -> Result<T> {
+    parse(text.trim()).map_err(|(_, detail)| AdventError::parse(day, detail))
+}
+
+// Every line of the input, blank ones included so the numbering matches the file
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line { number: index + 1, text: text.trim() })
+}
+
+// The input split into groups of lines at the blank lines between them, for the days
+// where the input has more than one part. Blank lines themselves are left out
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections: Vec<Vec<Line>> = Vec::new();
+    let mut blank = true;
+    for line in lines(input) {
+        if line.text.is_empty() {
+            blank = true;
+        } else if blank {
+            sections.push(vec![line]);
+            blank = false;
+        } else {
+            sections.last_mut().unwrap().push(line);
+        }
+    }
+    sections
+}
+
+impl fmt::Display for AdventError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AdventError::Io { path, error } => write!(f, "can't read {}: {}", path, error),
+            AdventError::Parse { day, detail, context: None } => write!(f, "invalid {} input: {}", day, detail),
+            AdventError::Parse { day, detail, context: Some(context) } => {
+                write!(f, "invalid {} input on line {}", day, context.line)?;
+                if let Some(highlight) = &context.highlight {
+                    write!(f, ", column {}", context.text[..highlight.start].chars().count() + 1)?;
+                }
+                // Show the line under the message, with the bad part underlined
+                let number = context.line.to_string();
+                write!(f, ": {}\n  {} | {}", detail, number, context.text)?;
+                if let Some(highlight) = &context.highlight {
+                    let indent = context.text[..highlight.start].chars().count();
+                    let width = context.text[highlight.clone()].chars().count().max(1);
+                    write!(f, "\n  {} | {}{}", " ".repeat(number.len()), " ".repeat(indent), "^".repeat(width))?;
+                }
+                Ok(())
+            }
+            AdventError::MissingInput { path } => write!(f, "input file not found at {}", path),
+            AdventError::NoSolution { year, day, part } => write!(f, "{} has no {} part {}", year, day, part)
+        }
+    }
+}
+
+impl Error for AdventError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AdventError::Io { error, .. } => Some(error),
+            _ => None
+        }
+    }
+}
+
+// Read a whole input file, normalized
+pub fn read_input(path: &str) -> Result<String> {
+    let input = fs::read_to_string(path).map_err(|error| input_error(path, error))?;
+    Ok(normalize(&input).into_owned())
+}
+
+// No byte order mark, only \n between lines (a lone \r counts as a line break too),
+// and nothing after the last line that isn't blank. Borrowed if the input was fine already
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input).trim_end();
+    if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n").replace('\r', "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+// Open an input file to go through a bit at a time, see stream_lines
+pub fn open_input(path: &str) -> Result<BufReader<File>> {
+    File::open(path).map(BufReader::new).map_err(|error| input_error(path, error))
+}
+
+// The lines of an input as they're read, numbered and trimmed like lines(), each one turned into a T by parse.
+// path is only for the error if reading fails part way through
+// Normalized like normalize(), so a byte order mark is dropped and blank lines are held back
+// until there's another line after them, which there isn't at the end of the input
+pub fn stream_lines<'a, T: 'a>(reader: &'a mut dyn BufRead, path: &'a str, mut parse: impl FnMut(Line) -> Result<T> + 'a)
+        -> impl Iterator<Item = Result<T>> + 'a {
+    let mut blank_lines = Vec::new();
+    reader.lines().enumerate().flat_map(move |(index, text)| {
+        let text = match text {
+            Ok(text) => text,
+            Err(error) => return vec![Err(input_error(path, error))]
+        };
+        let text = if index == 0 { text.trim_start_matches('\u{feff}') } else { &text }.trim();
+        if text.is_empty() {
+            blank_lines.push(index + 1);
+            return vec![];
+        }
+        let mut parsed: Vec<_> = blank_lines.drain(..).map(|number| parse(Line { number, text: "" })).collect();
+        parsed.push(parse(Line { number: index + 1, text }));
+        parsed
+    })
+}
+
+fn input_error(path: &str, error: io::Error) -> AdventError {
+    match error.kind() {
+        io::ErrorKind::NotFound => AdventError::MissingInput { path: path.to_string() },
+        _ => AdventError::Io { path: path.to_string(), error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_input() {
+        assert!(matches!(read_input("src/year2021/day0/input.txt"), Err(AdventError::MissingInput { .. })));
+        assert!(matches!(read_input("src"), Err(AdventError::Io { .. })));
+        assert_eq!("invalid day5 input: no arrow", AdventError::parse("day5", "no arrow").to_string());
+    }
+
+    #[test]
+    fn test_line_context() {
+        let input = "1,2
+            3,x,5
+
+            6";
+        let line = lines(input).nth(1).unwrap();
+        assert_eq!(Line { number: 2, text: "3,x,5" }, line);
+        assert_eq!("invalid day7 input on line 2: too many\n  2 | 3,x,5", line.error("day7", "too many").to_string());
+        let x = line.text.split(',').nth(1).unwrap();
+        assert_eq!("invalid day7 input on line 2, column 3: not a number\n  2 | 3,x,5\n    |   ^", line.error_in("day7", x, "not a number").to_string());
+
+        fn split(text: &str) -> PartResult<'_, u32, &'static str> {
+            let (_, number) = text.split_once(',').ok_or((text, "no comma"))?;
+            number.parse().map_err(|_| (number, "not a number"))
+        }
+        assert_eq!("invalid day7 input on line 2, column 3: not a number\n  2 | 3,x,5\n    |   ^^^", line.parse_with("day7", split).unwrap_err().to_string());
+        assert_eq!(7, lines("1,7").next().unwrap().parse_with("day7", split).unwrap());
+        assert_eq!("invalid day7 input: no comma", parse_text("day7", " 3 ", split).unwrap_err().to_string());
+
+        let sections = sections(input);
+        assert_eq!(2, sections.len());
+        assert_eq!(Line { number: 4, text: "6" }, sections[1][0]);
+    }
+
+    #[test]
+    fn test_stream_lines() {
+        let mut reader = "1\r\n  22\n3x\n".as_bytes();
+        let parsed: Vec<Result<u32>> = stream_lines(&mut reader, "numbers.txt", |line| {
+            line.text.parse().map_err(|_| line.error("day1", "not a number"))
+        }).collect();
+        assert_eq!(1, *parsed[0].as_ref().unwrap());
+        assert_eq!(22, *parsed[1].as_ref().unwrap());
+        assert_eq!("invalid day1 input on line 3: not a number\n  3 | 3x", parsed[2].as_ref().unwrap_err().to_string());
+        assert!(matches!(open_input("src/year2021/day0/input.txt"), Err(AdventError::MissingInput { .. })));
+
+        // the byte order mark and the blank lines at the end go, the blank line in the middle stays
+        let mut reader = "\u{feff}1\r\n\r\n2\r\n\r\n  \r\n".as_bytes();
+        let lines: Vec<String> = stream_lines(&mut reader, "numbers.txt", |line| Ok(format!("{}:{}", line.number, line.text))).map(Result::unwrap).collect();
+        assert_eq!(vec!["1:1", "2:", "3:2"], lines);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!("1\n\n2\n3", normalize("\u{feff}1\r\n\r\n2\r3\r\n\r\n \n"));
+        assert!(matches!(normalize("1\n2\n"), Cow::Borrowed("1\n2")));
+    }
+}
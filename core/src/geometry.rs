@@ -0,0 +1,239 @@
+/*
+Points in 2d and 3d space, shared by the days that work with coordinates.
+
+Points add and subtract like vectors, and multiplying by a number scales them.
+Rotations are by quarter turns, which is all the puzzles need.
+They parse from comma separated coordinates, like "0,9" or "-1,2,3".
+*/
+
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub fn new(x: i32, y: i32) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point2) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // -1, 0 or 1 on each axis. The step to take to move one space towards a point in a straight or diagonal line
+    pub fn signum(&self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    // A quarter turn counter clockwise around the origin, with y pointing up
+    pub fn rotate_left(&self) -> Point2 {
+        Point2::new(-self.y, self.x)
+    }
+
+    // A quarter turn clockwise around the origin, with y pointing up
+    pub fn rotate_right(&self) -> Point2 {
+        Point2::new(self.y, -self.x)
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    // The number of ways to turn something to face a different direction, see rotate
+    pub const ROTATIONS: usize = 24;
+
+    pub fn new(x: i32, y: i32, z: i32) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Point3) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    // The smaller value on each axis, the corner of a box that both points are on the far side of
+    pub fn min(&self, other: &Point3) -> Point3 {
+        Point3::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+
+    // The larger value on each axis
+    pub fn max(&self, other: &Point3) -> Point3 {
+        Point3::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+
+    // One of the 24 orientations from turning the axes to face a different direction
+    // (from 0 to ROTATIONS - 1, where 0 leaves the point where it is).
+    // Computed these by hand by taking a cube, writing x,y,z,-x,-y,-z on the sides
+    // then rotating it in all possible directions until we had 24 states
+    pub fn rotate(&self, rotation: usize) -> Point3 {
+        let Point3 { x, y, z } = *self;
+        match rotation {
+            0 => Point3::new(x, y, z),
+            1 => Point3::new(x, -z, y),
+            2 => Point3::new(x, -y, -z),
+            3 => Point3::new(x, z, -y),
+            4 => Point3::new(z, y, -x),
+            5 => Point3::new(z, x, y),
+            6 => Point3::new(z, -y, x),
+            7 => Point3::new(z, -x, -y),
+            8 => Point3::new(-x, y, -z),
+            9 => Point3::new(-x, z, y),
+            10 => Point3::new(-x, -y, z),
+            11 => Point3::new(-x, -z, -y),
+            12 => Point3::new(y, -x, z),
+            13 => Point3::new(y, -z, -x),
+            14 => Point3::new(y, x, -z),
+            15 => Point3::new(y, z, x),
+            16 => Point3::new(-y, z, -x),
+            17 => Point3::new(-y, x, z),
+            18 => Point3::new(-y, -z, x),
+            19 => Point3::new(-y, -x, -z),
+            20 => Point3::new(-z, y, x),
+            21 => Point3::new(-z, -x, y),
+            22 => Point3::new(-z, -y, -x),
+            23 => Point3::new(-z, x, -y),
+            _ => panic!("invalid rotation {}", rotation)
+        }
+    }
+}
+
+// Why a point didn't parse. A day's parser turns this into an AdventError
+// that says which line of the input it was
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePointError {
+    // Not the right number of comma separated coordinates
+    Coordinates { expected: usize, found: usize },
+    // A coordinate that isn't a number
+    Number(String)
+}
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParsePointError::Coordinates { expected, found } => write!(f, "expected {} coordinates separated by commas, found {}", expected, found),
+            ParsePointError::Number(coordinate) => write!(f, "expected a number for a coordinate, found \"{}\"", coordinate)
+        }
+    }
+}
+
+impl Error for ParsePointError {}
+
+// The operators are the same for both, one axis at a time
+macro_rules! point_ops {
+    ($point:ident, $($axis:ident),+) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Mul<i32> for $point {
+            type Output = $point;
+
+            fn mul(self, scale: i32) -> $point {
+                $point { $($axis: self.$axis * scale),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+
+        impl FromStr for $point {
+            type Err = ParsePointError;
+
+            fn from_str(text: &str) -> Result<$point, ParsePointError> {
+                let coordinates: Vec<&str> = text.split(',').map(str::trim).collect();
+                let expected = [$(stringify!($axis)),+].len();
+                if coordinates.len() != expected {
+                    return Err(ParsePointError::Coordinates { expected, found: coordinates.len() });
+                }
+                let mut coordinates = coordinates.into_iter()
+                    .map(|coordinate| coordinate.parse().map_err(|_| ParsePointError::Number(coordinate.to_string())));
+                Ok($point { $($axis: coordinates.next().unwrap()?),+ })
+            }
+        }
+    };
+}
+
+point_ops!(Point2, x, y);
+point_ops!(Point3, x, y, z);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(Point2::new(0, 9)), "0,9".parse());
+        assert_eq!(Ok(Point3::new(-1, 2, 3)), " -1, 2,3 ".parse());
+        assert_eq!(Err(ParsePointError::Coordinates { expected: 2, found: 3 }), "1,2,3".parse::<Point2>());
+        assert_eq!(Err(ParsePointError::Number("y".to_string())), "1,y".parse::<Point2>());
+        assert_eq!("expected 3 coordinates separated by commas, found 1", "7".parse::<Point3>().unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_point2() {
+        let p = Point2::new(3, -2);
+        assert_eq!(Point2::new(4, 0), p + Point2::new(1, 2));
+        assert_eq!(Point2::new(6, -4), p * 2);
+        assert_eq!(5, p.manhattan(&Point2::ORIGIN));
+        assert_eq!(Point2::new(1, -1), (p - Point2::new(-5, 0)).signum());
+        assert_eq!(Point2::new(2, 3), p.rotate_left());
+        assert_eq!(p, p.rotate_left().rotate_right());
+    }
+
+    #[test]
+    fn test_point3_rotations() {
+        let p = Point3::new(1, 2, 3);
+        assert_eq!(Point3::new(-1, -2, -3), -p);
+        assert_eq!(Point3::new(1, 1, 2), p.min(&Point3::new(4, 1, 2)));
+        // Every rotation faces a different way
+        let mut rotations: Vec<Point3> = (0..Point3::ROTATIONS).map(|r| p.rotate(r)).collect();
+        rotations.sort_by_key(|p| (p.x, p.y, p.z));
+        rotations.dedup();
+        assert_eq!(Point3::ROTATIONS, rotations.len());
+        assert!(rotations.iter().all(|r| r.manhattan(&Point3::ORIGIN) == 6));
+    }
+}
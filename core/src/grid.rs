@@ -0,0 +1,230 @@
+/*
+A rectangular 2d grid, shared by the days where the puzzle input is a grid of characters.
+
+Positions are (row, col) tuples, the same as the days used with their Vec<Vec<T>> grids.
+grid[(row, col)] reads or writes a single space, and neighbors4/neighbors8 give the
+adjacent positions that are on the grid (neighbors8 includes the diagonals).
+wrapping_neighbors8 treats the grid as a torus instead, where the edges join up with the opposite side.
+The free neighbors4/neighbors8 functions do the same given the number of rows and columns.
+*/
+
+use std::ops::{Index, IndexMut};
+use crate::error::{self, Line, Result};
+
+pub type Position = (usize, usize);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    // row by row, so (row, col) is at row * width + col
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    // Build a grid from its rows, every row has to be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(rows.iter().all(|row| row.len() == width), "grid rows have different lengths");
+        Grid { width, height, cells: rows.into_iter().flatten().collect() }
+    }
+
+    // One row per line of the input, and one space per character. Blank lines are skipped.
+    // cell returns None for a character that doesn't belong in the grid, which is an error for day
+    pub fn parse(day: &'static str, input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        Grid::from_lines(day, error::lines(input), cell)
+    }
+
+    // The same as parse, for a grid that's one section of a bigger input
+    pub fn from_lines<'a>(day: &'static str, lines: impl IntoIterator<Item = Line<'a>>, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for line in lines.into_iter().filter(|line| !line.text.is_empty()) {
+            let row = line.text.char_indices()
+                .map(|(i, c)| cell(c).ok_or_else(|| line.error_in(day, &line.text[i..i + c.len_utf8()], format!("unexpected '{}'", c))))
+                .collect::<Result<Vec<T>>>()?;
+            if let Some(first) = rows.first().filter(|first| first.len() != row.len()) {
+                return Err(line.error(day, format!("the row is {} long, the rows above are {}", row.len(), first.len())));
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Grid<T> {
+        let cells = (0..height).flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut cell)
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // None if the position is off the grid
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // Every space, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    // Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    // Every space with its position
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // A grid the same size, with f applied to every space
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    // Up, down, left and right, leaving out any that are off the grid.
+    // The iterator doesn't borrow the grid, so spaces can be updated while looping over it
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> {
+        neighbors4(position, self.height, self.width)
+    }
+
+    // All 8 surrounding spaces including the diagonals, leaving out any that are off the grid
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> {
+        neighbors8(position, self.height, self.width)
+    }
+
+    // All 8 surrounding spaces, going off one edge comes back on at the opposite edge.
+    // On a grid less than 3 wide or tall some of those are the same space, each is only given once,
+    // and the position itself is never one of its neighbors. An empty grid has no neighbors at all
+    pub fn wrapping_neighbors8(&self, (row, col): Position) -> impl Iterator<Item = Position> {
+        let (rows, cols) = (self.height, self.width);
+        let mut seen: Vec<Position> = Vec::with_capacity(8);
+        if rows == 0 || cols == 0 {
+            return seen.into_iter();
+        }
+        for dr in [rows - 1, 0, 1] {
+            for dc in [cols - 1, 0, 1] {
+                let adjacent = ((row + dr) % rows, (col + dc) % cols);
+                if adjacent != (row, col) && !seen.contains(&adjacent) {
+                    seen.push(adjacent);
+                }
+            }
+        }
+        seen.into_iter()
+    }
+}
+
+// The same as Grid::neighbors4, for any rows x cols grid (like a Vec<Vec<T>>)
+pub fn neighbors4((row, col): Position, rows: usize, cols: usize) -> impl Iterator<Item = Position> {
+    neighbors((row, col), rows, cols, &[(-1, 0), (1, 0), (0, -1), (0, 1)])
+}
+
+// The same as Grid::neighbors8, for any rows x cols grid
+pub fn neighbors8((row, col): Position, rows: usize, cols: usize) -> impl Iterator<Item = Position> {
+    neighbors((row, col), rows, cols, &[(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)])
+}
+
+fn neighbors((row, col): Position, rows: usize, cols: usize, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = Position> {
+    offsets.iter().filter_map(move |&(dr, dc)| {
+        let r = row.checked_add_signed(dr).filter(|&r| r < rows)?;
+        let c = col.checked_add_signed(dc).filter(|&c| c < cols)?;
+        Some((r, c))
+    })
+}
+
+// An empty grid, whether or not T has a default
+impl<T> Default for Grid<T> {
+    fn default() -> Grid<T> {
+        Grid { width: 0, height: 0, cells: Vec::new() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // A grid with every space set to the same value
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Position) -> &T {
+        assert!(row < self.height && col < self.width, "({}, {}) is off the {}x{} grid", row, col, self.width, self.height);
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut T {
+        assert!(row < self.height && col < self.width, "({}, {}) is off the {}x{} grid", row, col, self.width, self.height);
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = Grid::parse("day0", "123
+            456
+            ", |c| c.to_digit(10)).unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(1, 2)]);
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(vec![&[1, 2, 3][..], &[4, 5, 6][..]], grid.rows().collect::<Vec<_>>());
+        assert_eq!(Some(((1, 0), &4)), grid.enumerate().nth(3));
+        assert_eq!(12, grid.map(|n| n * 2)[(1, 2)]);
+        let error = Grid::parse("day0", "12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert!(error.to_string().starts_with("invalid day0 input on line 2, column 2: unexpected 'x'"));
+        assert!(Grid::parse("day0", "12\n345", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(vec![(1, 0), (0, 1)], grid.neighbors4((0, 0)).collect::<Vec<_>>());
+        assert_eq!(4, grid.neighbors4((1, 1)).count());
+        assert_eq!(3, grid.neighbors8((2, 2)).count());
+        assert_eq!(8, grid.neighbors8((1, 1)).count());
+        // a grid that's wider than it is tall
+        assert_eq!(vec![(1, 4), (0, 3), (0, 5)], neighbors4((0, 4), 2, 6).collect::<Vec<_>>());
+        assert_eq!(5, neighbors8((1, 4), 2, 6).count());
+    }
+
+    #[test]
+    fn test_wrapping_neighbors() {
+        let grid = Grid::filled(4, 3, 0);
+        assert_eq!(vec![(2, 3), (2, 0), (2, 1), (0, 3), (0, 1), (1, 3), (1, 0), (1, 1)],
+            grid.wrapping_neighbors8((0, 0)).collect::<Vec<_>>());
+        // in a 2x1 grid the two spaces are all there is
+        assert_eq!(vec![(0, 1)], Grid::filled(2, 1, 0).wrapping_neighbors8((0, 0)).collect::<Vec<_>>());
+        assert_eq!(0, Grid::filled(1, 1, 0).wrapping_neighbors8((0, 0)).count());
+        assert_eq!(0, Grid::filled(0, 0, 0).wrapping_neighbors8((0, 0)).count());
+        assert_eq!(0, Grid::filled(3, 0, 0).wrapping_neighbors8((0, 0)).count());
+    }
+}
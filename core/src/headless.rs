@@ -0,0 +1,43 @@
+/*
+Stand-ins for progress.rs and animate.rs when building for wasm32.
+There's no terminal to draw on in the browser (or a clock, Instant panics there),
+so these have the same API and do nothing.
+*/
+
+pub mod progress {
+    pub fn set_enabled(_enabled: bool) {}
+
+    pub struct Progress;
+
+    impl Progress {
+        pub fn new(_total: u64, _message: &'static str) -> Progress {
+            Progress
+        }
+
+        pub fn spinner(_message: &'static str) -> Progress {
+            Progress
+        }
+
+        pub fn inc(&self) {}
+
+        pub fn inc_now(&self) {}
+    }
+}
+
+pub mod animate {
+    pub fn set_frame_rate(_fps: u32) {}
+
+    pub struct Animation;
+
+    impl Animation {
+        pub fn start() -> Animation {
+            Animation
+        }
+
+        pub fn is_running(&self) -> bool {
+            false
+        }
+
+        pub fn frame(&mut self, _frame: impl FnOnce() -> String) {}
+    }
+}
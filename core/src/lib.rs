@@ -0,0 +1,26 @@
+/*
+What the days have in common, used by the solutions crate (advent2021) and anything else that wants it.
+
+algorithms has the searches, grid, point_set and geometry are the shapes the inputs come in,
+error is reading and parsing inputs and what goes wrong doing it, and answer is what the parts return.
+viz draws pictures of a day, series saves the numbers a day works out over time, animate plays a day step by step in the terminal,
+and progress shows how far along a long running part is.
+*/
+
+pub mod algorithms;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod animate;
+pub mod answer;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+#[cfg(target_arch = "wasm32")]
+mod headless;
+pub mod point_set;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod progress;
+pub mod series;
+pub mod viz;
+
+#[cfg(target_arch = "wasm32")]
+pub use headless::{animate, progress};
@@ -0,0 +1,134 @@
+/*
+A sparse set of 2d points, for the days where only a few spaces on a big (or endless) grid matter.
+
+A Grid has a space for every position from (0, 0) to its size, a PointSet only has the points in it,
+which can be anywhere, negative coordinates included. bounds gives the smallest box around them,
+translate moves them all, fold_x/fold_y fold the set over a line like day13's paper,
+and render draws part of the set as a Grid<bool> to print or look at.
+*/
+
+use std::collections::HashSet;
+use crate::geometry::Point2;
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PointSet {
+    points: HashSet<Point2>
+}
+
+impl PointSet {
+    pub fn new() -> PointSet {
+        PointSet::default()
+    }
+
+    // false if the point was already in the set
+    pub fn insert(&mut self, point: Point2) -> bool {
+        self.points.insert(point)
+    }
+
+    pub fn contains(&self, point: &Point2) -> bool {
+        self.points.contains(point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Point2> {
+        self.points.iter()
+    }
+
+    // The smallest and largest corners of the box around every point, both inclusive.
+    // None for an empty set, which has no box
+    pub fn bounds(&self) -> Option<(Point2, Point2)> {
+        let xs = self.points.iter().map(|p| p.x);
+        let ys = self.points.iter().map(|p| p.y);
+        Some((Point2::new(xs.clone().min()?, ys.clone().min()?), Point2::new(xs.max()?, ys.max()?)))
+    }
+
+    pub fn translate(&self, offset: Point2) -> PointSet {
+        self.points.iter().map(|&p| p + offset).collect()
+    }
+
+    // Everything right of the vertical line at x is mirrored onto the left side.
+    // Points on the line itself are dropped
+    pub fn fold_x(&self, x: i32) -> PointSet {
+        self.points.iter()
+            .filter(|p| p.x != x)
+            .map(|&p| if p.x > x { Point2::new(2 * x - p.x, p.y) } else { p })
+            .collect()
+    }
+
+    // The same as fold_x, but everything below the horizontal line at y comes up
+    pub fn fold_y(&self, y: i32) -> PointSet {
+        self.points.iter()
+            .filter(|p| p.y != y)
+            .map(|&p| if p.y > y { Point2::new(p.x, 2 * y - p.y) } else { p })
+            .collect()
+    }
+
+    // The box from min to max (inclusive) as a grid, with y going down the rows.
+    // Points outside the box are left out
+    pub fn render(&self, min: Point2, max: Point2) -> Grid<bool> {
+        let (width, height) = ((max.x - min.x + 1).max(0), (max.y - min.y + 1).max(0));
+        let mut grid = Grid::filled(width as usize, height as usize, false);
+        for p in self.translate(-min).iter().filter(|p| (0..width).contains(&p.x) && (0..height).contains(&p.y)) {
+            grid[(p.y as usize, p.x as usize)] = true;
+        }
+        grid
+    }
+
+    // Just the box around the points
+    pub fn to_grid(&self) -> Grid<bool> {
+        self.bounds().map_or_else(Grid::default, |(min, max)| self.render(min, max))
+    }
+}
+
+impl FromIterator<Point2> for PointSet {
+    fn from_iter<I: IntoIterator<Item = Point2>>(points: I) -> PointSet {
+        PointSet { points: points.into_iter().collect() }
+    }
+}
+
+// The lit spaces, with x the column and y the row
+impl From<&Grid<bool>> for PointSet {
+    fn from(grid: &Grid<bool>) -> PointSet {
+        grid.enumerate()
+            .filter(|(_, &lit)| lit)
+            .map(|((row, col), _)| Point2::new(col as i32, row as i32))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_set() {
+        let points: PointSet = [Point2::new(-1, 2), Point2::new(3, 0), Point2::new(0, 5)].into_iter().collect();
+        assert_eq!(Some((Point2::new(-1, 0), Point2::new(3, 5))), points.bounds());
+        assert_eq!(None, PointSet::new().bounds());
+        assert!(points.translate(Point2::new(1, -2)).contains(&Point2::new(0, 0)));
+
+        // folding over x=1 moves (3, 0) to (-1, 0), and a point on the fold line goes away
+        let folded = points.fold_x(1).fold_y(3);
+        assert_eq!(PointSet::from_iter([Point2::new(-1, 2), Point2::new(-1, 0), Point2::new(0, 1)]), folded);
+        assert_eq!(2, points.fold_y(2).len());
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::from_rows(vec![vec![true, false, false], vec![false, false, true]]);
+        let points = PointSet::from(&grid);
+        assert_eq!(2, points.len());
+        assert_eq!(grid, points.render(Point2::ORIGIN, Point2::new(2, 1)));
+        // the box around the points is the same here, and cutting it smaller leaves points out
+        assert_eq!(grid, points.to_grid());
+        assert_eq!(Grid::from_rows(vec![vec![false, false]]), points.render(Point2::new(0, 1), Point2::new(1, 1)));
+    }
+}
@@ -0,0 +1,82 @@
+/*
+Progress reporting for the slow days, so a long solve doesn't look like a frozen terminal.
+
+Day modules create a Progress and tick it as they work (scanners placed, states explored).
+The runner decides whether anything is shown: bars are only drawn for text output,
+and indicatif skips drawing entirely when stderr isn't a terminal.
+*/
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use indicatif::{ProgressBar, ProgressStyle};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+// Ticks are batched before they reach the bar, some searches tick millions of times
+const BATCH: u64 = 1024;
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+// Sync so the parallel searches can share one
+pub struct Progress {
+    bar: ProgressBar,
+    pending: AtomicU64
+}
+
+impl Progress {
+    // A bar for work with a known size, like placing every scanner
+    pub fn new(total: u64, message: &'static str) -> Progress {
+        let bar = Progress::bar(ProgressBar::new(total));
+        bar.set_style(ProgressStyle::with_template("{bar:40} {pos}/{len} {msg} ({elapsed})").unwrap());
+        bar.set_message(message);
+        Progress { bar, pending: AtomicU64::new(0) }
+    }
+
+    // A spinner with a running count, for searches where the total isn't known up front
+    pub fn spinner(message: &'static str) -> Progress {
+        let bar = Progress::bar(ProgressBar::new_spinner());
+        bar.set_style(ProgressStyle::with_template("{spinner} {human_pos} {msg} ({elapsed})").unwrap());
+        bar.set_message(message);
+        Progress { bar, pending: AtomicU64::new(0) }
+    }
+
+    fn bar(bar: ProgressBar) -> ProgressBar {
+        if ENABLED.load(Ordering::Relaxed) { bar } else { ProgressBar::hidden() }
+    }
+
+    pub fn inc(&self) {
+        // Whichever tick fills up the batch passes it on
+        if self.pending.fetch_add(1, Ordering::Relaxed) + 1 == BATCH {
+            self.pending.fetch_sub(BATCH, Ordering::Relaxed);
+            self.bar.inc(BATCH);
+        }
+    }
+
+    // Skip the batching, for progress that only ticks a handful of times
+    pub fn inc_now(&self) {
+        self.bar.inc(1);
+    }
+}
+
+// The bar disappears when the day is done so it doesn't get mixed in with the answers
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.bar.finish_and_clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batched_ticks() {
+        let progress = Progress::spinner("states explored");
+        for _ in 0..2000 {
+            progress.inc();
+        }
+        assert_eq!(1024, progress.bar.position());
+        assert_eq!(2000 - 1024, progress.pending.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,112 @@
+/*
+Numbers a day works out over time, for the days where the answer is the end of something that grows,
+like day6's lanternfish population on every day up to part 2's 256.
+
+A Series is a table: the names of the columns, then a row of numbers for each step.
+It's saved as CSV (a header row, then a line per row) or as JSON (a list with an object per row,
+keyed by the column names), picked by the file extension.
+In the JSON the numbers are strings, they go past 2^53 and JavaScript (like most JSON readers)
+would round them.
+With `advent run day6 --series fish.csv` the runner saves it after solving.
+*/
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use crate::error::{AdventError, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Series {
+    pub columns: Vec<String>,
+    // Each row has a number for every column
+    pub rows: Vec<Vec<u128>>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json
+}
+
+impl Format {
+    // From the file extension, None if it isn't .csv or .json
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::Json),
+            _ => None
+        }
+    }
+}
+
+// Save the series to path, as csv or json depending on the extension
+pub fn save(series: &Series, path: &Path) -> Result<()> {
+    let io_error = |error| AdventError::Io { path: path.display().to_string(), error };
+    let format = Format::from_path(path)
+        .ok_or_else(|| io_error(io::Error::new(io::ErrorKind::InvalidInput, "a series can be saved as .csv or .json")))?;
+    let mut out = BufWriter::new(File::create(path).map_err(io_error)?);
+    match format {
+        Format::Csv => write_csv(series, &mut out),
+        Format::Json => write_json(series, &mut out)
+    }.and_then(|_| out.flush()).map_err(io_error)
+}
+
+pub fn write_csv(series: &Series, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "{}", series.columns.join(","))?;
+    for row in &series.rows {
+        writeln!(out, "{}", row.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(","))?;
+    }
+    Ok(())
+}
+
+pub fn write_json(series: &Series, out: &mut dyn Write) -> io::Result<()> {
+    let rows: Vec<JsonRow> = series.rows.iter().map(|values| JsonRow { columns: &series.columns, values }).collect();
+    serde_json::to_writer(&mut *out, &rows)?;
+    writeln!(out)
+}
+
+// One row as a JSON object, the columns in order with each value as a string
+struct JsonRow<'a> {
+    columns: &'a [String],
+    values: &'a [u128]
+}
+
+impl Serialize for JsonRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.values.len()))?;
+        for (column, value) in self.columns.iter().zip(self.values) {
+            map.serialize_entry(column, &value.to_string())?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series() -> Series {
+        Series { columns: vec!["day".to_string(), "total".to_string()], rows: vec![vec![0, 5], vec![1, u128::MAX]] }
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = Vec::new();
+        write_csv(&series(), &mut out).unwrap();
+        assert_eq!(format!("day,total\n0,5\n1,{}\n", u128::MAX), String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut out = Vec::new();
+        write_json(&series(), &mut out).unwrap();
+        assert_eq!(format!("[{{\"day\":\"0\",\"total\":\"5\"}},{{\"day\":\"1\",\"total\":\"{}\"}}]\n", u128::MAX), String::from_utf8(out).unwrap());
+        let quoted = Series { columns: vec!["a \"b\"\n".to_string()], rows: vec![vec![1]] };
+        let mut out = Vec::new();
+        write_json(&quoted, &mut out).unwrap();
+        assert_eq!("[{\"a \\\"b\\\"\\n\":\"1\"}]\n", String::from_utf8(out).unwrap());
+        assert_eq!(Some(Format::Json), Format::from_path(Path::new("fish.JSON")));
+        assert_eq!(None, Format::from_path(Path::new("fish.txt")));
+    }
+}
@@ -0,0 +1,193 @@
+/*
+Pictures of the days that have something to look at: the letters day13's folds spell out,
+day20's enhanced image, day9's basins, a heatmap of day5's vents, day8's decoded displays.
+
+A day draws itself by implementing Render (or returning a Grid, which already does),
+one colored cell per space. The picture is saved as an SVG or a PPM image, picked by the
+file extension, with each cell drawn as a CELL_SIZE square.
+It can also be saved as text (.txt), a character per cell, or whatever text the picture draws itself
+if it has its own (day8's displays are ASCII art, day9 gives each basin a letter).
+With `advent run day13 --viz letters.svg` the runner saves the picture after solving.
+*/
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use crate::error::{AdventError, Result};
+use crate::grid::{Grid, Position};
+
+// Pixels per cell, so a 40x6 grid of letters is still big enough to read
+pub const CELL_SIZE: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+pub trait Render {
+    // (width, height) in cells
+    fn size(&self) -> (usize, usize);
+    // The color of the cell at (row, col)
+    fn color(&self, position: Position) -> Rgb;
+    // Cells this color are left out of an svg, it's drawn once behind the whole picture
+    fn background(&self) -> Rgb {
+        Rgb::BLACK
+    }
+    // The picture drawn with characters for a .txt, if it has a better way than write_text's
+    fn text(&self) -> Option<String> {
+        None
+    }
+}
+
+// Lit cells are white on black
+impl Render for Grid<bool> {
+    fn size(&self) -> (usize, usize) {
+        (self.width(), self.height())
+    }
+
+    fn color(&self, position: Position) -> Rgb {
+        if self[position] { Rgb::WHITE } else { Rgb::BLACK }
+    }
+}
+
+impl Render for Grid<Rgb> {
+    fn size(&self) -> (usize, usize) {
+        (self.width(), self.height())
+    }
+
+    fn color(&self, position: Position) -> Rgb {
+        self[position]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Svg,
+    Text
+}
+
+impl Format {
+    // From the file extension, None if it isn't .ppm, .svg or .txt
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "ppm" => Some(Format::Ppm),
+            "svg" => Some(Format::Svg),
+            "txt" => Some(Format::Text),
+            _ => None
+        }
+    }
+}
+
+// Save the picture to path, as an svg, ppm or text depending on the extension
+pub fn save(picture: &dyn Render, path: &Path) -> Result<()> {
+    let io_error = |error| AdventError::Io { path: path.display().to_string(), error };
+    let format = Format::from_path(path)
+        .ok_or_else(|| io_error(io::Error::new(io::ErrorKind::InvalidInput, "pictures can be saved as .svg, .ppm or .txt")))?;
+    let mut out = BufWriter::new(File::create(path).map_err(io_error)?);
+    match format {
+        Format::Ppm => write_ppm(picture, &mut out),
+        Format::Svg => write_svg(picture, &mut out),
+        Format::Text => write_text(picture, &mut out)
+    }.and_then(|_| out.flush()).map_err(io_error)
+}
+
+// Binary PPM (P6): a short text header, then 3 bytes per pixel, row by row
+pub fn write_ppm(picture: &dyn Render, out: &mut dyn Write) -> io::Result<()> {
+    let (width, height) = picture.size();
+    write!(out, "P6\n{} {}\n255\n", width * CELL_SIZE, height * CELL_SIZE)?;
+    for row in 0..height {
+        let line: Vec<u8> = (0..width)
+            .flat_map(|col| {
+                let Rgb(r, g, b) = picture.color((row, col));
+                [r, g, b].repeat(CELL_SIZE)
+            })
+            .collect();
+        for _ in 0..CELL_SIZE {
+            out.write_all(&line)?;
+        }
+    }
+    Ok(())
+}
+
+// One rect for the background and one for every cell that isn't the background color
+pub fn write_svg(picture: &dyn Render, out: &mut dyn Write) -> io::Result<()> {
+    let (width, height) = picture.size();
+    let (pixel_width, pixel_height) = (width * CELL_SIZE, height * CELL_SIZE);
+    writeln!(out, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">", pixel_width, pixel_height)?;
+    writeln!(out, "<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>", pixel_width, pixel_height, picture.background().hex())?;
+    for row in 0..height {
+        for col in 0..width {
+            let color = picture.color((row, col));
+            if color != picture.background() {
+                writeln!(out, "<rect x=\"{}\" y=\"{}\" width=\"{3}\" height=\"{3}\" fill=\"{2}\"/>",
+                    col * CELL_SIZE, row * CELL_SIZE, color.hex(), CELL_SIZE)?;
+            }
+        }
+    }
+    writeln!(out, "</svg>")
+}
+
+// The picture's own text if it has some, otherwise a # for every cell that isn't the background
+// and a space for the rest, a line per row
+pub fn write_text(picture: &dyn Render, out: &mut dyn Write) -> io::Result<()> {
+    if let Some(text) = picture.text() {
+        return out.write_all(text.as_bytes());
+    }
+    let (width, height) = picture.size();
+    for row in 0..height {
+        let line: String = (0..width)
+            .map(|col| if picture.color((row, col)) == picture.background() { ' ' } else { '#' })
+            .collect();
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_ppm() {
+        let picture = Grid::from_rows(vec![vec![true, false]]);
+        let mut out = Vec::new();
+        write_ppm(&picture, &mut out).unwrap();
+        let header = format!("P6\n{} {}\n255\n", 2 * CELL_SIZE, CELL_SIZE);
+        assert!(out.starts_with(header.as_bytes()));
+        assert_eq!(header.len() + 2 * CELL_SIZE * CELL_SIZE * 3, out.len());
+        // the first row of pixels is white then black
+        let pixels = &out[header.len()..];
+        assert_eq!([255, 255, 255], pixels[..3]);
+        assert_eq!([0, 0, 0], pixels[CELL_SIZE * 3..CELL_SIZE * 3 + 3]);
+    }
+
+    #[test]
+    fn test_write_svg() {
+        let picture = Grid::from_rows(vec![vec![Rgb::BLACK, Rgb(255, 0, 16)]]);
+        let mut out = Vec::new();
+        write_svg(&picture, &mut out).unwrap();
+        let svg = String::from_utf8(out).unwrap();
+        assert!(svg.contains("<rect x=\"10\" y=\"0\" width=\"10\" height=\"10\" fill=\"#ff0010\"/>"));
+        // the black cell is just the background
+        assert_eq!(2, svg.matches("<rect").count());
+        assert_eq!(Some(Format::Svg), Format::from_path(Path::new("out.SVG")));
+        assert_eq!(None, Format::from_path(Path::new("out.png")));
+    }
+
+    #[test]
+    fn test_write_text() {
+        let picture = Grid::from_rows(vec![vec![true, false, true], vec![false, true, false]]);
+        let mut out = Vec::new();
+        write_text(&picture, &mut out).unwrap();
+        assert_eq!("# #\n #\n", String::from_utf8(out).unwrap());
+        assert_eq!(Some(Format::Text), Format::from_path(Path::new("out.txt")));
+    }
+}
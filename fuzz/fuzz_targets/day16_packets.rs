@@ -0,0 +1,13 @@
+// Any text should parse into a packet or come back as an error, never panic or overflow the stack.
+// A packet that parsed always has a value
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use advent2021::year2021::day16;
+
+fuzz_target!(|input: &str| {
+    if let Ok(packet) = day16::parse_hex_packet(input) {
+        packet.count_version();
+        packet.calculate();
+    }
+});
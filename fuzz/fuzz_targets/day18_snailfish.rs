@@ -0,0 +1,9 @@
+// Any text should parse into snail numbers or come back as an error, never panic or overflow the stack
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use advent2021::year2021::day18;
+
+fuzz_target!(|input: &str| {
+    let _ = day18::parse_input(input);
+});
@@ -0,0 +1,9 @@
+// Any text should parse into reboot steps or come back as an error, never panic
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use advent2021::year2021::day22;
+
+fuzz_target!(|input: &str| {
+    let _ = day22::parse_input(input);
+});
@@ -0,0 +1,49 @@
+/*
+With the embed-inputs feature, every puzzle input and example under src/yearYYYY/dayN/ is compiled
+into the library, so the binary runs from any directory without the .txt files next to it.
+
+This writes $OUT_DIR/embedded_inputs.rs, a list of (path, contents) pairs that solution.rs includes.
+The paths are the same paths the days use for their default inputs, from the top of the workspace,
+like solutions/src/year2021/day1/depths.txt. Without the feature the list is empty.
+*/
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("embedded_inputs.rs");
+    let mut entries = Vec::new();
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        let crate_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+        let workspace = crate_dir.parent().unwrap();
+        for file in input_files(&crate_dir.join("src")) {
+            let relative = file.strip_prefix(workspace).unwrap().to_string_lossy().replace('\\', "/");
+            entries.push(format!("    ({:?}, include_str!({:?})),", relative, file.display().to_string()));
+            println!("cargo:rerun-if-changed={}", file.display());
+        }
+        // picks up inputs added to a day, or new days
+        println!("cargo:rerun-if-changed=src");
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+    let table = format!("&[\n{}\n]\n", entries.join("\n"));
+    fs::write(out, table).unwrap();
+}
+
+// The .txt files in src/year*/day*/, sorted so the table comes out the same every build
+fn input_files(src: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = subdirectories(src, "year").iter()
+        .flat_map(|year| subdirectories(year, "day"))
+        .flat_map(|day| fs::read_dir(day).unwrap().map(|entry| entry.unwrap().path()).collect::<Vec<_>>())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect();
+    files.sort();
+    files
+}
+
+fn subdirectories(dir: &Path, prefix: &str) -> Vec<PathBuf> {
+    fs::read_dir(dir).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir() && path.file_name().unwrap().to_string_lossy().starts_with(prefix))
+        .collect()
+}
@@ -0,0 +1,19 @@
+/* The C interface to the advent2021 solutions, built with the ffi feature (see src/ffi.rs) */
+#ifndef ADVENT2021_H
+#define ADVENT2021_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define ADVENT_OK 0
+#define ADVENT_ERROR 1
+
+/* Solve part 1 or 2 of a day (1 to 25) from len bytes of UTF-8 puzzle input.
+   Returns ADVENT_OK with *out set to the answer, or ADVENT_ERROR with *out set to the error message.
+   Either way *out must be freed with advent_free. */
+int advent_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t len, char **out);
+
+/* Free a string from advent_solve. NULL is ignored. */
+void advent_free(char *string);
+
+#endif
@@ -0,0 +1,87 @@
+/*
+A C interface to the solutions, with the ffi feature, for calling them from other languages.
+include/advent2021.h declares the functions, and the README shows how to build the library.
+
+advent_solve() solves one part of a day from the puzzle input in a UTF-8 buffer.
+Whether it worked or not, it hands back a string allocated by this library: the answer,
+or the error message (the same one the command line prints). Free it with advent_free().
+
+    char *answer;
+    if (advent_solve(1, 2, input, strlen(input), &answer) == ADVENT_OK) {
+        printf("%s\n", answer);
+    }
+    advent_free(answer);
+*/
+
+use std::ffi::{c_char, c_int, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+use std::str;
+use crate::solution::{self, DEFAULT_YEAR};
+
+pub const ADVENT_OK: c_int = 0;
+pub const ADVENT_ERROR: c_int = 1;
+
+/// Solve part 1 or 2 of a day (1 to 25) of 2021. Returns ADVENT_OK and points `out` at the answer,
+/// or ADVENT_ERROR and points `out` at the error message.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes, and `out` must point to a writable `char *`.
+/// The string written to `out` has to be freed with `advent_free`, not `free`.
+#[no_mangle]
+pub unsafe extern "C" fn advent_solve(day: u8, part: u8, input: *const u8, len: usize, out: *mut *mut c_char) -> c_int {
+    let bytes = if len == 0 { &[] } else { slice::from_raw_parts(input, len) };
+    // A panic can't unwind into C, so it's reported like any other error
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solve(day, part, bytes)))
+        .unwrap_or_else(|_| Err(format!("day{} part {} panicked", day, part)));
+    let (code, text) = match result {
+        Ok(answer) => (ADVENT_OK, answer),
+        Err(message) => (ADVENT_ERROR, message)
+    };
+    // Error messages quote the input, which could have a nul in it
+    *out = CString::new(text.replace('\0', "")).unwrap().into_raw();
+    code
+}
+
+/// Free a string returned by `advent_solve`. Does nothing with NULL.
+///
+/// # Safety
+/// `string` must have come from `advent_solve`, and can't be used again afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn advent_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+fn solve(day: u8, part: u8, input: &[u8]) -> Result<String, String> {
+    let input = str::from_utf8(input).map_err(|error| format!("the input isn't UTF-8: {}", error))?;
+    solution::solve_input(DEFAULT_YEAR, &format!("day{}", day), part, input)
+        .map(|answer| answer.to_string())
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::ptr;
+
+    fn call(day: u8, part: u8, input: &[u8]) -> (c_int, String) {
+        let mut out = ptr::null_mut();
+        unsafe {
+            let code = advent_solve(day, part, input.as_ptr(), input.len(), &mut out);
+            let text = CStr::from_ptr(out).to_str().unwrap().to_string();
+            advent_free(out);
+            (code, text)
+        }
+    }
+
+    #[test]
+    fn test_advent_solve() {
+        assert_eq!((ADVENT_OK, "7".to_string()), call(1, 1, b"199\n200\n208\n210\n200\n207\n240\n269\n260\n263"));
+        assert_eq!((ADVENT_ERROR, "2021 has no day26 part 1".to_string()), call(26, 1, b"1"));
+        assert_eq!(ADVENT_ERROR, call(1, 1, b"199\n\xff").0);
+        assert_eq!(ADVENT_ERROR, call(1, 1, b"199\n2\x000").0);
+    }
+}
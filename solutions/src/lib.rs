@@ -0,0 +1,24 @@
+/*
+The solutions as a library, so they can be used without the command line tool.
+
+Each day is a module under its year, for example advent2021::year2021::day15,
+with public functions to read or parse the puzzle input and to solve each part.
+Each day also implements solution::Solver, with its parsed input and answer types,
+and solution::registry lists every day as a Solution for the runner.
+
+The code shared between days is in the advent2021-core crate. Its modules are re-exported here,
+so advent2021::grid and crate::grid inside a day are the same as advent2021_core::grid.
+
+The advent2021 binary (the cli crate) is the command line on top of this.
+With the wasm feature, wasm.rs exports the solutions to JavaScript,
+and with the ffi feature, ffi.rs exports them to C.
+*/
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod solution;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod year2021;
+
+pub use advent2021_core::{algorithms, animate, answer, error, geometry, grid, point_set, progress, series, viz};
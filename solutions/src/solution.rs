@@ -0,0 +1,189 @@
+/*
+Every day implements the Solver trait, and the registry wraps each one in Solved
+so the runner in the cli crate can treat them all the same way through the Solution trait.
+
+Solver is the typed version: parse() turns the input text into the day's Input type,
+after error::normalize() has taken out any \r\n line endings and trailing blank lines,
+and solve_part1()/solve_part2() work out the answers from it. Code that's generic over
+the days (benchmarks, checking answers) can use Solver directly.
+
+Solution is the version the runner uses, with the types hidden:
+parse_input() parses the puzzle input text and keeps whatever the day needs,
+or returns an error if it isn't a valid input for the day.
+read_input() does the same as the input is read from a file (see Solver::read),
+and parse() opens the file for it, failing if the file is missing.
+default_input() is the path of the bundled input, used unless --input says otherwise.
+example_input() is the sample from the puzzle description, used with --example.
+part1() and part2() return the Answers, ready to print (see answer.rs).
+render() is a picture of the day for --viz, for the few days that draw one (see viz.rs),
+and series() is a table of numbers over time for --series (see series.rs).
+
+With the embed-inputs feature the bundled inputs and examples are compiled in, and parse()
+uses those instead of reading the files, so the binary works from any directory.
+
+solve_input() is one part of one day straight from the input text, with no files involved,
+for front ends that are handed the input, like the wasm build in the browser.
+*/
+
+use std::io::BufRead;
+use std::marker::PhantomData;
+use std::path::Path;
+use crate::answer::Answer;
+use crate::error::{self, AdventError, Result};
+use crate::series::Series;
+use crate::viz::Render;
+use crate::year2021;
+
+pub trait Solver {
+    // Whatever the parsed puzzle input is for the day
+    type Input: Send;
+    // The answer to both parts, a number for most days
+    type Output: Into<Answer>;
+
+    // The puzzle name, like "Sonar Sweep"
+    const TITLE: &'static str;
+    // Path of the bundled input
+    const DEFAULT_INPUT: &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    // Parse the input as it's read from a file. Days with a simple list of lines override this
+    // to go through it with error::stream_lines, the rest read the whole file and parse() it
+    fn read(reader: &mut dyn BufRead, path: &str) -> Result<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input).map_err(|error| AdventError::Io { path: path.to_string(), error })?;
+        Self::parse(&error::normalize(&input))
+    }
+    fn solve_part1(input: &Self::Input) -> Self::Output;
+    fn solve_part2(input: &Self::Input) -> Self::Output;
+
+    // A picture of the puzzle, for the days with something to look at
+    fn render(_input: &Self::Input) -> Option<Box<dyn Render>> {
+        None
+    }
+
+    // How the puzzle changes over time, for the days with numbers worth plotting
+    fn series(_input: &Self::Input) -> Option<Series> {
+        None
+    }
+}
+
+// Send so --parallel can run each day on its own thread
+pub trait Solution: Send {
+    // The puzzle name, like "Sonar Sweep"
+    fn title(&self) -> &'static str;
+    fn default_input(&self) -> &'static str;
+    // The sample input from the puzzle description, kept next to the real input
+    fn example_input(&self) -> String {
+        Path::new(self.default_input()).with_file_name("example.txt").to_string_lossy().into_owned()
+    }
+    fn parse_input(&mut self, input: &str) -> Result<()>;
+    fn read_input(&mut self, reader: &mut dyn BufRead, path: &str) -> Result<()>;
+    fn parse(&mut self, path: &str) -> Result<()> {
+        match embedded_input(path) {
+            Some(input) => self.parse_input(input),
+            None => self.read_input(&mut error::open_input(path)?, path)
+        }
+    }
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+    fn render(&self) -> Option<Box<dyn Render>>;
+    fn series(&self) -> Option<Series>;
+}
+
+// A Solver as a Solution, holding on to the parsed input between parse() and the parts
+pub struct Solved<S: Solver> {
+    input: Option<S::Input>,
+    // fn() -> S so Solved is Send whether or not the solver type is
+    solver: PhantomData<fn() -> S>
+}
+
+impl<S: Solver> Default for Solved<S> {
+    fn default() -> Solved<S> {
+        Solved { input: None, solver: PhantomData }
+    }
+}
+
+impl<S: Solver> Solved<S> {
+    fn input(&self) -> &S::Input {
+        self.input.as_ref().expect("parse has to be called before solving")
+    }
+}
+
+impl<S: Solver> Solution for Solved<S> {
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn default_input(&self) -> &'static str {
+        S::DEFAULT_INPUT
+    }
+
+    fn parse_input(&mut self, input: &str) -> Result<()> {
+        self.input = Some(S::parse(&error::normalize(input))?);
+        Ok(())
+    }
+
+    fn read_input(&mut self, reader: &mut dyn BufRead, path: &str) -> Result<()> {
+        self.input = Some(S::read(reader, path)?);
+        Ok(())
+    }
+
+    fn part1(&self) -> Answer {
+        S::solve_part1(self.input()).into()
+    }
+
+    fn part2(&self) -> Answer {
+        S::solve_part2(self.input()).into()
+    }
+
+    fn render(&self) -> Option<Box<dyn Render>> {
+        S::render(self.input())
+    }
+
+    fn series(&self) -> Option<Series> {
+        S::series(self.input())
+    }
+}
+
+// (path, contents) for each input compiled in by the embed-inputs feature, empty without it
+const EMBEDDED_INPUTS: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+// The compiled in input for a path like src/year2021/day1/depths.txt, if there is one
+pub fn embedded_input(path: &str) -> Option<&'static str> {
+    let path = path.replace('\\', "/");
+    EMBEDDED_INPUTS.iter().find(|(embedded, _)| *embedded == path).map(|(_, input)| *input)
+}
+
+// Parse the input text and solve both parts
+pub fn solve<S: Solver>(input: &str) -> Result<(S::Output, S::Output)> {
+    let input = S::parse(&error::normalize(input))?;
+    Ok((S::solve_part1(&input), S::solve_part2(&input)))
+}
+
+// The answer to one part (1 or 2) of a day like "day7", from the input text
+pub fn solve_input(year: u32, day: &str, part: u8, input: &str) -> Result<Answer> {
+    let no_solution = || AdventError::NoSolution { year, day: day.to_string(), part };
+    let mut registry = registry(year).ok_or_else(no_solution)?;
+    let (_, solution) = registry.iter_mut().find(|(name, _)| *name == day).ok_or_else(no_solution)?;
+    if part != 1 && part != 2 {
+        return Err(no_solution());
+    }
+    solution.parse_input(input)?;
+    Ok(if part == 1 { solution.part1() } else { solution.part2() })
+}
+
+pub type Registry = Vec<(&'static str, Box<dyn Solution>)>;
+
+// The year used when the command line doesn't name one
+pub const DEFAULT_YEAR: u32 = 2021;
+
+// Every year with solutions, oldest first
+pub const YEARS: [u32; 1] = [2021];
+
+// Lookup table of every implemented day in a year, in order
+pub fn registry(year: u32) -> Option<Registry> {
+    match year {
+        2021 => Some(year2021::registry()),
+        _ => None
+    }
+}
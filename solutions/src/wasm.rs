@@ -0,0 +1,24 @@
+/*
+The solutions in the browser, with the wasm feature.
+
+There's no filesystem or terminal there, so this only exports solve(), which takes
+the puzzle input as a string (from a textarea or a fetch) and returns the answer.
+See the README for building the .wasm and the JavaScript glue with wasm-bindgen.
+
+    import init, { solve } from "./advent2021.js";
+    await init();
+    solve(1, 2, input);  // "1739"
+*/
+
+use wasm_bindgen::prelude::*;
+use crate::solution::{self, DEFAULT_YEAR};
+
+// The answer to part 1 or 2 of a day (1 to 25) of 2021.
+// Throws an Error with the same message the command line prints if the day or part
+// doesn't exist or the input isn't valid for the day
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, JsError> {
+    solution::solve_input(DEFAULT_YEAR, &format!("day{}", day), part, input)
+        .map(|answer| answer.to_string())
+        .map_err(|error| JsError::new(&error.to_string()))
+}
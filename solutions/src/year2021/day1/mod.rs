@@ -0,0 +1,159 @@
+/*
+    Day 1: Sonar Sweep
+
+    Each number in a list is a measurement of depth going further out from the submarine.
+
+    Part 1: Count the number of times the depth increases compared to the previous measurement
+
+    Part 2: Rather than using each individual measurement, count the number of increases
+    using a 3 value rolling average.
+    Example: [199, 200, 208, 210] would compare 607 to 618 for an increase of 1
+*/
+use std::io::BufRead;
+use crate::error::{self, Line, Result};
+use crate::solution::Solver;
+
+// The positions of the depths that are bigger than the one before, so index 1 if the second depth went up
+pub fn increases(depths: &[i32]) -> impl Iterator<Item = usize> + '_ {
+    depths.windows(2).enumerate()
+        .filter(|(_, slice)| slice[1] > slice[0])
+        .map(|(index, _)| index + 1)
+}
+
+// The same for the 3 value rolling sums, where window n is the one starting at depth n.
+// Windows n-1 and n share two depths, so window n is bigger when depth n+2 is bigger than depth n-1
+pub fn rolling_increases(depths: &[i32]) -> impl Iterator<Item = usize> + '_ {
+    depths.windows(4).enumerate()
+        .filter(|(_, slice)| slice[3] > slice[0])
+        .map(|(index, _)| index + 1)
+}
+
+pub fn count_increases(depths: &[i32]) -> i32 {
+    increases(depths).count() as i32
+}
+
+pub fn count_rolling(depths: &[i32]) -> i32 {
+    rolling_increases(depths).count() as i32
+}
+
+// Both parts in one pass, keeping only the last 3 depths, so the depths never have to be in memory at once.
+// Consecutive rolling windows share two of their depths, so the window goes up
+// exactly when the depth coming in is bigger than the one 3 places back that drops out
+pub fn count_both(depths: impl IntoIterator<Item = i32>) -> (i32, i32) {
+    let (mut increases, mut rolling) = (0, 0);
+    let mut last3: [Option<i32>; 3] = [None; 3];
+    for (index, depth) in depths.into_iter().enumerate() {
+        if last3[(index + 2) % 3].is_some_and(|previous| depth > previous) {
+            increases += 1;
+        }
+        if last3[index % 3].is_some_and(|dropped| depth > dropped) {
+            rolling += 1;
+        }
+        last3[index % 3] = Some(depth);
+    }
+    (increases, rolling)
+}
+
+// Both parts straight from the file, a line at a time. Stops at the first line that isn't a depth
+pub fn sweep(path: &str) -> Result<(i32, i32)> {
+    let mut reader = error::open_input(path)?;
+    let mut failed = None;
+    let depths = error::stream_lines(&mut reader, path, parse_depth)
+        .map_while(|depth| depth.map_err(|error| failed = Some(error)).ok());
+    let counts = count_both(depths);
+    failed.map_or(Ok(counts), Err)
+}
+
+// Reads the file a line at a time, only the depths are kept
+pub fn read_depths(path: &str) -> Result<Vec<i32>> {
+    Day1::read(&mut error::open_input(path)?, path)
+}
+
+// Same as read_depths, for depths that aren't in a file (stdin, a socket, a string in a test)
+pub fn read_depths_from(mut reader: impl BufRead) -> Result<Vec<i32>> {
+    Day1::read(&mut reader, "day1 input")
+}
+
+pub fn parse_depths(depths: &str) -> Result<Vec<i32>> {
+    error::lines(depths).map(parse_depth).collect()
+}
+
+fn parse_depth(line: Line) -> Result<i32> {
+    line.text.parse().map_err(|_| line.error("day1", "expected a depth"))
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Input = Vec<i32>;
+    type Output = i32;
+
+    const TITLE: &'static str = "Sonar Sweep";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day1/depths.txt";
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        parse_depths(input)
+    }
+
+    fn read(reader: &mut dyn BufRead, path: &str) -> Result<Vec<i32>> {
+        error::stream_lines(reader, path, parse_depth).collect()
+    }
+
+    fn solve_part1(depths: &Vec<i32>) -> i32 {
+        count_increases(depths)
+    }
+
+    fn solve_part2(depths: &Vec<i32>) -> i32 {
+        count_rolling(depths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AdventError;
+
+    #[test]
+    fn test_count() {
+        let depths = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(7, count_increases(&depths));
+    }
+
+    #[test]
+    fn test_rolling2() {
+        let depths = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(5, count_rolling(&depths));
+    }
+
+    #[test]
+    fn test_positions() {
+        let depths = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(vec![1, 2, 3, 5, 6, 7, 9], increases(&depths).collect::<Vec<_>>());
+        // the sums are 607, 618, 618, 617, 647, 716, 769, 792
+        assert_eq!(vec![1, 4, 5, 6, 7], rolling_increases(&depths).collect::<Vec<_>>());
+        assert_eq!(0, rolling_increases(&[1, 2, 3]).count());
+    }
+
+    #[test]
+    fn test_count_both() {
+        let depths = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!((7, 5), count_both(depths));
+        assert_eq!((0, 0), count_both([]));
+        assert_eq!((1, 0), count_both([1, 2, 1]));
+        assert_eq!((7, 5), sweep("src/year2021/day1/example.txt").unwrap());
+    }
+
+    #[test]
+    fn test_read_errors() {
+        assert_eq!(vec![199, 200, 208], read_depths_from("199\n200\n208\n".as_bytes()).unwrap());
+        assert!(matches!(read_depths("src/year2021/day1/missing.txt"), Err(AdventError::MissingInput { .. })));
+        // the bad line is reported rather than panicking
+        match read_depths_from("199\n200\n2o8\n210".as_bytes()) {
+            Err(AdventError::Parse { context: Some(context), .. }) => {
+                assert_eq!(3, context.line);
+                assert_eq!("2o8", context.text);
+            }
+            other => panic!("expected a parse error, got {:?}", other)
+        }
+    }
+}
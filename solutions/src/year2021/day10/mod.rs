@@ -0,0 +1,337 @@
+/*
+Day 10: Syntax Scoring
+
+Each line is composed of nested chunks. A chunk must eventually have a corresponding close character:
+(, [, {, < are all valid open characters.
+A corrupted line has the wrong closing character.
+An incomplete line is missing one or more closing characters at the end
+
+Part 1: Find all the corrupted lines and give them a score based on the first illegal character:
+): 3 points, ]: 57 points, }: 1197 points, >: 25137 points. What is the total error score?
+
+Part 2: Discard the corrupted lines, the remaining lines are incomplete.
+Find the correct closing characters to complete the line in the correct order.
+Using scores ): 1, ]: 2, }: 3, >: 4
+For each character, take the score (starting with 0) and multiple by 5, then add the points for the character.
+Sort the scores. What is the middle score?
+
+
+*/
+use std::collections::HashMap;
+use std::fmt;
+use std::io::BufRead;
+use crate::answer::Answer;
+use crate::error::{self, Line, Result};
+use crate::solution::Solver;
+
+// Part 1 & Part 2
+// Both parts ended up being so similar, that I combined both into one method
+// Returns a tuple - first value is part 1, second is part 2 (None if no line is incomplete)
+// check_line works out whether each line is corrupted or incomplete, and this scores them
+pub fn syntax_score(lines: &[String]) -> (i64, Option<i64>) {
+    syntax_score_with(lines, &Syntax::default())
+}
+
+// Part 1 on its own. Takes the lines one at a time, so they never have to be in memory at once
+pub fn corruption_score<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> i64 {
+    corruption_score_with(lines, &Syntax::default())
+}
+
+// Part 2 on its own. Only the score of each incomplete line is kept, to find the middle one.
+// None if there are no incomplete lines, only corrupted lines and ones that are already complete
+pub fn completion_score<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> Option<i64> {
+    completion_score_with(lines, &Syntax::default())
+}
+
+// The brackets that pair up, and the points for the two parts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Syntax {
+    // (open, close) for each kind of chunk
+    pub pairs: Vec<(char, char)>,
+    // Part 1: points for the illegal closing character of a corrupted line
+    pub corrupted_scores: HashMap<char, i64>,
+    // Part 2: points for each closing character that completes a line
+    pub completion_scores: HashMap<char, i64>
+}
+
+impl Syntax {
+    // The navigation subsystem from the puzzle: (), [], {} and <>
+    pub fn navigation() -> Syntax {
+        Syntax {
+            pairs: vec![('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')],
+            corrupted_scores: [(')', 3), (']', 57), ('}', 1197), ('>', 25137)].into_iter().collect(),
+            completion_scores: [')', ']', '}', '>'].into_iter().zip(1..=4).collect()
+        }
+    }
+
+    fn closing(&self, open: char) -> Option<char> {
+        self.pairs.iter().find(|&&(pair_open, _)| pair_open == open).map(|&(_, close)| close)
+    }
+
+    fn is_closing(&self, c: char) -> bool {
+        self.pairs.iter().any(|&(_, close)| close == c)
+    }
+}
+
+// The puzzle's brackets and points
+impl Default for Syntax {
+    fn default() -> Syntax {
+        Syntax::navigation()
+    }
+}
+
+// syntax_score for any brackets and points. Panics if a closing character has no points
+pub fn syntax_score_with(lines: &[String], syntax: &Syntax) -> (i64, Option<i64>) {
+    (corruption_score_with(lines, syntax), completion_score_with(lines, syntax))
+}
+
+pub fn corruption_score_with<S: AsRef<str>>(lines: impl IntoIterator<Item = S>, syntax: &Syntax) -> i64 {
+    lines.into_iter().enumerate().map(|(number, line)| match check_line_with(line.as_ref(), syntax) {
+        Checked::Corrupted(error) => {
+            log::debug!("line {} is corrupted: {}", number + 1, error);
+            points(&syntax.corrupted_scores, error.found)
+        }
+        Checked::Incomplete(_) => 0
+    }).sum()
+}
+
+pub fn completion_score_with<S: AsRef<str>>(lines: impl IntoIterator<Item = S>, syntax: &Syntax) -> Option<i64> {
+    let mut incomplete: Vec<i64> = lines.into_iter().filter_map(|line| match check_line_with(line.as_ref(), syntax) {
+        Checked::Incomplete(completion) if !completion.is_empty() => Some(completion.chars().fold(0, |score, c|{
+            score * 5 + points(&syntax.completion_scores, c)
+        })),
+        _ => None
+    }).collect();
+
+    incomplete.sort();
+    incomplete.get(incomplete.len() / 2).copied()
+}
+
+fn points(scores: &HashMap<char, i64>, c: char) -> i64 {
+    *scores.get(&c).unwrap_or_else(|| panic!("there are no points for '{}'", c))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Checked {
+    // Where the first closing character that doesn't close the chunk that's open is
+    Corrupted(SyntaxError),
+    // The closing characters that finish the line, in order. Empty if every chunk is already closed
+    Incomplete(String)
+}
+
+// The first illegal closing character on a corrupted line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxError {
+    // Byte index of the character in the line
+    pub index: usize,
+    // The character that would have closed the innermost open chunk, None if no chunk was open
+    pub expected: Option<char>,
+    pub found: char
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.expected {
+            Some(expected) => write!(f, "expected '{}', found '{}' at {}", expected, self.found, self.index),
+            None => write!(f, "found '{}' at {} with no chunk open", self.found, self.index)
+        }
+    }
+}
+
+impl std::error::Error for SyntaxError {}
+
+pub fn check_line(line: &str) -> Checked {
+    check_line_with(line, &Syntax::default())
+}
+
+// Keeps a LIFO stack of the next closing character.
+// Each time an open character is encountered, the corresponding close character is added to the stack.
+// if the next character is a closing character but not the next value on the stack
+//      then this is an illegal line
+// If we traverse the line without an illegal closing character
+//      then what is left in the stack are the required closing characters to complete the line
+// Characters that aren't one of the syntax's brackets are skipped over
+pub fn check_line_with(line: &str, syntax: &Syntax) -> Checked {
+    let mut next_closing_stack: Vec<char> = vec![];
+    for (index, next_char) in line.char_indices() {
+        // if "next_char" is an open character, add the corresponding close char to the stack
+        if let Some(close_char) = syntax.closing(next_char) {
+            next_closing_stack.push(close_char);
+        } else if syntax.is_closing(next_char) {
+            // if it's a close char that isn't the one popped off the stack
+            // (or there's nothing open to close) this is an illegal line
+            let expected = next_closing_stack.pop();
+            if expected != Some(next_char) {
+                return Checked::Corrupted(SyntaxError { index, expected, found: next_char });
+            }
+        }
+    }
+    // reverse the stack to get the appropriate order of the required close characters
+    Checked::Incomplete(next_closing_stack.into_iter().rev().collect())
+}
+
+// Every line checked, in order
+pub fn check_lines(lines: &[String]) -> Vec<Checked> {
+    let syntax = Syntax::default();
+    lines.iter().map(|line| check_line_with(line, &syntax)).collect()
+}
+
+// The line with the closing characters it's missing added on the end, None if it's corrupted
+pub fn repair(line: &str) -> Option<String> {
+    match check_line(line) {
+        Checked::Incomplete(completion) => Some(format!("{}{}", line, completion)),
+        Checked::Corrupted(_) => None
+    }
+}
+
+// Reads the file a line at a time
+pub fn read_lines(path: &str) -> Result<Vec<String>> {
+    Day10::read(&mut error::open_input(path)?, path)
+}
+
+pub fn parse_lines(input: &str) -> Result<Vec<String>> {
+    error::lines(input).map(parse_line).collect()
+}
+
+// Lines are made of brackets only: ()[]{}<>
+fn parse_line(line: Line) -> Result<String> {
+    match line.text.char_indices().find(|&(_, c)| !"()[]{}<>".contains(c)) {
+        Some((i, c)) => Err(line.error_in("day10", &line.text[i..i + c.len_utf8()], format!("'{}' isn't a bracket", c))),
+        None => Ok(line.text.to_string())
+    }
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<String>;
+    type Output = Answer;
+
+    const TITLE: &'static str = "Syntax Scoring";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day10/lines.txt";
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        parse_lines(input)
+    }
+
+    fn read(reader: &mut dyn BufRead, path: &str) -> Result<Vec<String>> {
+        error::stream_lines(reader, path, parse_line).collect()
+    }
+
+    fn solve_part1(lines: &Vec<String>) -> Answer {
+        corruption_score(lines).into()
+    }
+
+    // Without any incomplete lines there's no middle score, the answer says so instead
+    fn solve_part2(lines: &Vec<String>) -> Answer {
+        completion_score(lines).map_or_else(|| "no incomplete lines to score".to_string().into(), Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_data() -> Vec<String> {
+        let data = "[({(<(())[]>[[{[]{<()<>>
+            [(()[<>])]({[<{<<[]>>(
+            {([(<{}[<>[]}>{[]{[(<()>
+            (((({<>}<{<{<>}{[]{[]{}
+            [[<[([]))<([[{}[[()]]]
+            [{[{({}]{}}([{[{{{}}([]
+            {<[[]]>}<{[{[{[]{()[[[]
+            [<(<(<(<{}))><([]([]()
+            <{([([[(<>()){}]>(<<{{
+            <{([{{}}[<[[[<>{}]]]>[]]";
+        data.lines().map(|line| line.trim().to_string()).collect()
+    }
+
+    #[test]
+    fn test_score_illegal_chars() {
+        let lines = test_data();
+        assert_eq!((26397, Some(288957)), syntax_score(&lines));
+    }
+
+    #[test]
+    fn test_separate_parts() {
+        assert_eq!(26397, corruption_score(test_data()));
+        assert_eq!(Some(288957), completion_score(test_data()));
+        // straight from a reader, without collecting the lines first
+        let input = test_data().join("\n");
+        let lines = || input.as_bytes().lines().map(|line| line.unwrap());
+        assert_eq!(26397, corruption_score(lines()));
+        assert_eq!(Some(288957), completion_score(lines()));
+    }
+
+    #[test]
+    fn test_check_lines() {
+        let checked = check_lines(&test_data());
+        assert_eq!(Checked::Incomplete("}}]])})]".to_string()), checked[0]);
+        assert_eq!(Checked::Corrupted(SyntaxError { index: 12, expected: Some(']'), found: '}' }), checked[2]);
+        assert_eq!(Checked::Incomplete("])}>".to_string()), checked[9]);
+        let corrupted: String = checked.iter().filter_map(|checked| match checked {
+            Checked::Corrupted(error) => Some(error.found),
+            Checked::Incomplete(_) => None
+        }).collect();
+        assert_eq!("})])>", corrupted);
+        assert_eq!(Checked::Incomplete(String::new()), check_line("{()}<>"));
+        assert_eq!(Checked::Corrupted(SyntaxError { index: 2, expected: None, found: ')' }), check_line("())"));
+    }
+
+    #[test]
+    fn test_other_syntax() {
+        // html-ish tags, with the text between them skipped
+        let syntax = Syntax {
+            pairs: vec![('<', '>'), ('«', '»')],
+            corrupted_scores: [('>', 1), ('»', 10)].into_iter().collect(),
+            completion_scores: [('>', 1), ('»', 2)].into_iter().collect()
+        };
+        assert_eq!(Checked::Incomplete("»>".to_string()), check_line_with("<a href «x» «y", &syntax));
+        // « takes 2 bytes
+        assert_eq!(Checked::Corrupted(SyntaxError { index: 5, expected: Some('»'), found: '>' }), check_line_with("«<b>>»", &syntax));
+        // the brackets that aren't in it are just text
+        assert_eq!(Checked::Incomplete(String::new()), check_line_with("(]", &syntax));
+        let lines = ["«<b>>»", "<«", "«", "<»", "<"].map(String::from);
+        // the incomplete lines score 2 * 5 + 1 = 11, 2 and 1, the middle of the three is 2
+        assert_eq!((11, Some(2)), syntax_score_with(&lines, &syntax));
+    }
+
+    #[test]
+    fn test_nothing_incomplete() {
+        // one corrupted line and one that's already complete, so no middle score to pick
+        let lines = vec!["(]".to_string(), "()".to_string()];
+        assert_eq!((57, None), syntax_score(&lines));
+        assert_eq!(Answer::Text("no incomplete lines to score".to_string()), Day10::solve_part2(&lines));
+    }
+
+    #[test]
+    #[should_panic(expected = "there are no points for ')'")]
+    fn test_missing_points() {
+        let mut syntax = Syntax::navigation();
+        syntax.corrupted_scores.remove(&')');
+        syntax_score_with(&["(]".to_string(), "())".to_string()], &syntax);
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let errors: Vec<String> = check_lines(&test_data()).into_iter().filter_map(|checked| match checked {
+            Checked::Corrupted(error) => Some(error.to_string()),
+            Checked::Incomplete(_) => None
+        }).collect();
+        assert_eq!(vec!["expected ']', found '}' at 12", "expected ']', found ')' at 8", "expected ')', found ']' at 7",
+            "expected '>', found ')' at 10", "expected ']', found '>' at 16"], errors);
+        assert_eq!("found '>' at 0 with no chunk open", SyntaxError { index: 0, expected: None, found: '>' }.to_string());
+    }
+
+    #[test]
+    fn test_repair() {
+        assert_eq!(Some("<{([{{}}[<[[[<>{}]]]>[]]])}>".to_string()), repair("<{([{{}}[<[[[<>{}]]]>[]]"));
+        assert_eq!(None, repair("{([(<{}[<>[]}>{[]{[(<()>"));
+        // a repaired line is complete
+        for line in test_data() {
+            if let Some(repaired) = repair(&line) {
+                assert_eq!(Checked::Incomplete(String::new()), check_line(&repaired));
+            }
+        }
+    }
+}
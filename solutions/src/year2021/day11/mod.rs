@@ -0,0 +1,299 @@
+/*
+Day 11: Dumbo Octopus
+
+A 2d grid of octopuses (heretofore octopi), each have an energy state.
+Every step, that energy state increases by 1.
+If the energy state exceeds 9, then the octopus "flashes".
+When an octopus flashes, all adjacent octopi (including diagonals) gain 1 energy
+No octopus can flash more than once per steps. All flashed octopi reset to 0 at the end of the step.
+
+Part 1: How many flashes happen after 100 steps?
+
+Part 2: What is the first step in which all octopi flash?
+
+The grid can be any size. Octopi on the edge have fewer neighbors, unless the edges wrap around
+(Edges::Wrap), then the grid is a torus and every octopus has 8 neighbors.
+*/
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use crate::animate::{self, Animation};
+use crate::answer::Answer;
+use crate::grid::{Grid, Position};
+use crate::error::{self, AdventError, Result};
+use crate::solution::Solver;
+
+// Part 1 - a lot of logic is reused for parts 1 and 2
+// go one step at a time, counting the number of flashes each step
+pub fn flash_after_steps(octopi: &Grid<i32>, steps: i32) -> i32 {
+    flash_series(octopi).take(steps as usize).sum()
+}
+
+// The number of flashes on each step, starting with step 1. Never ends, take as many steps as are needed.
+// Part 1 is the sum of the first 100, part 2 is the first step where every octopus flashes
+pub fn flash_series(octopi: &Grid<i32>) -> impl Iterator<Item = i32> {
+    flash_series_with(octopi, Edges::Stop)
+}
+
+// flash_series, picking what happens at the edges of the grid
+pub fn flash_series_with(octopi: &Grid<i32>, edges: Edges) -> impl Iterator<Item = i32> {
+    steps_with(octopi, edges).map(|(flashes, _)| flashes)
+}
+
+// The flashes on each step along with the grid after it, for part 2 to draw and to spot repeats
+fn steps_with(octopi: &Grid<i32>, edges: Edges) -> impl Iterator<Item = (i32, Grid<i32>)> {
+    let mut octopi = octopi.clone();
+    std::iter::from_fn(move || {
+        let flashes = do_step(&mut octopi, edges);
+        Some((flashes, octopi.clone()))
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    // The puzzle's grid: nothing past the edge, so the octopi along it have fewer neighbors
+    Stop,
+    // The edges join up with the opposite side, an octopus on the top row is next to the ones on the bottom row
+    Wrap
+}
+
+// The first step in a series of flashes where all of the octopi flash, None if it runs out before then
+pub fn first_all_flash(series: impl IntoIterator<Item = i32>, octopi: usize) -> Option<i32> {
+    series.into_iter().position(|flashes| flashes as usize == octopi).map(|index| index as i32 + 1)
+}
+
+// Part 2
+// first_all_flash over the series of flashes, one step at a time
+// (this is the one --animate shows, it ends with the whole grid lighting up)
+// Every energy level is back to 0-9 after a step, so there are only so many states the grid can be in.
+// The state after each step is kept, and if one comes round again without all the octopi
+// flashing then they never will, so the series stops there
+pub fn find_all_flash(octopi: &Grid<i32>) -> Synchronized {
+    let mut animation = Animation::start();
+    let mut seen: HashMap<Grid<i32>, i32> = HashMap::new();
+    let mut cycle = None;
+    let series = steps_with(octopi, Edges::Stop).zip(1..).map_while(|((flashes, after), step)| {
+        animation.frame(|| draw_octopi(&after, step, flashes));
+        if flashes as usize != octopi.len() {
+            if let Some(start) = seen.insert(after, step) {
+                cycle = Some(Synchronized::Cycle { start, len: step - start });
+                return None;
+            }
+        }
+        Some(flashes)
+    });
+    match first_all_flash(series, octopi.len()) {
+        Some(step) => Synchronized::StepFound(step),
+        None => cycle.expect("the series only stops when the grid repeats")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Synchronized {
+    // The first step where every octopus flashes
+    StepFound(i32),
+    // The octopi never all flash together. The grid after step start comes back every len steps
+    Cycle { start: i32, len: i32 }
+}
+
+impl fmt::Display for Synchronized {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Synchronized::StepFound(step) => write!(f, "{}", step),
+            Synchronized::Cycle { start, len } => write!(f, "never, the octopi repeat every {} steps from step {}", len, start)
+        }
+    }
+}
+
+// Part 2's answer is the step, or text saying why there isn't one
+impl From<Synchronized> for Answer {
+    fn from(synchronized: Synchronized) -> Answer {
+        match synchronized {
+            Synchronized::StepFound(step) => step.into(),
+            cycle => cycle.to_string().into()
+        }
+    }
+}
+
+// Every octopus shows its energy level, the ones that just flashed (back at 0) are highlighted
+fn draw_octopi(octopi: &Grid<i32>, step: i32, flashes: i32) -> String {
+    let rows: Vec<String> = octopi.rows()
+        .map(|row| row.iter().map(|&energy| match energy {
+            0 => animate::highlight("0"),
+            _ => char::from_digit(energy as u32, 10).unwrap_or('?').to_string()
+        }).collect())
+        .collect();
+    format!("step {}: {} flashes\n\n{}", step, flashes, rows.join("\n"))
+}
+
+// This function does the work for updating the octopi state each step
+// Add 1 to the energy level of every octopus, any that go over 9 are queued up to flash
+// Take octopi off the queue one at a time. Each one that hasn't flashed yet this step:
+//      flashes, and adds 1 to the energy of all adjacent octopi (including diagonals)
+//      any of those that go over 9 join the queue
+// A grid of booleans tracks the octopi that flash this step, so none flash twice
+// once the step is over, reset each flash octopi to 0
+// return the total number of flashes this step
+fn do_step(octopi: &mut Grid<i32>, edges: Edges) -> i32 {
+    let mut flashed = Grid::filled(octopi.width(), octopi.height(), false);
+    let mut queue: VecDeque<Position> = VecDeque::new();
+    for octopus in octopi.positions() {
+        octopi[octopus] += 1;
+        if octopi[octopus] > 9 {
+            queue.push_back(octopus);
+        }
+    }
+
+    let mut flashes = 0;
+    while let Some(octopus) = queue.pop_front() {
+        if flashed[octopus] {
+            continue;
+        }
+        flashed[octopus] = true;
+        flashes += 1;
+        for adjacent in adjacent(octopi, octopus, edges) {
+            octopi[adjacent] += 1;
+            if octopi[adjacent] > 9 && !flashed[adjacent] {
+                queue.push_back(adjacent);
+            }
+        }
+    }
+
+    // reset flash octopi to 0
+    for octopus in octopi.positions().filter(|&octopus| flashed[octopus]) {
+        octopi[octopus] = 0;
+    }
+
+    flashes
+}
+
+fn adjacent(octopi: &Grid<i32>, octopus: Position, edges: Edges) -> Vec<Position> {
+    match edges {
+        Edges::Stop => octopi.neighbors8(octopus).collect(),
+        Edges::Wrap => octopi.wrapping_neighbors8(octopus).collect()
+    }
+}
+
+pub fn read_octopi(path: &str) -> Result<Grid<i32>> {
+    let input = error::read_input(path)?;
+    parse_data(&input)
+}
+
+// A row of energy levels from 0 to 9 per line. The rows can be any length as long as they're all the same
+pub fn parse_data(input: &str) -> Result<Grid<i32>> {
+    let octopi = Grid::parse("day11", input, |c| c.to_digit(10).map(|energy| energy as i32))?;
+    if octopi.is_empty() {
+        return Err(AdventError::parse("day11", "expected a grid of energy levels"));
+    }
+    Ok(octopi)
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Grid<i32>;
+    type Output = Answer;
+
+    const TITLE: &'static str = "Dumbo Octopus";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day11/octopi.txt";
+
+    fn parse(input: &str) -> Result<Grid<i32>> {
+        parse_data(input)
+    }
+
+    fn solve_part1(octopi: &Grid<i32>) -> Answer {
+        flash_after_steps(octopi, 100).into()
+    }
+
+    fn solve_part2(octopi: &Grid<i32>) -> Answer {
+        find_all_flash(octopi).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_data() -> Grid<i32> {
+        let test_input = "5483143223
+            2745854711
+            5264556173
+            6141336146
+            6357385478
+            4167524645
+            2176841721
+            6882881134
+            4846848554
+            5283751526";
+        parse_data(test_input).unwrap()
+    }
+
+    #[test]
+    fn test_flashes_after_steps() {
+        let octopi = test_data();
+        assert_eq!(1656, flash_after_steps(&octopi, 100));
+    }
+
+    #[test]
+    fn test_all_flash() {
+        let octopi = test_data();
+        assert_eq!(Synchronized::StepFound(195), find_all_flash(&octopi));
+    }
+
+    #[test]
+    fn test_draw_octopi() {
+        let octopi = parse_data("109\n000").unwrap();
+        let drawn = draw_octopi(&octopi, 3, 4);
+        let flashed = animate::highlight("0");
+        assert_eq!(format!("step 3: 4 flashes\n\n1{}9\n{}{}{}", flashed, flashed, flashed, flashed), drawn);
+    }
+
+    #[test]
+    fn test_flash_series() {
+        let octopi = test_data();
+        let series: Vec<i32> = flash_series(&octopi).take(195).collect();
+        assert_eq!(vec![0, 35, 45, 16, 8, 1, 7, 24, 39, 29], series[..10]);
+        assert_eq!(1656, series[..100].iter().sum::<i32>());
+        assert_eq!(Some(195), first_all_flash(series.iter().copied(), octopi.len()));
+        assert_eq!(None, first_all_flash(series[..194].iter().copied(), octopi.len()));
+    }
+
+    #[test]
+    fn test_other_sizes() {
+        // the small example from the puzzle, the 9s flash on step 1 and set off the middle
+        let octopi = parse_data("11111\n19991\n19191\n19991\n11111").unwrap();
+        assert_eq!(vec![9, 0], flash_series(&octopi).take(2).collect::<Vec<_>>());
+        let row = parse_data("9999999").unwrap();
+        assert_eq!(Some(1), first_all_flash(flash_series(&row), row.len()));
+        assert!(matches!(parse_data("123\n45"), Err(AdventError::Parse { .. })));
+        assert!(matches!(parse_data("\n"), Err(AdventError::Parse { context: None, .. })));
+    }
+
+    #[test]
+    fn test_wrapping_edges() {
+        // the corner has 8 neighbors when the edges wrap, so one flash lifts every other octopus
+        let octopi = parse_data("900\n000\n000").unwrap();
+        assert_eq!(vec![1, 0], flash_series(&octopi).take(2).collect::<Vec<_>>());
+        let mut wrapped = flash_series_with(&octopi, Edges::Wrap);
+        assert_eq!(Some(1), wrapped.next());
+        // everything else is now at 2, after 8 more steps they all reach 10 together
+        assert_eq!(Some(9), first_all_flash(wrapped, octopi.len()).map(|step| step + 1));
+    }
+
+    #[test]
+    fn test_never_synchronized() {
+        // every octopus is next to the other 3. The pairs take turns flashing, and each time
+        // they lift the other pair by 2, from 5 to 7 and from 0 to 2 (then 3 steps later to 5)
+        let octopi = parse_data("00\n55").unwrap();
+        assert_eq!(Synchronized::Cycle { start: 1, len: 8 }, find_all_flash(&octopi));
+        assert_eq!(None, first_all_flash(flash_series(&octopi).take(1000), octopi.len()));
+        assert_eq!(Answer::Text("never, the octopi repeat every 8 steps from step 1".to_string()), find_all_flash(&octopi).into());
+        assert_eq!(Answer::Int(195), find_all_flash(&test_data()).into());
+    }
+
+    #[test]
+    fn test_long_cascade() {
+        // a 9 at the start of a long row of 8s, each flash sets off the next. Too deep to follow by recursing
+        let octopi = parse_data(&format!("9{}", "8".repeat(200_000))).unwrap();
+        assert_eq!(vec![200_001, 0], flash_series(&octopi).take(2).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,392 @@
+/*
+Day 12: Passage Pathing
+
+A series of caves are connected from tunnels. Caves with lower case names are small caves.
+Start at the cave labeled "start" and end at the cave labeled "end"
+
+Part 1: Map all possible paths through the caves. No path can pass through the same small cave twice.
+
+Part 2: Map all possible paths, but this time, any one sigle small cave can be visited twice.
+
+Both parts are presets of Rules: the most visits to a small cave, and how many small caves can get more than one.
+*/
+
+use std::collections::HashMap;
+use crate::error::{self, Result};
+use crate::solution::Solver;
+
+// The struct mostly exists because I wanted to build a graph with edges.
+// But I had to abandon that approach due to being bad at Rust.
+// https://github.com/nrc/r4cppp/blob/master/graphs/README.md
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct Cave {
+    name: String,
+    is_large: bool,
+}
+
+impl Cave {
+    fn new(name: String) -> Cave {
+        let is_large = name == name.to_ascii_uppercase();
+        Cave { name, is_large }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+// Part 1: Most logic is combined with part 2
+pub fn count_total_paths(graph: &HashMap<Cave, Vec<Cave>>) -> usize {
+    count_paths_with(graph, &Rules::ONCE)
+}
+
+// Part 2
+pub fn count_paths_visit_twice(graph: &HashMap<Cave, Vec<Cave>>) -> usize {
+    count_paths_with(graph, &Rules::ONE_TWICE)
+}
+
+// How often a path can go through the same small cave. Large caves can always be visited again,
+// start only ever at the beginning, and the path stops as soon as it gets to end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    // The most times any one small cave can be visited
+    pub max_visits: u8,
+    // How many different small caves can be visited more than once
+    pub revisited_caves: usize
+}
+
+impl Rules {
+    // Part 1: no small cave twice
+    pub const ONCE: Rules = Rules { max_visits: 1, revisited_caves: 0 };
+    // Part 2: one small cave twice, the rest once
+    pub const ONE_TWICE: Rules = Rules { max_visits: 2, revisited_caves: 1 };
+
+    // Whether a small cave visited so many times already can be visited again,
+    // when revisited small caves have been visited more than once
+    fn allows(&self, visits: u8, revisited: usize) -> bool {
+        visits < self.max_visits && (visits != 1 || revisited < self.revisited_caves)
+    }
+}
+
+// Counting only depends on where the path is now and how many times it's been through each small cave,
+// not on the order it went through them. The visits to each small cave are packed into a u64,
+// with just enough bits per cave to count up to max_visits, and the count from each
+// (cave, visits) is worked out once and remembered.
+// Once no more caves can be revisited, a cave visited once can't be visited again any more than
+// one that's at max_visits, so it's counted as max_visits. Paths that revisited different caves
+// then end up with the same visits, and are only counted once (for part 2 that's most of them).
+// A graph with too many small caves for the bits goes back to walking every path
+pub fn count_paths_with(graph: &HashMap<Cave, Vec<Cave>>, rules: &Rules) -> usize {
+    let caves = CaveIndex::new(graph, rules);
+    if caves.small_count * caves.bits as usize > u64::BITS as usize {
+        let start = graph.keys().find(|cave| cave.name == "start").unwrap();
+        return count_paths(start, &mut vec![], graph, rules);
+    }
+    let visits = caves.small[caves.start].map_or(0, |start| caves.with_visits(0, start, 1));
+    caves.count_from(caves.start, caves.settle(visits, 0, rules), 0, rules, &mut HashMap::new())
+}
+
+// The caves numbered 0..n, with tunnels between the numbers
+struct CaveIndex {
+    start: usize,
+    end: usize,
+    adjacent: Vec<Vec<usize>>,
+    // The small caves are numbered again 0..small_count for their visit counts, None for the large ones
+    small: Vec<Option<usize>>,
+    small_count: usize,
+    // Bits for each small cave's visits
+    bits: u32
+}
+
+impl CaveIndex {
+    fn new(graph: &HashMap<Cave, Vec<Cave>>, rules: &Rules) -> CaveIndex {
+        let caves: Vec<&Cave> = graph.keys().collect();
+        let numbers: HashMap<&Cave, usize> = caves.iter().enumerate().map(|(number, &cave)| (cave, number)).collect();
+        let number = |name: &str| caves.iter().position(|cave| cave.name == name).unwrap();
+        let mut next_small = 0..;
+        let small: Vec<Option<usize>> = caves.iter().map(|cave| if cave.is_large { None } else { next_small.next() }).collect();
+        CaveIndex {
+            start: number("start"),
+            end: number("end"),
+            adjacent: caves.iter().map(|&cave| graph[cave].iter().map(|adjacent| numbers[adjacent]).collect()).collect(),
+            small_count: small.iter().flatten().count(),
+            small,
+            bits: u8::BITS - rules.max_visits.leading_zeros()
+        }
+    }
+
+    fn visits(&self, visits: u64, small: usize) -> u8 {
+        ((visits >> (small as u32 * self.bits)) & ((1 << self.bits) - 1)) as u8
+    }
+
+    fn with_visits(&self, visits: u64, small: usize, count: u8) -> u64 {
+        let shift = small as u32 * self.bits;
+        visits & !(((1 << self.bits) - 1) << shift) | (u64::from(count) << shift)
+    }
+
+    // Every cave visited once counts as max_visits when there are no revisits left
+    fn settle(&self, visits: u64, revisited: usize, rules: &Rules) -> u64 {
+        if revisited < rules.revisited_caves {
+            return visits;
+        }
+        (0..self.small_count).filter(|&small| self.visits(visits, small) == 1)
+            .fold(visits, |visits, small| self.with_visits(visits, small, rules.max_visits))
+    }
+
+    // revisited is how many of the small caves in visits are over 1
+    fn count_from(&self, cave: usize, visits: u64, revisited: usize, rules: &Rules, memo: &mut HashMap<(usize, u64), usize>) -> usize {
+        if cave == self.end {
+            return 1;
+        }
+        if let Some(&count) = memo.get(&(cave, visits)) {
+            return count;
+        }
+        let mut count = 0;
+        for &adjacent in &self.adjacent[cave] {
+            if adjacent == self.start {
+                continue;
+            }
+            match self.small[adjacent] {
+                None => count += self.count_from(adjacent, visits, revisited, rules, memo),
+                Some(small) => {
+                    let before = self.visits(visits, small);
+                    if !rules.allows(before, revisited) {
+                        continue;
+                    }
+                    let next = if before == 0 && revisited >= rules.revisited_caves {
+                        self.with_visits(visits, small, rules.max_visits)
+                    } else if before == 1 {
+                        // that was the last revisit, settle the rest
+                        self.settle(self.with_visits(visits, small, 2), revisited + 1, rules)
+                    } else {
+                        self.with_visits(visits, small, before + 1)
+                    };
+                    let revisited = if before == 1 { revisited + 1 } else { revisited };
+                    count += self.count_from(adjacent, next, revisited, rules, memo);
+                }
+            }
+        }
+        memo.insert((cave, visits), count);
+        count
+    }
+}
+
+// The same walk as recurse_paths, but only counting the paths that reach the end.
+// There's one path, added to and taken back off as the walk goes in and out of each cave
+fn count_paths<'a>(root: &'a Cave, path: &mut Vec<&'a Cave>, graph: &'a HashMap<Cave, Vec<Cave>>, rules: &Rules) -> usize {
+    if !can_visit(root, path, rules) {
+        return 0;
+    }
+    if root.name == "end" {
+        return 1;
+    }
+
+    path.push(root);
+    let count = graph.get(root).unwrap().iter()
+        .map(|adjacent| count_paths(adjacent, path, graph, rules))
+        .sum();
+    path.pop();
+    count
+}
+
+// Every path from start to end, each one the caves in the order they're visited.
+// Only for looking at the paths, counting them doesn't need them all in memory at once
+pub fn list_paths<'a>(graph: &'a HashMap<Cave, Vec<Cave>>, rules: &Rules) -> Vec<Vec<&'a Cave>> {
+    let start = graph.keys().find(|cave| cave.name == "start").unwrap();
+    recurse_paths(start, &[], graph, rules).unwrap()
+}
+
+// Whether the path can go on into root
+fn can_visit(root: &Cave, path: &[&Cave], rules: &Rules) -> bool {
+    if root.is_large {
+        return true;
+    }
+    // start is only at the beginning
+    if root.name == "start" {
+        return path.is_empty();
+    }
+    let small_count: HashMap<&Cave, u8> = path.iter()
+        .filter(|c| !c.is_large)
+        .fold(HashMap::new(), |mut map, cave| {
+            *map.entry(cave).or_insert(0) += 1;
+            map
+        });
+    let visits = small_count.get(root).copied().unwrap_or(0);
+    rules.allows(visits, small_count.values().filter(|&&count| count > 1).count())
+}
+
+/**
+ * Recursive method that finds the next step in a path.
+ * root - the current cave we are in
+ * path - list of caves we have visited to get to this point
+ * graph - representation of the cave system
+ * rules - how many times small caves can be visited, part 1 vs part 2
+ * 
+ * First, look to see if we are in an invalid path state, if so, return None
+ * If we are at the "end" return this exact path
+ * Otherwise, create a series of potential paths by calling recurse_paths on all adjacent caves
+ * 
+ * Bonus: I did lifetimes! A small consolation for failing at a graph structure
+ */
+fn recurse_paths<'a>(root: &'a Cave, path: &[&'a Cave], graph: &'a HashMap<Cave, Vec<Cave>>, rules: &Rules) -> Option<Vec<Vec<&'a Cave>>> {
+    if !can_visit(root, path, rules) {
+        return None;
+    }
+
+    // clone path - we make a new path vector for each choice of next cave
+    let mut current_path = path.to_vec();
+    current_path.push(root);
+    if root.name == "end" {
+        return Some(vec![current_path])
+    }
+
+    // filter_map removes Nones - those paths are dead ends
+    // flat map to reduce back to a list of "paths", rather than a list of list of paths.
+    Some(graph.get(root).unwrap().iter()
+        .filter_map(|adjacent| recurse_paths(adjacent, &current_path, graph, rules))
+        .flatten()
+        .collect())
+
+}
+
+pub fn read_paths(path: &str) -> Result<HashMap<Cave, Vec<Cave>>> {
+    let input = error::read_input(path)?;
+    parse_input(&input)
+}
+
+pub fn parse_input(input: &str) -> Result<HashMap<Cave, Vec<Cave>>> {
+    let mut graph: HashMap<Cave, Vec<Cave>> = HashMap::new();
+
+    // map together caves - but unable to map to references of caves (instead, .clone() a bunch)
+    // this is definitely the wrong way to do this, the right way probably involves Rc<RefCell<Cave>> or something
+    // Graphs are an especially hard problem in rust.
+    for line in error::lines(input) {
+        let (c1, c2) = line.text.split_once("-")
+            .filter(|(c1, c2)| !c1.is_empty() && !c2.is_empty())
+            .ok_or_else(|| line.error("day12", "expected a tunnel like \"start-A\""))?;
+        let c1 = Cave::new(c1.to_string());
+        let c2 = Cave::new(c2.to_string());
+
+        let c1_map = graph.entry(c1.clone()).or_insert(vec![]);
+        c1_map.push(c2.clone());
+        let c2_map = graph.entry(c2).or_insert(vec![]);
+        c2_map.push(c1);
+    }
+
+    Ok(graph)
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = HashMap<Cave, Vec<Cave>>;
+    type Output = usize;
+
+    const TITLE: &'static str = "Passage Pathing";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day12/paths.txt";
+
+    fn parse(input: &str) -> Result<HashMap<Cave, Vec<Cave>>> {
+        parse_input(input)
+    }
+
+    fn solve_part1(graph: &HashMap<Cave, Vec<Cave>>) -> usize {
+        count_total_paths(graph)
+    }
+
+    fn solve_part2(graph: &HashMap<Cave, Vec<Cave>>) -> usize {
+        count_paths_visit_twice(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_paths_simple() {
+        let input = "start-A
+            start-b
+            A-c
+            A-b
+            b-d
+            A-end
+            b-end";
+        let graph = parse_input(input).unwrap();
+        assert_eq!(10, count_total_paths(&graph));
+        assert_eq!(36, count_paths_visit_twice(&graph));
+    }
+
+    #[test]
+    fn test_all_paths_advanced() {
+        let input = "fs-end
+            he-DX
+            fs-he
+            start-DX
+            pj-DX
+            end-zg
+            zg-sl
+            zg-pj
+            pj-he
+            RW-he
+            fs-DX
+            pj-RW
+            zg-RW
+            start-pj
+            he-WI
+            zg-he
+            pj-fs
+            start-RW";
+        let graph = parse_input(input).unwrap();
+        assert_eq!(226, count_total_paths(&graph));
+        assert_eq!(3509, count_paths_visit_twice(&graph));
+    }
+
+    #[test]
+    fn test_list_paths() {
+        let graph = parse_input("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end").unwrap();
+        let mut paths: Vec<String> = list_paths(&graph, &Rules::ONCE).iter()
+            .map(|path| path.iter().map(|cave| cave.name()).collect::<Vec<_>>().join(","))
+            .collect();
+        paths.sort();
+        assert_eq!(10, paths.len());
+        assert_eq!("start,A,b,A,c,A,end", paths[0]);
+        assert_eq!("start,b,end", paths[9]);
+        assert_eq!(36, list_paths(&graph, &Rules::ONE_TWICE).len());
+    }
+
+    #[test]
+    fn test_other_rules() {
+        // counting and listing agree on every set of rules
+        let graph = parse_input("dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc").unwrap();
+        assert_eq!(19, count_total_paths(&graph));
+        assert_eq!(103, count_paths_visit_twice(&graph));
+        for max_visits in 1..=3 {
+            for revisited_caves in 0..=3 {
+                let rules = Rules { max_visits, revisited_caves };
+                assert_eq!(list_paths(&graph, &rules).len(), count_paths_with(&graph, &rules), "{:?}", rules);
+            }
+        }
+        // two caves twice is more paths than part 2's one, and a single visit leaves nothing to revisit
+        let twice_each = Rules { max_visits: 2, revisited_caves: 2 };
+        assert!(count_paths_with(&graph, &twice_each) > 103);
+        assert_eq!(count_paths_with(&graph, &Rules::ONCE), count_paths_with(&graph, &Rules { max_visits: 1, revisited_caves: 5 }));
+        // walking the paths gets the same counts, for graphs with too many small caves for the memo's bits
+        let start = graph.keys().find(|cave| cave.name == "start").unwrap();
+        assert_eq!(19, count_paths(start, &mut vec![], &graph, &Rules::ONCE));
+        assert_eq!(103, count_paths(start, &mut vec![], &graph, &Rules::ONE_TWICE));
+    }
+
+    #[test]
+    fn test_many_small_caves() {
+        // 70 small caves in a line, too many for 2 bits each in a u64, so part 2 walks the paths.
+        // Going back one cave uses up the second visit, and then there's no way on
+        let tunnels: Vec<String> = (1..70).map(|cave| format!("c{}-c{}", cave - 1, cave)).collect();
+        let graph = parse_input(&format!("start-c0\nc69-end\n{}", tunnels.join("\n"))).unwrap();
+        assert_eq!(1, count_total_paths(&graph));
+        assert_eq!(1, count_paths_visit_twice(&graph));
+        // with a large cave before the end, the path can go from it back to c69 and out again
+        let graph = parse_input(&format!("start-c0\nc69-A\nA-end\n{}", tunnels.join("\n"))).unwrap();
+        assert_eq!(2, count_paths_visit_twice(&graph));
+        assert_eq!(2, list_paths(&graph, &Rules::ONE_TWICE).len());
+    }
+}
@@ -0,0 +1,184 @@
+/*
+Day 13: Transparent Origami
+
+Fold a piece of transparent paper in half horizontally or vertically.
+Dots on the paper then overlap or combine.
+All folds occur at exact half way points, the folded line is discarded
+and there are an equal number of lines on either side of the fold.
+
+Part 1: do the first fold, then count the number of dots
+
+Part 2: do all the folds, the dots spell out a message in capital letters.
+*/
+
+use crate::answer::Answer;
+use crate::error::{self, AdventError, Line, Result};
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::point_set::PointSet;
+use crate::solution::Solver;
+use crate::viz::Render;
+
+// Part 1 - do a single fold (instruction), then count the "dots"
+// dots that land on top of each other only count once
+pub fn dots_one_fold(dots: &PointSet, instruction: &str) -> usize {
+    fold(dots, instruction).len()
+}
+
+// Part 2 - iterate through the fold instructions, replacing the "dots" after each step
+// just return the folded paper and eyeball it - no idea how to do this part programatically
+pub fn fold_all(dots: &PointSet, instructions: &[String]) -> Grid<bool> {
+    // the paper starts just big enough for the dots, and each fold line is the new edge
+    let (_, largest) = dots.bounds().unwrap_or_default();
+    let mut size = largest + Point2::new(1, 1);
+    let mut folded = dots.clone();
+    for instruction in instructions {
+        folded = fold(&folded, instruction);
+        match fold_line(instruction) {
+            ("x", index) => size.x = index,
+            (_, index) => size.y = index
+        }
+    }
+    folded.render(Point2::ORIGIN, size - Point2::new(1, 1))
+}
+
+// Use different methods for horizontal vs vertical folds
+fn fold(dots: &PointSet, instruction: &str) -> PointSet {
+    match fold_line(instruction) {
+        ("y", index) => dots.fold_y(index),
+        ("x", index) => dots.fold_x(index),
+        (axis, _) => panic!("Bad fold request {}", axis)
+    }
+}
+
+// "fold along y=7" is ("y", 7)
+fn fold_line(instruction: &str) -> (&str, i32) {
+    let parts: Vec<_> = instruction.trim().split("=").collect();
+    (parts[0].trim_start_matches("fold along "), parts[1].parse().unwrap())
+}
+
+pub fn read_data(path: &str) -> Result<(PointSet, Vec<String>)> {
+    let input = error::read_input(path)?;
+    parse_data(&input)
+}
+
+// The dot coordinates come first, then a blank line, then the fold instructions
+pub fn parse_data(input: &str) -> Result<(PointSet, Vec<String>)> {
+    let sections = error::sections(input);
+    let [dots, instructions] = &sections[..] else {
+        return Err(AdventError::parse("day13", "expected the dots, a blank line, then the folds"));
+    };
+
+    // The folds are followed when solving, so check them now
+    if let Some(bad) = instructions.iter().find(|line| !is_fold(line.text)) {
+        return Err(bad.error("day13", "expected a fold like \"fold along y=7\""));
+    }
+    Ok((dots_from_lines(dots)?, instructions.iter().map(|line| line.text.to_string()).collect()))
+}
+
+fn is_fold(instruction: &str) -> bool {
+    match instruction.split_once("=") {
+        Some(("fold along x" | "fold along y", index)) => index.parse::<i32>().is_ok_and(|index| index >= 0),
+        _ => false
+    }
+}
+
+pub fn parse_dots(input: &str) -> Result<PointSet> {
+    dots_from_lines(&error::lines(input).collect::<Vec<_>>())
+}
+
+fn dots_from_lines(lines: &[Line]) -> Result<PointSet> {
+    let mut dots = PointSet::new();
+    for line in lines {
+        let p: Vec<i32> = line.text.split(",").filter_map(|point| point.parse().ok().filter(|&n| n >= 0)).collect();
+        if p.len() != 2 || line.text.split(",").count() != 2 {
+            return Err(line.error("day13", "expected a dot like \"6,10\""));
+        }
+        dots.insert(Point2::new(p[0], p[1]));
+    }
+    Ok(dots)
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = (PointSet, Vec<String>);
+    type Output = Answer;
+
+    const TITLE: &'static str = "Transparent Origami";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day13/paper.txt";
+
+    fn parse(input: &str) -> Result<(PointSet, Vec<String>)> {
+        parse_data(input)
+    }
+
+    fn solve_part1((dots, instructions): &(PointSet, Vec<String>)) -> Answer {
+        dots_one_fold(dots, &instructions[0]).into()
+    }
+
+    // The answer is the folded paper, the letters have to be read by eye
+    fn solve_part2((dots, instructions): &(PointSet, Vec<String>)) -> Answer {
+        fold_all(dots, instructions).into()
+    }
+
+    // Or look at the letters as a picture
+    fn render((dots, instructions): &(PointSet, Vec<String>)) -> Option<Box<dyn Render>> {
+        Some(Box::new(fold_all(dots, instructions)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_dots() -> PointSet {
+        let input = "6,10
+            0,14
+            9,10
+            0,3
+            10,4
+            4,11
+            6,0
+            6,12
+            4,1
+            0,13
+            10,12
+            3,4
+            3,0
+            8,4
+            1,10
+            2,14
+            8,10
+            9,0";
+        parse_dots(input).unwrap()
+    }
+
+    #[test]
+    fn test_dots() {
+        let dots = get_dots();
+        assert!(dots.contains(&Point2::new(0, 3)));
+        assert!(dots.contains(&Point2::new(1, 10)));
+        assert!(dots.contains(&Point2::new(6, 10)));
+        assert!(!dots.contains(&Point2::new(7, 10)));
+    }
+
+    #[test]
+    fn test_one_fold() {
+        let dots = get_dots();
+        assert_eq!(17, dots_one_fold(&dots, "fold along y=7"));
+    }
+
+    #[test]
+    fn test_two_folds() {
+        let dots = get_dots();
+        let dots = fold(&dots, "fold along y=7");
+        assert_eq!(16, dots_one_fold(&dots, "fold along x=5"))
+    }
+
+    #[test]
+    fn test_fold_all() {
+        // the example folds into a square, and the paper keeps the empty rows below it
+        let paper = fold_all(&get_dots(), &["fold along y=7".to_string(), "fold along x=5".to_string()]);
+        assert_eq!("#####\n#   #\n#   #\n#   #\n#####\n     \n     ", Answer::from(paper).to_string());
+    }
+}
\ No newline at end of file
@@ -20,7 +20,8 @@ appears and subtract the number of times the least common element appears
 Part 2: Do the same as part 1 but for 40 steps
 */
 use std::collections::HashMap;
-use std::fs;
+use crate::error::{self, AdventError, Line, Result};
+use crate::solution::Solver;
 
 // Part 1: brute force
 // resolve the next polymer after each step
@@ -35,13 +36,13 @@ pub fn common_polymers(template: &str, pair_insertion: &HashMap<String, char>, s
         count_map
     });
     
-    return element_count.values().max().unwrap() - element_count.values().min().unwrap();
+    element_count.values().max().unwrap() - element_count.values().min().unwrap()
 }
 
 // Helper method for part 1
 // takes the starting position, inserts the pair insertion character between each group of two characters
 // track the polymer as a vector of characters
-fn next_polymer(start: &Vec<char>, pair_insertion: &HashMap<String, char>) -> Vec<char> {
+fn next_polymer(start: &[char], pair_insertion: &HashMap<String, char>) -> Vec<char> {
     let mut polymer = start.windows(2).fold(vec![], |mut p, char_pair|{
         let key: String = char_pair.iter().collect();
         p.push(char_pair[0]);
@@ -49,7 +50,7 @@ fn next_polymer(start: &Vec<char>, pair_insertion: &HashMap<String, char>) -> Ve
         p
     });
     polymer.push(*start.last().unwrap());
-    return polymer;
+    polymer
 }
 
 // Part 2 - Make it not O(M * 2^n)
@@ -101,23 +102,63 @@ pub fn polymers_as_pairs(template: &str, pair_insertion: &HashMap<String, char>,
     // except the very last character
     *element_count.entry(template.chars().last().unwrap()).or_insert(0) += 1;
 
-    return element_count.values().max().unwrap() - element_count.values().min().unwrap();
+    element_count.values().max().unwrap() - element_count.values().min().unwrap()
 }
 
-fn parse_pair_map(input: &str) -> HashMap<String, char> {
-    input.lines().fold(HashMap::new(), |mut map, pair| {
-        let pair: Vec<_> = pair.trim().split(" -> ").collect();
-        map.insert(pair[0].to_string(), pair[1].chars().next().unwrap());
-        map
-    })
+// Rules look like "CH -> B"
+pub fn parse_pair_map(input: &str) -> Result<HashMap<String, char>> {
+    pairs_from_lines(&error::lines(input).collect::<Vec<_>>())
+}
+
+fn pairs_from_lines(lines: &[Line]) -> Result<HashMap<String, char>> {
+    lines.iter().map(|line| {
+        let rule = line.text.split_once(" -> ")
+            .filter(|(pair, element)| pair.chars().count() == 2 && element.chars().count() == 1);
+        match rule {
+            Some((pair, element)) => Ok((pair.to_string(), element.chars().next().unwrap())),
+            None => Err(line.error("day14", "expected a rule like \"CH -> B\""))
+        }
+    }).collect()
+}
+
+pub fn read_polymer_data(path: &str) -> Result<(String, HashMap<String, char>)> {
+    let input = error::read_input(path)?;
+    parse_polymer_data(&input)
 }
 
-pub fn read_polymer_data() -> (String, HashMap<String, char>) {
-    let input = fs::read_to_string("src/day14/pairs.txt").expect("missing pairs.txt");
-    let template = "PHVCVBFHCVPFKBNHKNBO".to_string();
-    (template, parse_pair_map(&input))
+// The polymer template is the first line, then a blank line, then the pair insertion rules
+pub fn parse_polymer_data(input: &str) -> Result<(String, HashMap<String, char>)> {
+    let sections = error::sections(input);
+    let [template, pairs] = &sections[..] else {
+        return Err(AdventError::parse("day14", "expected the polymer template, a blank line, then the rules"));
+    };
+    if let [_, extra, ..] = template[..] {
+        return Err(extra.error("day14", "the polymer template should be one line"));
+    }
+    Ok((template[0].text.to_string(), pairs_from_lines(pairs)?))
 }
 
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = (String, HashMap<String, char>);
+    type Output = i64;
+
+    const TITLE: &'static str = "Extended Polymerization";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day14/polymer.txt";
+
+    fn parse(input: &str) -> Result<(String, HashMap<String, char>)> {
+        parse_polymer_data(input)
+    }
+
+    fn solve_part1((template, pair_insertion): &(String, HashMap<String, char>)) -> i64 {
+        common_polymers(template, pair_insertion, 10)
+    }
+
+    fn solve_part2((template, pair_insertion): &(String, HashMap<String, char>)) -> i64 {
+        polymers_as_pairs(template, pair_insertion, 40)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -140,7 +181,7 @@ mod tests {
             BC -> B
             CC -> N
             CN -> C";
-        parse_pair_map(input)
+        parse_pair_map(input).unwrap()
     }
 
     #[test]
@@ -12,16 +12,17 @@ Part 2: The cave is actually 5 times larger. The current grid repeats downward a
 but each time it repeats the risk scores are 1 higher. If a risk score would exceed 9, it becomes 1.
 */
 
-use std::cmp;
-use std::fs;
 use std::collections::BinaryHeap;
 use std::cmp::Ordering;
+use crate::grid::{Grid, Position};
+use crate::error::{self, Result};
+use crate::solution::Solver;
 
 // Create a "Risk" struct for the purposes of the priority queue
 #[derive(Clone, Eq, PartialEq)]
 struct Risk {
     cost: i32,
-    position: (usize, usize)
+    position: Position
 }
 
 // The priority queue in rust is a max queue, reverse the "Ord" for a min queue
@@ -40,16 +41,16 @@ impl PartialOrd for Risk {
 
 // Part 1 & 2: Dijkstra's algorith using a priority queue
 // Rust's BinaryHeap is a priority queue and uses Dijkstra's algorithm as an example in the docs
-pub fn dijkstra(grid: &Vec<Vec<i32>>) -> i32 {
+pub fn dijkstra(grid: &Grid<i32>) -> i32 {
     // Potential risk costs all initialized to infinity (or i32::MAX)
-    let mut distances: Vec<Vec<i32>> = vec![vec![i32::MAX; grid[0].len()]; grid.len()];
-    let target = (grid.len() - 1, grid[0].len() - 1);
+    let mut distances = Grid::filled(grid.width(), grid.height(), i32::MAX);
+    let target = (grid.height() - 1, grid.width() - 1);
 
     let mut queue = BinaryHeap::new();
     
     // starting space is free
     queue.push(Risk { cost: 0, position: (0, 0)});
-    distances[0][0] = 0;
+    distances[(0, 0)] = 0;
 
     // When are priority queue is empty, the shortest distance is calculated to all points
     // pop the position with the lowest total risk cost to get there
@@ -58,92 +59,76 @@ pub fn dijkstra(grid: &Vec<Vec<i32>>) -> i32 {
         if current.position == target {
             break;
         }
-        let (row, col) = current.position;
+        let position = current.position;
 
         // We already found a better path to this position
-        if current.cost > distances[row][col] {
+        if current.cost > distances[position] {
             continue;
         }
 
-        // Look at adjacent positions
-        for neighbor in find_adjacent(row, col, &grid) {
+        // Look at adjacent (non-diagonal) positions
+        for neighbor in grid.neighbors4(position) {
             // Compute the cost to this neighbor from the current position
-            let cost = distances[row][col] + grid[neighbor.0][neighbor.1];
-            if cost < distances[neighbor.0][neighbor.1] {
+            let cost = distances[position] + grid[neighbor];
+            if cost < distances[neighbor] {
                 // if that cost is less than the known potential cost to that position
                 // update the known potential costs and add to the priority queue
-                distances[neighbor.0][neighbor.1] = cost;
-                queue.push(Risk { cost, position: (neighbor.0, neighbor.1)});
+                distances[neighbor] = cost;
+                queue.push(Risk { cost, position: neighbor });
             }
         }
     }
 
-    return distances[target.0][target.1];
-}
-
-// Adjacent non-diagonal spaces
-fn find_adjacent(row: usize, col: usize, grid: &Vec<Vec<i32>>) -> Vec<(usize, usize)> {
-    let mut adjacent = Vec::new();
-    let max = grid.len() - 1;
-    for r in row.checked_sub(1).unwrap_or(0)..=cmp::min(row + 1, max) {
-        if r == row  {
-            continue;
-        }
-        adjacent.push((r, col));
-    }
-    let max = grid[0].len() - 1;
-    for c in col.checked_sub(1).unwrap_or(0)..=cmp::min(col + 1, max) {
-        if c == col {
-            continue;
-        }
-        adjacent.push((row, c));
-    }
-    adjacent
+    distances[target]
 }
 
 // Make the grid bigger
-// there's probably a smarter modulo way to do this
-pub fn expand_grid(grid: &Vec<Vec<i32>>) -> Vec<Vec<i32>> {
-    let mut expanded = grid.clone();
-    for r in 0..grid.len() {
-        for c in grid[0].len()..(grid[0].len() * 5) {
-            let last_c = c - grid[0].len();
-            let updated_val = expanded[r][last_c] + 1;
-            expanded[r].push( if updated_val > 9 { 1 } else { updated_val });
-        }
-    }
-    for r in grid.len()..(grid.len() * 5) {
-        let mut row = Vec::new();
-        for c in 0..expanded[0].len() {
-            let last_r = r - grid.len();
-            let updated_val = expanded[last_r][c] + 1;
-            row.push( if updated_val > 9 { 1 } else { updated_val });
-        }
-        expanded.push(row);
-    }
-
-    return expanded;
+// Each repeat of the grid adds 1 for every tile it is to the right and down,
+// wrapping back around to 1 after 9
+pub fn expand_grid(grid: &Grid<i32>) -> Grid<i32> {
+    let (width, height) = (grid.width(), grid.height());
+    Grid::from_fn(width * 5, height * 5, |(r, c)| {
+        let tile = (r / height + c / width) as i32;
+        (grid[(r % height, c % width)] + tile - 1) % 9 + 1
+    })
 }
 
-fn parse_data(input: &str) -> Vec<Vec<i32>> {
-    input.lines()
-        .map(|line| line.trim().chars()
-            .map(|c| c.to_string().parse::<i32>().unwrap()).collect()
-        )
-        .collect()
+pub fn parse_data(input: &str) -> Result<Grid<i32>> {
+    Grid::parse("day15", input, |c| c.to_digit(10).map(|risk| risk as i32))
 }
 
-pub fn read_grid() -> Vec<Vec<i32>> {
-    let input = fs::read_to_string("src/day15/grid.txt").expect("missing grid.txt");
+pub fn read_grid(path: &str) -> Result<Grid<i32>> {
+    let input = error::read_input(path)?;
     parse_data(&input)
 }
 
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input = Grid<i32>;
+    type Output = i32;
+
+    const TITLE: &'static str = "Chiton";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day15/grid.txt";
+
+    fn parse(input: &str) -> Result<Grid<i32>> {
+        parse_data(input)
+    }
+
+    fn solve_part1(grid: &Grid<i32>) -> i32 {
+        dijkstra(grid)
+    }
+
+    fn solve_part2(grid: &Grid<i32>) -> i32 {
+        dijkstra(&expand_grid(grid))
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn test_data() -> Vec<Vec<i32>> {
+    fn test_data() -> Grid<i32> {
         let input = "1163751742
             1381373672
             2136511328
@@ -154,7 +139,7 @@ mod tests {
             3125421639
             1293138521
             2311944581";
-        parse_data(input)
+        parse_data(input).unwrap()
     }
 
     #[test]
@@ -167,10 +152,10 @@ mod tests {
     fn test_expand_grid() {
         let grid = test_data();
         let expanded = expand_grid(&grid);
-        assert_eq!(2, expanded[0][10]);
-        assert_eq!(3, expanded[8][11]);
-        assert_eq!(1, expanded[9][14]);
-        assert_eq!(9, expanded[expanded.len() -1][expanded[0].len() - 1]);
+        assert_eq!(2, expanded[(0, 10)]);
+        assert_eq!(3, expanded[(8, 11)]);
+        assert_eq!(1, expanded[(9, 14)]);
+        assert_eq!(9, expanded[(expanded.height() - 1, expanded.width() - 1)]);
     }
 
     #[test]
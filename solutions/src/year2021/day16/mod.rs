@@ -0,0 +1,315 @@
+/*
+Day 16: Packet Decoder
+
+You receive a tranmission in hex format with a specific encoding.
+Bits represent a version number and a type. The type determines what the next bits are.
+Operator types can have multiple sub packets.
+
+Part 1: Add together all the version numbers for every packet and sub packet
+
+Part 2: Using rules for each operator type (sum, product, etc.), calculate the packet value.
+*/
+
+use std::collections::HashMap;
+use std::ops::Range;
+use crate::answer::Answer;
+use crate::error::{self, AdventError, Result};
+use crate::solution::Solver;
+use serde::{Deserialize, Serialize};
+
+// Packet represented by a struct
+// Value is optional and only in type_id 4
+// sub_packets are only present in type_id != 4, empty otherwise
+// Avoid rust borrow issues by having the Packet own the sub packets
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Packet {
+    version: i32,
+    type_id: i32,
+    value: Option<u128>,
+    sub_packets: Vec<Packet>
+}
+
+impl Packet {
+    // Part 1: Count all the packet version numbers by recursively calling into sub packets
+    pub fn count_version(&self) -> i64 {
+        i64::from(self.version) + self.sub_packets.iter().map(|p| p.count_version()).sum::<i64>()
+    }
+
+    // Part 2: Calculate operations depend on the type_id
+    // parse_hex_packet already checked that the value fits, so this can't overflow
+    pub fn calculate(&self) -> u128 {
+        self.checked_calculate().expect("packet value overflows a u128")
+    }
+
+    // The tree like nature of the Packet struct makes this pretty straightforward.
+    // Literals can be as big as a u128 and a product of a few of them gets big fast,
+    // so sums and products are checked, None if any step overflows
+    fn checked_calculate(&self) -> Option<u128> {
+        let mut values = self.sub_packets.iter().map(|p| p.checked_calculate());
+        match self.type_id {
+            4 => self.value,
+            0 => values.try_fold(0u128, |sum, value| sum.checked_add(value?)),
+            1 => values.try_fold(1u128, |product, value| product.checked_mul(value?)),
+            2 => values.collect::<Option<Vec<_>>>()?.into_iter().min(),
+            3 => values.collect::<Option<Vec<_>>>()?.into_iter().max(),
+            5 => Some(u128::from(values.next()?? > values.next()??)),
+            6 => Some(u128::from(values.next()?? < values.next()??)),
+            7 => Some(u128::from(values.next()?? == values.next()??)),
+            _ => panic!("unknown type")
+        }
+    }
+}
+
+// Converts our hex string into an array of chars that are either '0' or '1'
+// Maybe it would be better to do bytes and bitwise operations, but I'm not super familiar with that in Rust
+pub fn parse_hex_packet(hex_string: &str) -> Result<Packet> {
+    let hex_map: HashMap<char, &str> = ('0'..='9').chain('A'..='F')
+        .zip(vec!["0000","0001","0010","0011","0100","0101","0110","0111","1000","1001","1010","1011","1100","1101","1110","1111"])
+        .collect();
+
+    // The transmission is a single line
+    let line = error::lines(hex_string).find(|line| !line.text.is_empty())
+        .ok_or_else(|| AdventError::parse("day16", "the input is empty"))?;
+    let binary: Vec<_> = line.text.char_indices()
+        .map(|(i, c)| hex_map.get(&c).copied()
+            .ok_or_else(|| line.error_in("day16", &line.text[i..i + c.len_utf8()], format!("'{}' isn't a hex digit", c))))
+        .collect::<Result<_>>()?;
+    let binary: Vec<char> = binary.join("").chars().collect();
+    let packet = parse_packet(&binary[..], 1)?.0;
+    if packet.checked_calculate().is_none() {
+        return Err(AdventError::parse("day16", "the packet's value is too big for a u128"));
+    }
+    Ok(packet)
+}
+
+// Puzzle inputs nest around 20 deep. Anything past this is garbage that would otherwise
+// recurse until the stack runs out
+const MAX_DEPTH: usize = 100;
+
+// The bits in the range, or an error if the transmission ends before the range does
+fn read_bits(binary: &[char], range: Range<usize>) -> Result<&[char]> {
+    binary.get(range).ok_or_else(|| AdventError::parse("day16", "the transmission ends part way through a packet"))
+}
+
+fn to_number(bits: &[char]) -> Result<u128> {
+    let bits: String = bits.iter().collect();
+    u128::from_str_radix(&bits, 2).map_err(|_| AdventError::parse("day16", format!("{} bits is too big for a number", bits.len())))
+}
+
+// Recursive method to parse the binary bit array into packets and sub packets
+// Returns the packet and the number of bits it took to create the packet
+// depth is how many packets this one is inside of, counting itself
+fn parse_packet(binary: &[char], depth: usize) -> Result<(Packet, usize)> {
+    if depth > MAX_DEPTH {
+        return Err(AdventError::parse("day16", format!("packets are nested more than {} deep", MAX_DEPTH)));
+    }
+    //Version and type_id are common to all packets
+    let version = to_number(read_bits(binary, 0..3)?)? as i32;
+    let type_id = to_number(read_bits(binary, 3..6)?)? as i32;
+
+    // Value type packet
+    if type_id == 4 {
+        let mut idx = 6;
+        let mut chunks: Vec<char> = Vec::new();
+        let mut next = read_bits(binary, idx..idx+5)?;
+        // Loop through 5 bit chunks until the first bit is 0
+        loop {
+            // grab the last 4 bits, discarding the first one
+            chunks.extend_from_slice(&next[1..]);
+            idx += 5;
+            if next[0] == '0' {
+                break;
+            }
+            next = read_bits(binary, idx..idx+5)?;
+        }
+        let value = to_number(&chunks)?;
+        Ok((Packet { version, type_id, value: Some(value), sub_packets: vec![] }, idx))
+
+    }// Operator type packet
+    else {
+        let length_id = read_bits(binary, 6..7)?[0];
+        let length: usize = match length_id {
+            '0' => 15,
+            _ => 11
+        };
+        let mut sub_start = 7 + length;
+
+        // Length calculations will depend on length_id
+        // but either way, loop until we have all sub packets
+        let mut length = to_number(read_bits(binary, 7..sub_start)?)? as i32;
+        let mut sub_packets: Vec<Packet> = Vec::new();
+        while length > 0 {
+            // pass down the bits not used yet to get the next sub packet
+            let (p, bits) = parse_packet(read_bits(binary, sub_start..binary.len())?, depth + 1)?;
+            sub_packets.push(p);
+            // the next sub packet will index after the end of the previous one
+            sub_start += bits;
+            if length_id == '0' {
+                // For length_id 0, length represents the total bits in the sub packets
+                length -= bits as i32;
+            } else {
+                // for length_id 1, length represents the number of sub packets
+                length -= 1;
+            }
+        }
+        // calculate needs something to compare
+        match (type_id, sub_packets.len()) {
+            (2 | 3, 0) => return Err(AdventError::parse("day16", format!("operator {} has no sub packets", type_id))),
+            (5..=7, n) if n != 2 => return Err(AdventError::parse("day16", format!("operator {} has {} sub packets, it compares 2", type_id, n))),
+            _ => ()
+        }
+        Ok((Packet { version, type_id, value: None, sub_packets }, sub_start))
+    }
+}
+
+pub fn read_packet(path: &str) -> Result<Packet> {
+    let input = error::read_input(path)?;
+    parse_hex_packet(&input)
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = Packet;
+    type Output = Answer;
+
+    const TITLE: &'static str = "Packet Decoder";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day16/packets.txt";
+
+    fn parse(input: &str) -> Result<Packet> {
+        parse_hex_packet(input)
+    }
+
+    fn solve_part1(packet: &Packet) -> Answer {
+        packet.count_version().into()
+    }
+
+    fn solve_part2(packet: &Packet) -> Answer {
+        packet.calculate().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Packs a string of bits into hex, padding the end with zeros
+    fn to_hex(bits: &str) -> String {
+        let bits = bits.to_string() + &"0".repeat((4 - bits.len() % 4) % 4);
+        bits.as_bytes().chunks(4)
+            .map(|nibble| format!("{:X}", u8::from_str_radix(std::str::from_utf8(nibble).unwrap(), 2).unwrap()))
+            .collect()
+    }
+
+    // The bits for a literal packet holding value
+    fn literal(value: u128) -> String {
+        let digits = format!("{:b}", value);
+        let digits = "0".repeat((4 - digits.len() % 4) % 4) + &digits;
+        let groups: Vec<_> = digits.as_bytes().chunks(4).map(|group| std::str::from_utf8(group).unwrap()).collect();
+        let chunks: String = groups.iter().enumerate()
+            .map(|(i, group)| if i + 1 < groups.len() { "1" } else { "0" }.to_string() + group)
+            .collect();
+        "000100".to_string() + &chunks
+    }
+
+    // The bits for an operator packet, with the count of sub packets as its length
+    fn operator(type_id: u8, sub_packets: &[String]) -> String {
+        format!("000{:03b}1{:011b}{}", type_id, sub_packets.len(), sub_packets.concat())
+    }
+
+    #[test]
+    fn test_parse_packet() {
+        let packet = parse_hex_packet("D2FE28").unwrap();
+        assert_eq!(6, packet.version);
+        assert_eq!(4, packet.type_id);
+        assert_eq!(2021, packet.value.unwrap());
+
+        let packet = parse_hex_packet("38006F45291200").unwrap();
+        assert_eq!(1, packet.version);
+        assert_eq!(6, packet.type_id);
+        assert_eq!(2, packet.sub_packets.len());
+        assert_eq!(10, packet.sub_packets[0].value.unwrap());
+        assert_eq!(20, packet.sub_packets[1].value.unwrap());
+
+        let packet = parse_hex_packet("EE00D40C823060").unwrap();
+        assert_eq!(7, packet.version);
+        assert_eq!(3, packet.type_id);
+        assert_eq!(3, packet.sub_packets.len());
+        assert_eq!(1, packet.sub_packets[0].value.unwrap());
+        assert_eq!(2, packet.sub_packets[1].value.unwrap());
+        assert_eq!(3, packet.sub_packets[2].value.unwrap());
+
+        // sums of one sub packet each, wrapped around a literal
+        let nested = |depth: usize| to_hex(&("000000100000000001".repeat(depth - 1) + "00010000001"));
+        assert_eq!(1, parse_hex_packet(&nested(MAX_DEPTH)).unwrap().calculate());
+        assert!(parse_hex_packet(&nested(MAX_DEPTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_count_packet_version() {
+        let packet = parse_hex_packet("8A004A801A8002F478").unwrap();
+        assert_eq!(16, packet.count_version());
+
+        let packet = parse_hex_packet("620080001611562C8802118E34").unwrap();
+        assert_eq!(12, packet.count_version());
+
+        let packet = parse_hex_packet("C0015000016115A2E0802F182340").unwrap();
+        assert_eq!(23, packet.count_version());
+
+        let packet = parse_hex_packet("A0016C880162017C3686B18A3D4780").unwrap();
+        assert_eq!(31, packet.count_version());
+    }
+
+    #[test]
+    fn test_packet_json() {
+        let packet = parse_hex_packet("38006F45291200").unwrap();
+        let json = serde_json::to_string(&packet).unwrap();
+        assert!(json.starts_with("{\"version\":1,\"type_id\":6,\"value\":null,\"sub_packets\":[{\"version\":6,"));
+        assert_eq!(packet, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn test_packet_calculation() {
+        let packet = parse_hex_packet("C200B40A82").unwrap();
+        assert_eq!(3, packet.calculate());
+
+        let packet = parse_hex_packet("04005AC33890").unwrap();
+        assert_eq!(54, packet.calculate());
+
+        let packet = parse_hex_packet("880086C3E88112").unwrap();
+        assert_eq!(7, packet.calculate());
+
+        let packet = parse_hex_packet("CE00C43D881120").unwrap();
+        assert_eq!(9, packet.calculate());
+
+        let packet = parse_hex_packet("D8005AC2A8F0").unwrap();
+        assert_eq!(1, packet.calculate());
+
+        let packet = parse_hex_packet("F600BC2D8F").unwrap();
+        assert_eq!(0, packet.calculate());
+
+        let packet = parse_hex_packet("9C005AC2F8F0").unwrap();
+        assert_eq!(0, packet.calculate());
+
+        let packet = parse_hex_packet("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(1, packet.calculate());
+    }
+
+    #[test]
+    fn test_packet_overflow() {
+        // products past i64 and u64 are fine
+        let packet = parse_hex_packet(&to_hex(&operator(1, &[literal(u64::MAX.into()), literal(u64::MAX.into())]))).unwrap();
+        assert_eq!(u128::from(u64::MAX) * u128::from(u64::MAX), packet.calculate());
+        let packet = parse_hex_packet(&to_hex(&operator(0, &[literal(u128::MAX - 1), literal(1)]))).unwrap();
+        assert_eq!(u128::MAX, packet.calculate());
+        assert_eq!(Answer::BigInt(u128::MAX), Day16::solve_part2(&packet));
+
+        // one more and it doesn't fit
+        assert!(parse_hex_packet(&to_hex(&operator(0, &[literal(u128::MAX), literal(1)]))).is_err());
+        assert!(parse_hex_packet(&to_hex(&operator(1, &[literal(1 << 64), literal(1 << 64)]))).is_err());
+        // an overflow that gets compared away still doesn't fit
+        let too_big = operator(1, &[literal(1 << 64), literal(1 << 64)]);
+        assert!(parse_hex_packet(&to_hex(&operator(5, &[too_big, literal(1)]))).is_err());
+    }
+}
+
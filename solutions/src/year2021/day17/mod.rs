@@ -13,18 +13,21 @@ Part 2: How many distinct initial velocity values will put the probe in the targ
 */
 
 use std::cmp;
+use crate::error::{self, AdventError, Line, Result};
+use crate::geometry::Point2;
+use crate::solution::Solver;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TargetArea {
-    x_min: i32,
-    x_max: i32,
-    y_min: i32,
-    y_max: i32,
+    // bottom left and top right corners
+    min: Point2,
+    max: Point2
 }
 
 impl TargetArea {
-    fn is_inside(&self, x: i32, y: i32) -> bool {
-        x >= self.x_min && x <= self.x_max && y >= self.y_min && y <= self.y_max
+    fn is_inside(&self, p: Point2) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
     }
 }
 
@@ -49,9 +52,9 @@ impl TargetArea {
 // The highest point in the parabolic trajecotry is when the number of steps is equal to the initial velocity
 // at this point, the velocity is 0
 pub fn highest_possible(target: &TargetArea) -> i32 {
-    let initial_velocity = target.y_min.abs() - 1;
+    let initial_velocity = target.min.y.abs() - 1;
     let steps = initial_velocity;
-    return y_position(initial_velocity, steps);
+    y_position(initial_velocity, steps)
 }
 
 // Part 2 - just brute force it
@@ -59,22 +62,21 @@ pub fn highest_possible(target: &TargetArea) -> i32 {
 // pick reasonable upper and lower bounds for the initial x and y velocities
 // loop through all combonations, and loop through steps to find if the velocity combo is valid
 pub fn all_possible_velocities(target: &TargetArea) -> usize {
-    let mut valid: Vec<(i32, i32)> = Vec::new();
+    let mut valid: Vec<Point2> = Vec::new();
     // Highest possible valid xv is the max x position of the target area
     // could probably pick a smarter min xv, but this already runs in 12ms
-    for xv in 1..=target.x_max {
+    for xv in 1..=target.max.x {
         // lowest possible y is the bottom of the y target area
         // highest possible y is the same from part 1
-        for yv in target.y_min..=(target.y_min.abs() - 1) {
+        for yv in target.min.y..=(target.min.y.abs() - 1) {
             let mut steps = 0;
             loop {
-                let x = x_position(xv, steps);
-                let y = y_position(yv, steps);
-                if x > target.x_max || y < target.y_min {
+                let position = Point2::new(x_position(xv, steps), y_position(yv, steps));
+                if position.x > target.max.x || position.y < target.min.y {
                     break;
                 }
-                if target.is_inside(x, y) {
-                    valid.push((x,y));
+                if target.is_inside(position) {
+                    valid.push(position);
                     break;
                 }
                 steps += 1;
@@ -85,37 +87,68 @@ pub fn all_possible_velocities(target: &TargetArea) -> usize {
 }
 
 fn y_position(initial_velocity: i32, steps: i32) -> i32 {
-    return (steps + 1) * initial_velocity - steps * (steps + 1) / 2;
+    (steps + 1) * initial_velocity - steps * (steps + 1) / 2
 }
 
 fn x_position(initial_velocity: i32, steps: i32) -> i32 {
     let effective_steps = cmp::min(initial_velocity, steps);
-    return (effective_steps + 1) * initial_velocity - effective_steps * (effective_steps + 1) / 2;
+    (effective_steps + 1) * initial_velocity - effective_steps * (effective_steps + 1) / 2
 }
 
-// skip file reading for this one
-pub fn read_target_area() -> TargetArea {
-    let input = "target area: x=201..230, y=-99..-65";
-    let coords: Vec<_> = input.split(": ").collect();
-    parse_target_area(coords[1])
+pub fn read_target_area(path: &str) -> Result<TargetArea> {
+    let input = error::read_input(path)?;
+    parse_input(&input)
 }
 
-fn parse_target_area(input: &str) -> TargetArea {
-    let split: Vec<_> = input.split(", ").collect();
-    let x_range = split[0].split("x=").last().unwrap();
-    let x_range: Vec<i32> = x_range.split("..").map(|v| v.parse().unwrap()).collect();
+// "target area: x=20..30, y=-10..-5"
+pub fn parse_input(input: &str) -> Result<TargetArea> {
+    let line = error::lines(input).find(|line| !line.text.is_empty())
+        .ok_or_else(|| AdventError::parse("day17", "the input is empty"))?;
+    let coords = line.text.strip_prefix("target area: ")
+        .ok_or_else(|| line.error("day17", "the input should start with \"target area: \""))?;
+    target_area_from(&line, coords)
+}
 
-    let y_range = split[1].split("y=").last().unwrap();
-    let y_range: Vec<i32> = y_range.split("..").map(|v| v.parse().unwrap()).collect();
+// "x=20..30, y=-10..-5"
+pub fn parse_target_area(input: &str) -> Result<TargetArea> {
+    let line = Line { number: 1, text: input.trim() };
+    target_area_from(&line, line.text)
+}
 
-    TargetArea {
-        x_min: x_range[0],
-        x_max: x_range[1],
-        y_min: y_range[0],
-        y_max: y_range[1]
-    }
+fn target_area_from(line: &Line, coords: &str) -> Result<TargetArea> {
+    let (x_range, y_range) = coords.split_once(", ")
+        .ok_or_else(|| line.error_in("day17", coords, "expected a target area like \"x=20..30, y=-10..-5\""))?;
+    let range = |range: &str, axis: &str| {
+        let (min, max) = range.strip_prefix(axis)?.split_once("..")?;
+        Some((min.parse::<i32>().ok()?, max.parse::<i32>().ok()?))
+    };
+    let (x_min, x_max) = range(x_range, "x=").ok_or_else(|| line.error_in("day17", x_range, "expected a range like \"x=20..30\""))?;
+    let (y_min, y_max) = range(y_range, "y=").ok_or_else(|| line.error_in("day17", y_range, "expected a range like \"y=-10..-5\""))?;
+
+    Ok(TargetArea { min: Point2::new(x_min, y_min), max: Point2::new(x_max, y_max) })
 }
 
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = TargetArea;
+    type Output = i64;
+
+    const TITLE: &'static str = "Trick Shot";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day17/target.txt";
+
+    fn parse(input: &str) -> Result<TargetArea> {
+        parse_input(input)
+    }
+
+    fn solve_part1(target_area: &TargetArea) -> i64 {
+        i64::from(highest_possible(target_area))
+    }
+
+    fn solve_part2(target_area: &TargetArea) -> i64 {
+        all_possible_velocities(target_area) as i64
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -124,26 +157,26 @@ mod tests {
     #[test]
     fn test_highest_y_position() {
         let input = "x=20..30, y=-10..-5";
-        let target = parse_target_area(input);
+        let target = parse_target_area(input).unwrap();
         assert_eq!(45, highest_possible(&target));
     }
 
     #[test]
     fn test_all_valid_velocities() {
         let input = "x=20..30, y=-10..-5";
-        let target = parse_target_area(input);
+        let target = parse_target_area(input).unwrap();
         assert_eq!(112, all_possible_velocities(&target));
     }
 
     #[test]
     fn test_target_area() {
         let input = "x=20..30, y=-10..-5";
-        let target = parse_target_area(input);
-        assert_eq!(20, target.x_min);
-        assert_eq!(-10, target.y_min);
-        assert_eq!(-5, target.y_max);
+        let target = parse_target_area(input).unwrap();
+        assert_eq!(20, target.min.x);
+        assert_eq!(-10, target.min.y);
+        assert_eq!(-5, target.max.y);
 
-        assert_eq!(true, target.is_inside(25, -7));
+        assert!(target.is_inside(Point2::new(25, -7)));
     }
 
     #[test]
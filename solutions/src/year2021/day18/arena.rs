@@ -0,0 +1,204 @@
+/*
+Snail numbers kept in one Vec instead of a tree of Rc<RefCell<_>> nodes.
+
+Nodes point at each other by their index in the arena, so making a pair or splitting a number
+is a push onto the Vec rather than an allocation (and a new uuid) per node.
+Exploded pairs just stay behind in the arena unused. Clearing the arena keeps its memory,
+so part 2 works through every sum with the same arena (one per thread with the parallel feature).
+
+The solver uses these with the arena feature. The input has already been checked by parse_input
+in mod.rs by the time anything gets here.
+*/
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Clone, Copy)]
+struct Node {
+    // Some for a regular number, None for a pair
+    value: Option<i32>,
+    left: usize,
+    right: usize,
+    parent: Option<usize>
+}
+
+#[derive(Default)]
+pub struct Arena {
+    nodes: Vec<Node>
+}
+
+impl Arena {
+    pub fn new() -> Arena {
+        Arena::default()
+    }
+
+    // Forget every number, keeping the memory for the next ones
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    fn value(&mut self, value: i32) -> usize {
+        self.nodes.push(Node { value: Some(value), left: 0, right: 0, parent: None });
+        self.nodes.len() - 1
+    }
+
+    fn pair(&mut self, left: usize, right: usize) -> usize {
+        self.nodes.push(Node { value: None, left, right, parent: None });
+        let pair = self.nodes.len() - 1;
+        self.nodes[left].parent = Some(pair);
+        self.nodes[right].parent = Some(pair);
+        pair
+    }
+
+    // Read a number from a line that's known to be valid, returns the index of its outer pair
+    pub fn parse(&mut self, line: &str) -> usize {
+        self.parse_element(line.trim().as_bytes(), &mut 0)
+    }
+
+    fn parse_element(&mut self, bytes: &[u8], index: &mut usize) -> usize {
+        let c = bytes[*index];
+        *index += 1;
+        if c != b'[' {
+            return self.value((c - b'0') as i32);
+        }
+        let left = self.parse_element(bytes, index);
+        // skip the ','
+        *index += 1;
+        let right = self.parse_element(bytes, index);
+        // and the ']'
+        *index += 1;
+        self.pair(left, right)
+    }
+
+    pub fn add(&mut self, lhs: usize, rhs: usize) -> usize {
+        let sum = self.pair(lhs, rhs);
+        while self.explode(sum, 1) || self.split(sum) {}
+        sum
+    }
+
+    // depth counts the outer pair as 1, same as nested() in mod.rs
+    fn explode(&mut self, number: usize, depth: usize) -> bool {
+        let node = self.nodes[number];
+        if node.value.is_some() {
+            return false;
+        }
+        if depth < 5 {
+            return self.explode(node.left, depth + 1) || self.explode(node.right, depth + 1);
+        }
+        if let Some(left) = self.nearest(number, true) {
+            *self.nodes[left].value.as_mut().unwrap() += self.nodes[node.left].value.unwrap();
+        }
+        if let Some(right) = self.nearest(number, false) {
+            *self.nodes[right].value.as_mut().unwrap() += self.nodes[node.right].value.unwrap();
+        }
+        self.nodes[number].value = Some(0);
+        true
+    }
+
+    // The closest regular number to the left (or right) of this one, if there is one.
+    // Go up until we come from the other side, then down the near edge of the side we're after
+    fn nearest(&self, number: usize, to_left: bool) -> Option<usize> {
+        let mut child = number;
+        while let Some(parent) = self.nodes[child].parent {
+            let (near, far) = if to_left {
+                (self.nodes[parent].right, self.nodes[parent].left)
+            } else {
+                (self.nodes[parent].left, self.nodes[parent].right)
+            };
+            if near == child {
+                let mut traverse = far;
+                while self.nodes[traverse].value.is_none() {
+                    traverse = if to_left { self.nodes[traverse].right } else { self.nodes[traverse].left };
+                }
+                return Some(traverse);
+            }
+            child = parent;
+        }
+        None
+    }
+
+    fn split(&mut self, number: usize) -> bool {
+        let node = self.nodes[number];
+        match node.value {
+            Some(value) if value > 9 => {
+                let left = self.value(value / 2);
+                let right = self.value(value - value / 2);
+                self.nodes[left].parent = Some(number);
+                self.nodes[right].parent = Some(number);
+                self.nodes[number] = Node { value: None, left, right, parent: node.parent };
+                true
+            }
+            Some(_) => false,
+            None => self.split(node.left) || self.split(node.right)
+        }
+    }
+
+    pub fn magnitude(&self, number: usize) -> i32 {
+        let node = self.nodes[number];
+        match node.value {
+            Some(value) => value,
+            None => 3 * self.magnitude(node.left) + 2 * self.magnitude(node.right)
+        }
+    }
+}
+
+// Part 1: the magnitude of all the numbers added up in order
+pub fn sum_magnitude(lines: &[&str]) -> i32 {
+    let mut arena = Arena::new();
+    let sum = lines.iter()
+        .map(|line| arena.parse(line))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .reduce(|total, number| arena.add(total, number))
+        .unwrap();
+    arena.magnitude(sum)
+}
+
+// Part 2: the largest magnitude from adding any two different numbers
+pub fn find_largest_combo_magnitude(lines: &[&str]) -> i32 {
+    let pairs: Vec<(usize, usize)> = (0..lines.len())
+        .flat_map(|i| (0..lines.len()).filter(move |&j| j != i).map(move |j| (i, j)))
+        .collect();
+    let magnitude = |arena: &mut Arena, &(i, j): &(usize, usize)| {
+        arena.clear();
+        let lhs = arena.parse(lines[i]);
+        let rhs = arena.parse(lines[j]);
+        let sum = arena.add(lhs, rhs);
+        arena.magnitude(sum)
+    };
+    #[cfg(not(feature = "parallel"))]
+    let largest = {
+        let mut arena = Arena::new();
+        pairs.iter().map(|pair| magnitude(&mut arena, pair)).max()
+    };
+    #[cfg(feature = "parallel")]
+    let largest = pairs.par_iter().map_init(Arena::new, magnitude).max();
+    largest.unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arena_sums() {
+        let mut arena = Arena::new();
+        let lhs = arena.parse("[[[[4,3],4],4],[7,[[8,4],9]]]");
+        let rhs = arena.parse("[1,1]");
+        let sum = arena.add(lhs, rhs);
+        assert_eq!(1384, arena.magnitude(sum));
+
+        let lines = ["[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+            "[[[[5,4],[7,7]],8],[[8,3],8]]",
+            "[[9,3],[[9,9],[6,[4,9]]]]",
+            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"];
+        assert_eq!(4140, sum_magnitude(&lines));
+        assert_eq!(3993, find_largest_combo_magnitude(&lines));
+    }
+}
@@ -11,25 +11,34 @@ A number's magnitude is 3 * the left pair + 2 * the right pair
 
 Part 1: given a list of numbers, add them together in order and get the magnitude
 Part 2: what is the largest magnitude of any 2 combinations of numbers in the list
+
+With the arena feature the solver uses the numbers in arena.rs instead,
+which keep every node in one Vec rather than allocating each one.
 */
 
-use std::rc::Rc;
+pub mod arena;
+
+use std::rc::{Rc, Weak};
 use std::cell::RefCell;
 use std::fmt;
-use std::fs;
 use uuid::Uuid;
+use crate::error::{self, Line, Result};
+use crate::solution::Solver;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 // Helper type to avoid writing the smart pointers everywhere
 pub type SnailNumber = Rc<RefCell<SnailNumberNode>>;
 
 // The raw node type of the number
 // it can have a value, or a left/right, not both
+// The parent is a Weak pointer, a strong one both ways would be a cycle and the number would never be freed
 pub struct SnailNumberNode {
     id: Uuid,
     value: Option<i32>,
     left: Option<SnailNumber>,
     right: Option<SnailNumber>,
-    parent: Option<SnailNumber>
+    parent: Option<Weak<RefCell<SnailNumberNode>>>
 }
 
 // custom debug display for print!
@@ -79,9 +88,9 @@ impl SnailNumberNode {
             right: Some(right.clone()),
             parent: None
         }));
-        left.borrow_mut().parent = Some(result.clone());
-        right.borrow_mut().parent = Some(result.clone());
-        return result;
+        left.borrow_mut().parent = Some(Rc::downgrade(&result));
+        right.borrow_mut().parent = Some(Rc::downgrade(&result));
+        result
     }
 }
 
@@ -102,16 +111,21 @@ impl SnailNumberNode {
     /// Counts how deep the nested number is
     /// Note: root level counts as 1 so a number nested 4 layers deep would be 5
     fn nested(&self) -> i32 {
-        if self.parent.is_none() {
-            return 1;
+        match self.parent() {
+            Some(parent) => 1 + parent.borrow().nested(),
+            None => 1
         }
-        return 1 + self.parent.as_ref().unwrap().borrow().nested()
+    }
+
+    // The pair this number is part of, None for the whole number
+    fn parent(&self) -> Option<SnailNumber> {
+        self.parent.as_ref().and_then(Weak::upgrade)
     }
 
     /// Traverse the number tree to find the closes real number value
     /// to the left of the current SnailNumberNode, if one exists
     fn nearest_left(&self) -> Option<SnailNumber> {
-        if let Some(p) = self.parent.as_ref() {
+        if let Some(p) = self.parent() {
             let parent = p.borrow();
             if *parent.left_unwrap().borrow() == *self {
                 return parent.nearest_left();
@@ -130,7 +144,7 @@ impl SnailNumberNode {
     /// Traverse the number tree to find the closest real number value
     /// to the right of the current SnailNumberNode, if one exists
     fn nearest_right(&self) -> Option<SnailNumber> {
-        if let Some(p) = self.parent.as_ref() {
+        if let Some(p) = self.parent() {
             let parent = p.borrow();
             if *parent.right_unwrap().borrow() == *self {
                 return parent.nearest_right();
@@ -172,45 +186,35 @@ pub fn add_all(numbers: Vec<SnailNumber>) -> SnailNumber {
 // we use for the number graph mutates the underlying memory references. After being added in
 // part 1, the numbers are no longer the same (due to reducing).
 // The same issue will happen when adding each number for part 2, so instead of creating a Vec<SnailNumber>
-// we create a Vec<str> and parse out a new number each time
-pub fn largest_magnitude() -> i32 {
-    let input = fs::read_to_string("src/day18/numbers.txt").expect("missing numbers.txt");
-    let lines: Vec<_> = input.lines().map(|l| l.trim()).collect();
-    find_largest_combo_magnitude(lines)
-}
-
+// we take a Vec<str> and parse out a new number each time
+//
 // Addition is not commutative, so to brute force all combinations
 // we have to add them all twice in both directions
 // runs in around 3 seconds. 
-fn find_largest_combo_magnitude(lines: Vec<&str>) -> i32 {
-    let mut largest = 0;
-    for i in 0..lines.len() {
-        for j in (i+1)..lines.len() {
-            let lhs = parse_line(lines[i]);
-            let rhs = parse_line(lines[j]);
-            let magnitude = add(lhs, rhs).borrow().magnitude();
-            if magnitude > largest {
-                largest = magnitude;
-            }
-        }
-    }
-    for i in (1..lines.len()).rev() {
-        for j in (0..(i-1)).rev() {
-            let lhs = parse_line(lines[i]);
-            let rhs = parse_line(lines[j]);
-            let magnitude = add(lhs, rhs).borrow().magnitude();
-            if magnitude > largest {
-                largest = magnitude;
-            }
-        }
-    }
-    largest
+// Each sum parses its own numbers, so with the parallel feature they can all be worked out at the same time
+// (the Rc pointers in a snail number never leave the thread that parsed it)
+pub fn find_largest_combo_magnitude(lines: Vec<&str>) -> i32 {
+    let pairs: Vec<(usize, usize)> = (0..lines.len())
+        .flat_map(|i| (0..lines.len()).filter(move |&j| j != i).map(move |j| (i, j)))
+        .collect();
+    let magnitude = |&(i, j): &(usize, usize)| {
+        let lhs = parse_line(lines[i]).expect(CHECKED);
+        let rhs = parse_line(lines[j]).expect(CHECKED);
+        // the let makes the borrow end before the sum is dropped
+        let magnitude = add(lhs, rhs).borrow().magnitude();
+        magnitude
+    };
+    #[cfg(not(feature = "parallel"))]
+    let largest = pairs.iter().map(magnitude).max();
+    #[cfg(feature = "parallel")]
+    let largest = pairs.par_iter().map(magnitude).max();
+    largest.unwrap_or(0)
 }
 
 // Add two snail numbers
 fn add(lhs: SnailNumber, rhs: SnailNumber) -> SnailNumber {
     let result = SnailNumberNode::from_pair(lhs, rhs);
-    return reduce(result);
+    reduce(result)
 }
 
 // Do the reducing steps in a loop until no more steps are required
@@ -224,7 +228,7 @@ fn reduce(number: SnailNumber) -> SnailNumber {
         }
         break;
     }
-    return number;
+    number
 }
 
 // Explode step. Traverse the numbers until we find an explosion
@@ -248,7 +252,7 @@ fn explode(number: SnailNumber) -> bool {
         current.right = None;
         current.left = None;
         current.value = Some(0);
-        return true;
+        true
     }
     else {
         return explode(number.borrow().left_unwrap())
@@ -266,63 +270,127 @@ fn split(number: SnailNumber) -> bool {
             let lhs = SnailNumberNode::from_value(v.floor() as i32);
             let rhs = SnailNumberNode::from_value(v.ceil() as i32);
             
-            lhs.borrow_mut().parent = Some(number.clone());
-            rhs.borrow_mut().parent = Some(number.clone());
+            lhs.borrow_mut().parent = Some(Rc::downgrade(&number));
+            rhs.borrow_mut().parent = Some(Rc::downgrade(&number));
             current.value = None;
             current.left = Some(lhs);
             current.right = Some(rhs);
             return true;
         }
-        return false;
+        false
     } else{
-        return split(current.left_unwrap().clone()) 
-            || split(current.right_unwrap().clone());
+        split(current.left_unwrap().clone()) 
+            || split(current.right_unwrap().clone())
     }
 }
 
-fn parse_input(input: &str) -> Vec<SnailNumber> {
-    input.lines().map(|line| parse_line(line.trim())).collect()
+// The input is parsed again for each part, after read_input has already checked it
+const CHECKED: &str = "snail numbers are checked when the input is read";
+
+// The numbers in the input are already reduced, so no pair is more than 4 deep.
+// Adding two of them nests everything one deeper, and explode only handles pairs exactly 5 deep
+const INPUT_DEPTH: usize = 4;
+
+pub fn parse_input(input: &str) -> Result<Vec<SnailNumber>> {
+    error::lines(input).map(|line| snail_number_from(&line, INPUT_DEPTH)).collect()
 }
 
-fn parse_line(input: &str) -> SnailNumber {
-    let chars: Vec<char> = input.chars().collect();
-    parse_snail_number(&chars[..]).0
+// A single number, which can be one in the middle of an addition that still needs to explode
+pub fn parse_line(input: &str) -> Result<SnailNumber> {
+    snail_number_from(&Line { number: 1, text: input.trim() }, INPUT_DEPTH + 1)
 }
 
-fn parse_snail_number(chars: &[char]) -> (SnailNumber, usize) {
-    let mut index: usize = 0;
-    index += 1; // [
-
-    let left;
-    let right;
-    if chars[index] == '[' {
-        let (number, size) = parse_snail_number(&chars[index..]);
-        left = number;
-        index += size + 1;
-    } else {
-        let value = chars[index].to_string().parse().unwrap();
-        left = SnailNumberNode::from_value(value);
-        index += 1;
+fn snail_number_from(line: &Line, max_depth: usize) -> Result<SnailNumber> {
+    let chars: Vec<char> = line.text.chars().collect();
+    let (number, end) = parse_snail_number(&chars, 0, 1, max_depth).map_err(|(index, detail)| {
+        // Point at the character the parser stopped on, or just past the end of the line
+        let start = line.text.char_indices().nth(index).map_or(line.text.len(), |(i, _)| i);
+        let end = line.text[start..].chars().next().map_or(start, |c| start + c.len_utf8());
+        line.error_in("day18", &line.text[start..end], detail)
+    })?;
+    if end + 1 < chars.len() {
+        let rest = line.text.char_indices().nth(end + 1).map_or("", |(i, _)| &line.text[i..]);
+        return Err(line.error_in("day18", rest, "extra characters after the snail number"));
     }
+    Ok(number)
+}
 
-    index += 1; // ','
+// Parse the pair starting at index. Returns the number and the index of its closing ']'.
+// Errors are the index of the character where it went wrong, and what was expected there.
+// depth is how many pairs this one is inside of, counting itself
+fn parse_snail_number(chars: &[char], index: usize, depth: usize, max_depth: usize) -> std::result::Result<(SnailNumber, usize), (usize, String)> {
+    if depth > max_depth {
+        return Err((index, format!("pairs can only be nested {} deep", max_depth)));
+    }
+    expect(chars, index, '[')?;
+    let (left, index) = parse_element(chars, index + 1, depth, max_depth)?;
+    expect(chars, index, ',')?;
+    let (right, index) = parse_element(chars, index + 1, depth, max_depth)?;
+    expect(chars, index, ']')?;
+    Ok((SnailNumberNode::from_pair(left, right), index))
+}
 
-    if chars[index] == '[' {
-        let (number, size) = parse_snail_number(&chars[index..]);
-        right = number;
-        index += size + 1;
-    } else {
-        let value = chars[index].to_string().parse().unwrap();
-        right = SnailNumberNode::from_value(value);
-        index += 1;
+// Either side of a pair: a single digit or another pair. Returns it and the index just after it
+fn parse_element(chars: &[char], index: usize, depth: usize, max_depth: usize) -> std::result::Result<(SnailNumber, usize), (usize, String)> {
+    match chars.get(index) {
+        Some('[') => parse_snail_number(chars, index, depth + 1, max_depth).map(|(number, end)| (number, end + 1)),
+        Some(c) if c.is_ascii_digit() => Ok((SnailNumberNode::from_value(c.to_digit(10).unwrap() as i32), index + 1)),
+        Some(c) => Err((index, format!("expected a digit or '[', found '{}'", c))),
+        None => Err((index, "the line ends too soon".to_string()))
     }
+}
 
-    (SnailNumberNode::from_pair(left, right), index)
+fn expect(chars: &[char], index: usize, expected: char) -> std::result::Result<(), (usize, String)> {
+    match chars.get(index) {
+        Some(&c) if c == expected => Ok(()),
+        Some(c) => Err((index, format!("expected '{}', found '{}'", expected, c))),
+        None => Err((index, "the line ends too soon".to_string()))
+    }
 }
 
-pub fn read_input() -> Vec<SnailNumber> {
-    let input = fs::read_to_string("src/day18/numbers.txt").expect("missing numbers.txt");
-    parse_input(&input)
+// Returns the raw input rather than parsed numbers - see the note on part 2 about why.
+// Every line is parsed once here to check it
+pub fn read_input(path: &str) -> Result<String> {
+    let input = error::read_input(path)?;
+    parse_input(&input)?;
+    Ok(input)
+}
+
+// The lines with numbers on them
+fn numbers(input: &str) -> Vec<&str> {
+    input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()).collect()
+}
+
+// Adding snail numbers mutates them, so keep the raw input
+// and parse a fresh set of numbers for each part
+pub struct Day18;
+
+// The input is kept as text rather than parsed numbers - see the note on part 2 about why
+impl Solver for Day18 {
+    type Input = String;
+    type Output = i32;
+
+    const TITLE: &'static str = "Snailfish";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day18/numbers.txt";
+
+    fn parse(input: &str) -> Result<String> {
+        parse_input(input)?;
+        Ok(input.to_string())
+    }
+
+    fn solve_part1(input: &String) -> i32 {
+        if cfg!(feature = "arena") {
+            return arena::sum_magnitude(&numbers(input));
+        }
+        add_all(parse_input(input).expect(CHECKED)).borrow().magnitude()
+    }
+
+    fn solve_part2(input: &String) -> i32 {
+        if cfg!(feature = "arena") {
+            return arena::find_largest_combo_magnitude(&numbers(input));
+        }
+        find_largest_combo_magnitude(numbers(input))
+    }
 }
 
 #[cfg(test)]
@@ -331,17 +399,21 @@ mod tests {
 
     #[test]
     fn test_snail_creation() {
-        let sn = parse_line("[9,[8,7]]");
+        let sn = parse_line("[9,[8,7]]").unwrap();
         assert_eq!(9, sn.borrow().left_unwrap().borrow().value.unwrap());
 
-        let sn = parse_line("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]");
+        let sn = parse_line("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]").unwrap();
         assert_eq!("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]", format!("{:?}", sn.borrow()));
+
+        // the input has to be reduced already
+        assert!(parse_input("[[[[[9,8],1],2],3],4]").is_err());
+        assert!(parse_line(&"[".repeat(10_000)).is_err());
     }
 
     #[test]
     fn test_split() {
         // parser doesn't allow 2 char numbers - so for the split test, add them in after the fact
-        let sn = parse_line("[[[[0,7],4],[0,[0,0]]],[1,1]]");
+        let sn = parse_line("[[[[0,7],4],[0,[0,0]]],[1,1]]").unwrap();
         sn.borrow().left_unwrap().borrow().right_unwrap().borrow().left_unwrap().borrow_mut().value = Some(15);
         sn.borrow().left_unwrap().borrow().right_unwrap().borrow().right_unwrap().borrow().right_unwrap().borrow_mut().value = Some(13);
         assert_eq!("[[[[0,7],4],[15,[0,13]]],[1,1]]", format!("{:?}", sn.borrow()));
@@ -353,28 +425,28 @@ mod tests {
 
     #[test]
     fn test_explode() {
-        let sn = parse_line("[[[[[9,8],1],2],3],4]");
+        let sn = parse_line("[[[[[9,8],1],2],3],4]").unwrap();
         explode(sn.clone());
         assert_eq!("[[[[0,9],2],3],4]", format!("{:?}", sn.borrow()));
 
-        let sn = parse_line("[[6,[5,[4,[3,2]]]],1]");
+        let sn = parse_line("[[6,[5,[4,[3,2]]]],1]").unwrap();
         explode(sn.clone());
         assert_eq!("[[6,[5,[7,0]]],3]", format!("{:?}", sn.borrow()));
     }
 
     #[test]
     fn test_snail_addition() {
-        let lhs = parse_line("[[[[4,3],4],4],[7,[[8,4],9]]]");
-        let rhs = parse_line("[1,1]");
+        let lhs = parse_line("[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap();
+        let rhs = parse_line("[1,1]").unwrap();
         assert_eq!("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", format!("{:?}", add(lhs, rhs).borrow()));
     }
 
     #[test]
     fn test_snail_number_magnitude() {
-        let sn = parse_line("[[1,2],[[3,4],5]]");
+        let sn = parse_line("[[1,2],[[3,4],5]]").unwrap();
         assert_eq!(143, sn.borrow().magnitude());
 
-        let sn = parse_line("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]");
+        let sn = parse_line("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").unwrap();
         assert_eq!(3488, sn.borrow().magnitude());
     }
 
@@ -390,7 +462,7 @@ mod tests {
             [[9,3],[[9,9],[6,[4,9]]]]
             [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
             [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
-        let numbers = parse_input(input);
+        let numbers = parse_input(input).unwrap();
         let result = add_all(numbers);
         assert_eq!(4140, result.borrow().magnitude());
     }
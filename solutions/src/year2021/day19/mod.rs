@@ -17,55 +17,49 @@ Part 2: What is the manhattan distance of the scanners that are the farthest apa
 
 use std::collections::HashSet;
 use std::collections::HashMap;
-use std::fs;
+use std::cell::OnceCell;
+use crate::progress::Progress;
+use crate::error::{self, Result};
+use crate::geometry::Point3;
+use crate::solution::Solver;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
-pub struct Point {
-    x: i32,
-    y: i32,
-    z: i32
-}
-
-impl Point {
-    fn new(x: i32, y: i32, z: i32) -> Point {
-        Point { x, y, z }
-    }
-
-    // euclidean distance is a float. Truncate to i32 to avoid potential FP issues
-    // and to just be easier to deal with in general.
-    fn distance(&self, other: &Point) -> i32 {
-        f32::sqrt(((self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2)) as f32) as i32
-    }
-
-    fn translate(&self, other: &Point) -> Point {
-        Point::new(self.x + other.x, self.y + other.y, self.z + other.z)
-    }
-
-    fn manhattan(&self, other: &Point) -> i32 {
-        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
-    }
+// euclidean distance is a float. Truncate to i32 to avoid potential FP issues
+// and to just be easier to deal with in general.
+fn distance(a: &Point3, b: &Point3) -> i32 {
+    let d = *a - *b;
+    f32::sqrt((d.x.pow(2) + d.y.pow(2) + d.z.pow(2)) as f32) as i32
 }
 
 // Parts 1 and 2. Not the cleanest solution, and takes around 22 seconds to run.
 // Brute force each possible rotation of each scanner compared to a set of known beacon positions.
-pub fn locate_beacons(scanners: &Vec<Vec<Point>>) -> (usize, i32) {
+pub fn locate_beacons(scanners: &[Vec<Point3>]) -> (usize, i32) {
     // Start with Scanner 0 as the reference beacons - store in a set of known beacons
-    let mut known_beacons: HashSet<Point> = scanners[0].iter().map(|p| p.clone()).collect();
-    let mut known_scanners = vec![Point::new(0,0,0)];
+    let mut known_beacons: HashSet<Point3> = scanners[0].iter().cloned().collect();
+    let mut known_scanners = vec![Point3::ORIGIN];
     // Other scanners are marked as unknown
     let mut unknown_scanners: Vec<usize> = (1..scanners.len()).collect();
+    let progress = Progress::new(unknown_scanners.len() as u64, "scanners placed");
     // compare unknown scanners to known beacon positions until all scanners are known
-    while unknown_scanners.len() > 0 {
-        for &i in &unknown_scanners {
-            // Check if we can determine the position of this scanner
-            if let Some((scanner, beacons)) = determine_scanner_location(&scanners[i], &known_beacons) {
-                known_scanners.push(scanner);
-                for p in beacons {
-                    known_beacons.insert(p);
-                }
-                unknown_scanners.retain(|&index| index != i);
-                break;
+    while !unknown_scanners.is_empty() {
+        // Check if we can determine the position of each scanner, and take the first one that works.
+        // With the parallel feature the scanners are checked at the same time, it's still the first
+        // in order that gets placed so the search goes the same way
+        let locate = |&i: &usize| determine_scanner_location(&scanners[i], &known_beacons).map(|located| (i, located));
+        #[cfg(not(feature = "parallel"))]
+        let located = unknown_scanners.iter().find_map(locate);
+        #[cfg(feature = "parallel")]
+        let located = unknown_scanners.par_iter().find_map_first(locate);
+
+        if let Some((i, (scanner, beacons))) = located {
+            known_scanners.push(scanner);
+            for p in beacons {
+                known_beacons.insert(p);
             }
+            unknown_scanners.retain(|&index| index != i);
+            progress.inc_now();
+            log::debug!("placed scanner {}, {} beacons known", i, known_beacons.len());
         }
     }
 
@@ -80,7 +74,7 @@ pub fn locate_beacons(scanners: &Vec<Vec<Point>>) -> (usize, i32) {
             }
         }
     }
-    return (known_beacons.len(), farthest);
+    (known_beacons.len(), farthest)
 }
 
 /*
@@ -93,29 +87,29 @@ loop through rotations
             apply the rotation and translation to all beacons in the scanner
             scanner position is the translation (relative to 0,0,0)
 */
-fn determine_scanner_location(scanner: &Vec<Point>, known_points: &HashSet<Point>) -> Option<(Point, Vec<Point>)> {
-    for rotation in 1..=24 {
-        let rotated_points: Vec<_> = scanner.iter().map(|p| rotate(&p, rotation)).collect();
-        let mut distance_map: HashMap<i32, Vec<(&Point, &Point)>> = HashMap::new();
+fn determine_scanner_location(scanner: &[Point3], known_points: &HashSet<Point3>) -> Option<(Point3, Vec<Point3>)> {
+    for rotation in 0..Point3::ROTATIONS {
+        let rotated_points: Vec<_> = scanner.iter().map(|p| p.rotate(rotation)).collect();
+        let mut distance_map: HashMap<i32, Vec<(&Point3, &Point3)>> = HashMap::new();
         for p in &rotated_points {
             for known in known_points {
-                let distance = p.distance(known);
+                let distance = distance(p, known);
                 let list = distance_map.entry(distance).or_insert(vec![]);
                 list.push((p, known));
             }
         }
         for (_, possible_translation) in distance_map.iter().filter(|(_,v)| v.len() >= 12){
             for &pair in possible_translation {
-                let translation = Point::new(pair.1.x - pair.0.x, pair.1.y - pair.0.y, pair.1.z - pair.0.z);
+                let translation = *pair.1 - *pair.0;
                 let mut match_count = 0;
                 for &p in possible_translation {
-                    if p.0.translate(&translation) == *p.1 {
+                    if *p.0 + translation == *p.1 {
                         match_count += 1;
                     }
                 }
                 if match_count >= 12 {
-                    let translated: Vec<Point> = rotated_points.iter()
-                        .map(|beacon| beacon.translate(&translation))
+                    let translated: Vec<Point3> = rotated_points.iter()
+                        .map(|&beacon| beacon + translation)
                         .collect();
                     return Some((translation, translated));
                 }
@@ -125,64 +119,66 @@ fn determine_scanner_location(scanner: &Vec<Point>, known_points: &HashSet<Point
     None
 }
 
-// computed these by hand by taking a cube, writing x,y,z,-x,-y,-z on the sides
-// then rotating it in all possible directions until we had 24 states
-// (would have been easier to just do all 48 possible orientations)
-fn rotate(p: &Point, rotation: i32) -> Point {
-    match rotation {
-        1 => Point::new(p.x, p.y, p.z),
-        2 => Point::new(p.x, -p.z, p.y),
-        3 => Point::new(p.x, -p.y, -p.z),
-        4 => Point::new(p.x, p.z, -p.y),
-        5 => Point::new(p.z, p.y, -p.x),
-        6 => Point::new(p.z, p.x, p.y),
-        7 => Point::new(p.z, -p.y, p.x),
-        8 => Point::new(p.z, -p.x, -p.y),
-        9 => Point::new(-p.x, p.y, -p.z),
-        10 => Point::new(-p.x, p.z, p.y),
-        11 => Point::new(-p.x, -p.y, p.z),
-        12 => Point::new(-p.x, -p.z, -p.y),
-        13 => Point::new(p.y, -p.x, p.z),
-        14 => Point::new(p.y, -p.z, -p.x),
-        15 => Point::new(p.y, p.x, -p.z),
-        16 => Point::new(p.y, p.z, p.x),
-        17 => Point::new(-p.y, p.z, -p.x),
-        18 => Point::new(-p.y, p.x, p.z),
-        19 => Point::new(-p.y, -p.z, p.x),
-        20 => Point::new(-p.y, -p.x, -p.z),
-        21 => Point::new(-p.z, p.y, p.x),
-        22 => Point::new(-p.z, -p.x, p.y),
-        23 => Point::new(-p.z, -p.y, -p.x),
-        24 => Point::new(-p.z, p.x, -p.y),
-        _ => panic!("invalid rotation")
-    }
-}
-
-fn parse_input(input: &str) -> Vec<Vec<Point>> {
-    input.split("\n\n")
+pub fn parse_input(input: &str) -> Result<Vec<Vec<Point3>>> {
+    error::sections(input).into_iter()
         .map(|scanner| {
-            let mut lines = scanner.lines();
-            lines.next(); // skip --- scanner --- line
-            lines.map(|line| {
-                let p: Vec<i32> = line.trim().split(",").map(|p| p.parse().unwrap()).collect();
-                Point::new(p[0], p[1], p[2])
+            // skip --- scanner --- line
+            scanner.iter().skip(1).map(|line| {
+                let p: Vec<i32> = line.text.split(",").filter_map(|p| p.parse().ok()).collect();
+                if p.len() != 3 || line.text.split(",").count() != 3 {
+                    return Err(line.error("day19", "expected a beacon like \"-618,-824,-621\""));
+                }
+                Ok(Point3::new(p[0], p[1], p[2]))
             })
             .collect()
         })
         .collect()
 }
 
-pub fn read_input() -> Vec<Vec<Point>> {
-    let input = fs::read_to_string("src/day19/scanners.txt").expect("missing scanners.txt");
+pub fn read_input(path: &str) -> Result<Vec<Vec<Point3>>> {
+    let input = error::read_input(path)?;
     parse_input(&input)
 }
 
+// Both parts come out of locate_beacons, so the result is kept for whichever part runs second
+pub struct Scanners {
+    scanners: Vec<Vec<Point3>>,
+    located: OnceCell<(usize, i32)>
+}
+
+impl Scanners {
+    fn located(&self) -> (usize, i32) {
+        *self.located.get_or_init(|| locate_beacons(&self.scanners))
+    }
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = Scanners;
+    type Output = usize;
+
+    const TITLE: &'static str = "Beacon Scanner";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day19/scanners.txt";
+
+    fn parse(input: &str) -> Result<Scanners> {
+        Ok(Scanners { scanners: parse_input(input)?, located: OnceCell::new() })
+    }
+
+    fn solve_part1(scanners: &Scanners) -> usize {
+        scanners.located().0
+    }
+
+    fn solve_part2(scanners: &Scanners) -> usize {
+        scanners.located().1 as usize
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn get_scanner_data() -> Vec<Vec<Point>> {
+    fn get_scanner_data() -> Vec<Vec<Point3>> {
         let input = "--- scanner 0 ---
             404,-588,-901
             528,-643,409
@@ -319,7 +315,7 @@ mod tests {
             891,-625,532
             -652,-548,-490
             30,-46,-14";
-        parse_input(input)
+        parse_input(input).unwrap()
     }
     
     #[test]
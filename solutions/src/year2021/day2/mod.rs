@@ -0,0 +1,186 @@
+/*
+Day 2: Dive!
+
+The puzzle input is a list of commands "forward", "down", and "up" followed by an integer.
+
+Part 1: "forward" adds to the horizontal position, "down" increases depth, "up" decreases depth.
+Find the product of the position and the depth after processing all commands.
+
+Part 2: There is a third component called aim. "down X" increases aim by X. "up X" decreases aim by X.
+The "forward X" command increases horizontal position by X AND increases depth by aim times X.
+*/
+
+use std::io::BufRead;
+use std::str::FromStr;
+use crate::error::{self, AdventError, Line, PartResult, Result};
+use crate::solution::Solver;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64)
+}
+
+pub fn calc_position(commands: &[Command]) -> i64 {
+    let mut position = (0, 0);
+    for command in commands {
+        let (x,y) = position;
+        position = match *command {
+            Command::Forward(value) => (x + value, y),
+            Command::Down(value)    => (x, y + value),
+            Command::Up(value)      => (x, y - value)
+        }
+    }
+    position.0 * position.1
+}
+
+#[derive(Debug)]
+struct Heading {
+    aim: i64,
+    position: i64,
+    depth: i64
+}
+
+pub fn calc_aim(commands: &[Command]) -> i64 {
+    let mut heading = Heading { aim: 0, position: 0, depth: 0 };
+    for command in commands {
+        heading = match *command {
+            Command::Forward(value) => Heading { aim: heading.aim, position: heading.position + value, depth: heading.depth + heading.aim * value },
+            Command::Down(value) => Heading { aim: heading.aim + value, position: heading.position, depth: heading.depth },
+            Command::Up(value) => Heading { aim: heading.aim - value, position: heading.position, depth: heading.depth }
+        }
+    }
+    heading.position * heading.depth
+}
+
+// Both parts in one walk over the commands. The position is the same for both,
+// and the depth in part 1 is the aim in part 2
+pub fn dive(commands: impl IntoIterator<Item = Command>) -> (i64, i64) {
+    let mut heading = Heading { aim: 0, position: 0, depth: 0 };
+    for command in commands {
+        match command {
+            Command::Forward(value) => {
+                heading.position += value;
+                heading.depth += heading.aim * value;
+            }
+            Command::Down(value) => heading.aim += value,
+            Command::Up(value) => heading.aim -= value
+        }
+    }
+    (heading.position * heading.aim, heading.position * heading.depth)
+}
+
+// dive() on the commands as they're read, so they're never all in memory at once, however long the log is.
+// Stops at the first line that isn't a command
+pub fn dive_from(reader: &mut dyn BufRead, path: &str) -> Result<(i64, i64)> {
+    let mut failed = None;
+    let commands = error::stream_lines(reader, path, parse_command)
+        .map_while(|command| command.map_err(|error| failed = Some(error)).ok());
+    let products = dive(commands);
+    failed.map_or(Ok(products), Err)
+}
+
+// Reads the file a line at a time
+pub fn read_commands(path: &str) -> Result<Vec<Command>> {
+    error::stream_lines(&mut error::open_input(path)?, path, parse_command).collect()
+}
+
+pub fn parse_commands(input: &str) -> Result<Vec<Command>> {
+    error::lines(input).map(parse_command).collect()
+}
+
+fn parse_command(line: Line) -> Result<Command> {
+    line.parse_with("day2", split_command)
+}
+
+// "forward 5" on its own, without a line to point at in the error
+impl FromStr for Command {
+    type Err = AdventError;
+
+    fn from_str(text: &str) -> Result<Command> {
+        error::parse_text("day2", text, split_command)
+    }
+}
+
+// The command, or the part of the text that's wrong and what's wrong with it
+fn split_command(text: &str) -> PartResult<'_, Command, &'static str> {
+    let (verb, value) = text.split_once(' ').ok_or((text, "expected a command like \"forward 5\""))?;
+    let command: fn(i64) -> Command = match verb {
+        "forward" => Command::Forward,
+        "down" => Command::Down,
+        "up" => Command::Up,
+        _ => return Err((verb, "expected forward, down or up"))
+    };
+    value.parse().map(command).map_err(|_| (value, "expected a number"))
+}
+
+pub struct Day2;
+
+// The input is the answers to both parts, worked out with dive() as soon as it's parsed
+// rather than going through the commands once for each part
+impl Solver for Day2 {
+    type Input = (i64, i64);
+    type Output = i64;
+
+    const TITLE: &'static str = "Dive!";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day2/commands.txt";
+
+    fn parse(input: &str) -> Result<(i64, i64)> {
+        Ok(dive(parse_commands(input)?))
+    }
+
+    fn read(reader: &mut dyn BufRead, path: &str) -> Result<(i64, i64)> {
+        dive_from(reader, path)
+    }
+
+    fn solve_part1(&(position, _): &(i64, i64)) -> i64 {
+        position
+    }
+
+    fn solve_part2(&(_, aim): &(i64, i64)) -> i64 {
+        aim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calc_position() {
+        let commands = parse_commands("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2").unwrap();
+        assert_eq!(150, calc_position(&commands));
+    }
+
+    #[test]
+    fn test_calc_aim() {
+        let commands = parse_commands("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2").unwrap();
+        assert_eq!(900, calc_aim(&commands));
+    }
+
+    #[test]
+    fn test_dive() {
+        let commands = parse_commands("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2").unwrap();
+        assert_eq!((150, 900), dive(commands));
+        assert_eq!((0, 0), dive([]));
+    }
+
+    #[test]
+    fn test_dive_from() {
+        let log = "forward 5\ndown 5\nforward 8\r\nup 3\ndown 8\nforward 2\n\n";
+        assert_eq!((150, 900), dive_from(&mut log.as_bytes(), "log").unwrap());
+        let error = dive_from(&mut "forward 5\n\nup 3".as_bytes(), "log").unwrap_err();
+        assert!(error.to_string().starts_with("invalid day2 input on line 2"));
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(Command::Forward(5), "forward 5".parse().unwrap());
+        assert_eq!(Command::Up(3), " up 3 ".parse().unwrap());
+        assert_eq!("invalid day2 input: expected forward, down or up", "sideways 2".parse::<Command>().unwrap_err().to_string());
+        assert_eq!("invalid day2 input: expected a number", "down x".parse::<Command>().unwrap_err().to_string());
+        let error = parse_commands("forward 5\nbackward 2").unwrap_err();
+        assert_eq!("invalid day2 input on line 2, column 1: expected forward, down or up\n  2 | backward 2\n    | ^^^^^^^^", error.to_string());
+    }
+}
@@ -0,0 +1,220 @@
+/*
+Day 20: Trench Map
+
+You have 2 parts, an image enhancement algorithm, and an image map.
+The image map is a 2d grid. To enhance each point in the grid:
+    look at the surrounding points (9 points total)
+    Convert them to binary. For example:
+    ...
+    #..
+    .#.
+    ...#...#. == 000100010 == 34
+    look up the 34th character in the enhancement algorithm to get the result for that point.
+All points are process simultaneously
+The output grid is infinute expanding in all directions
+
+Part 1: how many points are "on" in the '#' position after 2 steps
+Part 2: how many points are on after 50 steps
+
+With the simd feature the solver works on the image packed into bits instead (see packed.rs).
+*/
+
+pub mod packed;
+
+
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::error::{self, AdventError, Line, Result};
+use crate::point_set::PointSet;
+use crate::solution::Solver;
+use crate::viz::Render;
+
+// The infinite image. Far enough out every pixel is the same, the background,
+// so only the pixels that aren't the background are kept, in a sparse set
+struct Image {
+    differ: PointSet,
+    background: bool
+}
+
+impl Image {
+    fn new(image: &Grid<bool>) -> Image {
+        Image { differ: PointSet::from(image), background: false }
+    }
+
+    fn get(&self, point: Point2) -> bool {
+        self.differ.contains(&point) != self.background
+    }
+
+    // The trick with the infinite grid is the first and last char in the enhance array
+    // in the sample, both are '.' so the background stays off.
+    // but in the puzzle input, ehnance[0] == '#'. Which means that a grid of 9 falses evaluates to true.
+    // Also, a grid on 9 trues evaluates to false. This means the background flips from true/false every step.
+    // Only pixels next to one that differs can change differently from the background,
+    // so the search area is the box around them plus one row/col in each direction
+    fn enhance(&self, enhance: &[bool]) -> Image {
+        let background = if self.background { enhance[511] } else { enhance[0] };
+        let mut differ = PointSet::new();
+        if let Some((min, max)) = self.differ.bounds() {
+            for y in min.y - 1..=max.y + 1 {
+                for x in min.x - 1..=max.x + 1 {
+                    let point = Point2::new(x, y);
+                    if enhance[find_surrounding(point, self)] != background {
+                        differ.insert(point);
+                    }
+                }
+            }
+        }
+        Image { differ, background }
+    }
+}
+
+// Parts 1 & 2 - just change the number of steps
+// An image with the background on has infinitely many lit pixels, so there's no count.
+// The puzzle asks after an even number of steps, when the background is back off
+pub fn count_after_steps(image: &Grid<bool>, enhance: &[bool], steps: usize) -> usize {
+    let enhanced = run_steps(image, enhance, steps);
+    assert!(!enhanced.background, "every pixel past the edge of the image is lit after {} steps", steps);
+    enhanced.differ.len()
+}
+
+// The part of the image that isn't background after the steps
+pub fn enhance_image(image: &Grid<bool>, enhance: &[bool], steps: usize) -> Grid<bool> {
+    let enhanced = run_steps(image, enhance, steps);
+    let (min, max) = enhanced.differ.bounds().unwrap_or_default();
+    Grid::from_fn((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, |(row, col)| {
+        enhanced.get(min + Point2::new(col as i32, row as i32))
+    })
+}
+
+fn run_steps(image: &Grid<bool>, enhance: &[bool], steps: usize) -> Image {
+    (0..steps).fold(Image::new(image), |image, _| image.enhance(enhance))
+}
+
+// The 3x3 square around the point, including the point itself, read left to right and top to bottom
+fn find_surrounding(point: Point2, image: &Image) -> usize {
+    let mut index = 0;
+    for y in point.y - 1..=point.y + 1 {
+        for x in point.x - 1..=point.x + 1 {
+            // shift in a 1 or 0 bit
+            index = index << 1 | image.get(Point2::new(x, y)) as usize;
+        }
+    }
+    index
+}
+
+// One '#' or '.' for each of the 512 possible 3x3 squares
+pub fn parse_enhancement_algo(input: &str) -> Result<Vec<bool>> {
+    enhancement_from(&Line { number: 1, text: input.trim() })
+}
+
+fn enhancement_from(line: &Line) -> Result<Vec<bool>> {
+    if let Some((i, c)) = line.text.char_indices().find(|&(_, c)| pixel(c).is_none()) {
+        return Err(line.error_in("day20", &line.text[i..i + c.len_utf8()], format!("expected '#' or '.', found '{}'", c)));
+    }
+    if line.text.len() != 512 {
+        return Err(line.error("day20", format!("the enhancement algorithm should be 512 characters, not {}", line.text.len())));
+    }
+    Ok(line.text.chars().filter_map(pixel).collect())
+}
+
+pub fn parse_input_image(input: &str) -> Result<Grid<bool>> {
+    Grid::parse("day20", input, pixel)
+}
+
+fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    }
+}
+
+pub fn read_data(path: &str) -> Result<(Grid<bool>, Vec<bool>)> {
+    let input = error::read_input(path)?;
+    parse_data(&input)
+}
+
+// The enhancement algorithm is the first line, then a blank line, then the image
+pub fn parse_data(input: &str) -> Result<(Grid<bool>, Vec<bool>)> {
+    let sections = error::sections(input);
+    let [enhance, image] = &sections[..] else {
+        return Err(AdventError::parse("day20", "expected the enhancement algorithm, a blank line, then the image"));
+    };
+    if let [_, extra, ..] = enhance[..] {
+        return Err(extra.error("day20", "the enhancement algorithm should be one line"));
+    }
+    Ok((Grid::from_lines("day20", image.iter().copied(), pixel)?, enhancement_from(&enhance[0])?))
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = (Grid<bool>, Vec<bool>);
+    type Output = usize;
+
+    const TITLE: &'static str = "Trench Map";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day20/trench.txt";
+
+    fn parse(input: &str) -> Result<(Grid<bool>, Vec<bool>)> {
+        parse_data(input)
+    }
+
+    fn solve_part1((image, enhance): &(Grid<bool>, Vec<bool>)) -> usize {
+        if cfg!(feature = "simd") {
+            return packed::count_after_steps(image, enhance, 2);
+        }
+        count_after_steps(image, enhance, 2)
+    }
+
+    fn solve_part2((image, enhance): &(Grid<bool>, Vec<bool>)) -> usize {
+        if cfg!(feature = "simd") {
+            return packed::count_after_steps(image, enhance, 50);
+        }
+        count_after_steps(image, enhance, 50)
+    }
+
+    // The image at the end of part 2
+    fn render((image, enhance): &(Grid<bool>, Vec<bool>)) -> Option<Box<dyn Render>> {
+        Some(Box::new(enhance_image(image, enhance, 50)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> Grid<bool> {
+        let input = 
+            "#..#.
+            #....
+            ##..#
+            ..#..
+            ..###";
+        parse_input_image(input).unwrap()
+    }
+
+    fn get_enhancement() -> Vec<bool> {
+        let input = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#";
+        parse_enhancement_algo(input).unwrap()
+    }
+
+    #[test]
+    fn test_surrouding_number() {
+        let enhance = get_enhancement();
+        let image = get_input();
+        let surrounding = find_surrounding(Point2::new(2, 2), &Image::new(&image));
+        assert_eq!(34, surrounding);
+        assert!(enhance[surrounding]);
+    }
+
+    #[test]
+    fn test_image_enhance() {
+        let enhance = get_enhancement();
+        let image = get_input();
+        assert_eq!(35, count_after_steps(&image, &enhance, 2));
+        assert_eq!(3351, count_after_steps(&image, &enhance, 50));
+    }
+
+}
+
+
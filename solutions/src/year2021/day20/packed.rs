@@ -0,0 +1,98 @@
+/*
+The image packed into bits, 64 pixels to a u64, used by the solver with the simd feature.
+
+Instead of a set of points this is a dense image, with room around it to grow a pixel
+on every side each step. Pixels off the edge are the background.
+Going along a row, the 3x3 square for the next pixel is the last one shifted over a column
+with the new column of 3 bits added on the right, so each pixel costs a few shifts and one lookup.
+*/
+
+use crate::grid::Grid;
+
+struct Image {
+    width: usize,
+    rows: Vec<Vec<u64>>,
+    background: bool
+}
+
+impl Image {
+    // Room for the image to grow by a pixel on every side each step
+    fn new(image: &Grid<bool>, steps: usize) -> Image {
+        let width = image.width() + 2 * steps;
+        let mut rows = vec![vec![0; width.div_ceil(64)]; image.height() + 2 * steps];
+        for ((row, col), &lit) in image.enumerate() {
+            if lit {
+                let col = col + steps;
+                rows[row + steps][col / 64] |= 1 << (col % 64);
+            }
+        }
+        Image { width, rows, background: false }
+    }
+
+    // 0 or 1 for the pixel, the background if it's off the edge
+    fn bit(&self, row: Option<&Vec<u64>>, col: usize) -> usize {
+        match row {
+            Some(row) if col < self.width => (row[col / 64] >> (col % 64)) as usize & 1,
+            _ => self.background as usize
+        }
+    }
+
+    fn enhance(&self, enhance: &[bool]) -> Image {
+        let background = if self.background { enhance[511] } else { enhance[0] };
+        let mut rows = vec![vec![0; self.width.div_ceil(64)]; self.rows.len()];
+        for (r, enhanced) in rows.iter_mut().enumerate() {
+            let above = r.checked_sub(1).and_then(|above| self.rows.get(above));
+            let (row, below) = (self.rows.get(r), self.rows.get(r + 1));
+            // the column of 3 bits at col, top to bottom
+            let column = |col: Option<usize>| match col {
+                Some(col) => self.bit(above, col) << 2 | self.bit(row, col) << 1 | self.bit(below, col),
+                None => self.background as usize * 0b111
+            };
+            // before the first pixel the square has the column off the left edge and column 0
+            let mut square = spread(column(None)) << 1 | spread(column(Some(0)));
+            for col in 0..self.width {
+                // drop the leftmost column and add the next one on the right
+                square = (square << 1 & 0b110_110_110) | spread(column(Some(col + 1)));
+                if enhance[square] {
+                    enhanced[col / 64] |= 1 << (col % 64);
+                }
+            }
+        }
+        Image { width: self.width, rows, background }
+    }
+
+    fn count(&self) -> usize {
+        self.rows.iter().flatten().map(|word| word.count_ones() as usize).sum()
+    }
+}
+
+// A column of 3 bits, top to bottom, moved to where the right hand column goes in the 3x3 square's number
+fn spread(column: usize) -> usize {
+    (column >> 2) << 6 | (column >> 1 & 1) << 3 | (column & 1)
+}
+
+// The same as super::count_after_steps
+pub fn count_after_steps(image: &Grid<bool>, enhance: &[bool], steps: usize) -> usize {
+    let enhanced = (0..steps).fold(Image::new(image, steps), |image, _| image.enhance(enhance));
+    assert!(!enhanced.background, "every pixel past the edge of the image is lit after {} steps", steps);
+    enhanced.count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{parse_data, count_after_steps as count_sparse};
+
+    #[test]
+    fn test_same_as_sparse() {
+        let (image, enhance) = parse_data(&std::fs::read_to_string("src/year2021/day20/example.txt").unwrap()).unwrap();
+        assert_eq!(35, count_after_steps(&image, &enhance, 2));
+        // a background that flips every step
+        let mut flipping = enhance.clone();
+        flipping[0] = true;
+        flipping[511] = false;
+        for steps in [2, 4, 6] {
+            assert_eq!(count_sparse(&image, &flipping, steps), count_after_steps(&image, &flipping, steps));
+        }
+    }
+}
@@ -18,6 +18,9 @@ Find the player who wins the most universes, how many universes does that player
 
 use std::cmp;
 use std::collections::HashMap;
+use crate::answer::Answer;
+use crate::error::{self, AdventError, Result};
+use crate::solution::Solver;
 
 // Part 1 deterministic die struct
 #[derive(Debug)]
@@ -40,7 +43,7 @@ impl DeterministicDie {
         if self.roll > 100 {
             self.roll = 1;
         }
-        return self.roll;
+        self.roll
     }
 }
 
@@ -64,20 +67,18 @@ impl Universe {
         self.p2_score += new_position;
     }
 
-    fn p1_win(&self) -> Option<bool> {
-        if self.p2_score < 21 && self.p1_score < 21 {
+    fn p1_win(&self, winning_score: i32) -> Option<bool> {
+        if self.p2_score < winning_score && self.p1_score < winning_score {
             return None;
         }
-        return Some(self.p1_score > self.p2_score);
+        Some(self.p1_score > self.p2_score)
     }
 }
 
 //Part 1: Play the game out one roll at a time with the deterministic dice
 pub fn play_deterministic(p1_start: i32, p2_start: i32) -> i32 { 
     // each entry is a player with (total_score, current_position)
-    let mut players: Vec<(i32, i32)> = Vec::new();
-    players.push((0, p1_start));
-    players.push((0, p2_start));
+    let mut players: Vec<(i32, i32)> = vec![(0, p1_start), (0, p2_start)];
     let mut die = DeterministicDie::new();
 
     // game ends when the first player reaches 1000
@@ -94,14 +95,20 @@ pub fn play_deterministic(p1_start: i32, p2_start: i32) -> i32 {
             }
         }
     }
-    return players.into_iter().map(|(score, _)| score).min().unwrap() * die.num_roles;
+    players.into_iter().map(|(score, _)| score).min().unwrap() * die.num_roles
 }
 
 // Part 2: recursive DFS with memoization
 // each player can have a score of 0 - 20 and position 1-10
 // This gives a worst case of 44100 states to track (reality is 14222)
 // runs in ~2 seconds
-pub fn dirac_dice(p1_start: i32, p2_start: i32) -> usize {
+pub fn dirac_dice(p1_start: i32, p2_start: i32) -> u128 {
+    dirac_dice_to(p1_start, p2_start, 21)
+}
+
+// The universe counts grow by 27 times every roll. Playing to 21 they stay under 2^50,
+// but a game to 28 already passes u64, so the counts are u128
+pub fn dirac_dice_to(p1_start: i32, p2_start: i32, winning_score: i32) -> u128 {
     let initial_universe = Universe {
         p1_score: 0,
         p2_score: 0,
@@ -110,17 +117,17 @@ pub fn dirac_dice(p1_start: i32, p2_start: i32) -> usize {
     };
 
     // memoize the universe state and how many player 1 and player 2 wins happen for that state
-    let mut memo: HashMap<Universe, (usize,usize)> = HashMap::new();
+    let mut memo: HashMap<Universe, (u128, u128)> = HashMap::new();
 
-    let (p1_wins, p2_wins) = roll_in_universe(&initial_universe, &mut memo);
-    return cmp::max(p1_wins, p2_wins);
+    let (p1_wins, p2_wins) = roll_in_universe(&initial_universe, winning_score, &mut memo);
+    cmp::max(p1_wins, p2_wins)
 }
 
 // Roll the dice for a round of the game
 // create a new universe for each possible roll combination (27 * 27)
 // end universe lines where there is a winner, and track who wins
 // recursively determine the winners for each created universe
-fn roll_in_universe(universe: &Universe, memo: &mut HashMap<Universe, (usize, usize)>) -> (usize, usize) {
+fn roll_in_universe(universe: &Universe, winning_score: i32, memo: &mut HashMap<Universe, (u128, u128)>) -> (u128, u128) {
     if let Some((p1, p2)) = memo.get(universe) {
         return (*p1, *p2);
     }    
@@ -132,7 +139,7 @@ fn roll_in_universe(universe: &Universe, memo: &mut HashMap<Universe, (usize, us
         let mut u = universe.clone();
         let new_pos = calc_position(u.p1_position, p1_roll);
         u.move_p1(new_pos);
-        if let Some(p1_win) = u.p1_win() {
+        if let Some(p1_win) = u.p1_win(winning_score) {
             if p1_win {
                 p1_wins += 1;
             }else {
@@ -145,7 +152,7 @@ fn roll_in_universe(universe: &Universe, memo: &mut HashMap<Universe, (usize, us
             let mut u = u.clone();
             let new_pos = calc_position(u.p2_position, p2_roll);
             u.move_p2(new_pos);
-            if let Some(p1_win) = u.p1_win() {
+            if let Some(p1_win) = u.p1_win(winning_score) {
                 if p1_win {
                     p1_wins += 1;
                 }else {
@@ -155,13 +162,13 @@ fn roll_in_universe(universe: &Universe, memo: &mut HashMap<Universe, (usize, us
                 continue;
             }
             // recursive this universe until we find winners, and how many universes they win in
-            let (p1,p2) = roll_in_universe(&u, memo);
+            let (p1,p2) = roll_in_universe(&u, winning_score, memo);
             p1_wins += p1;
             p2_wins += p2;
         }
     }
     memo.insert(universe.clone(), (p1_wins, p2_wins));
-    return (p1_wins, p2_wins);
+    (p1_wins, p2_wins)
 }
 
 fn calc_position(current: i32, roll: i32) -> i32 {
@@ -180,6 +187,47 @@ fn dice_combos() -> Vec<i32> {
          9]
 }
 
+// Each line looks like "Player 1 starting position: 6"
+pub fn read_starting_positions(path: &str) -> Result<(i32, i32)> {
+    let input = error::read_input(path)?;
+    parse_starting_positions(&input)
+}
+
+// "Player 1 starting position: 4", positions are 1 to 10
+pub fn parse_starting_positions(input: &str) -> Result<(i32, i32)> {
+    let positions: Vec<i32> = error::lines(input)
+        .map(|line| line.text.split_once(": ")
+            .and_then(|(_, position)| position.parse().ok())
+            .filter(|position| (1..=10).contains(position))
+            .ok_or_else(|| line.error("day21", "expected a starting position from 1 to 10")))
+        .collect::<Result<_>>()?;
+    match positions[..] {
+        [p1, p2] => Ok((p1, p2)),
+        _ => Err(AdventError::parse("day21", format!("expected 2 players, found {}", positions.len())))
+    }
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = (i32, i32);
+    type Output = Answer;
+
+    const TITLE: &'static str = "Dirac Dice";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day21/players.txt";
+
+    fn parse(input: &str) -> Result<(i32, i32)> {
+        parse_starting_positions(input)
+    }
+
+    fn solve_part1(&(p1_start, p2_start): &(i32, i32)) -> Answer {
+        play_deterministic(p1_start, p2_start).into()
+    }
+
+    fn solve_part2(&(p1_start, p2_start): &(i32, i32)) -> Answer {
+        dirac_dice(p1_start, p2_start).into()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -194,4 +242,12 @@ mod tests {
     fn test_dirac_uinverse() {
         assert_eq!(444356092776315, dirac_dice(4, 8));
     }
+
+    #[test]
+    fn test_dirac_past_u64() {
+        // playing to 28 is the first game where the winner's count doesn't fit in a u64
+        let wins = dirac_dice_to(4, 8, 28);
+        assert_eq!(20867804065144576999, wins);
+        assert!(wins > u64::MAX.into());
+    }
 }
@@ -17,50 +17,50 @@ Part 2: How many points are on when considering all instructions?
 */
 
 use std::collections::HashSet;
-use std::cmp;
-use std::fs;
+use crate::answer::Answer;
+use crate::error::{self, Line, Result};
+use crate::geometry::Point3;
+use crate::solution::Solver;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Step {
     on: bool,
     cuboid: Cuboid
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Cuboid {
-    x_min: i32,
-    x_max: i32,
-    y_min: i32,
-    y_max: i32,
-    z_min: i32,
-    z_max: i32
+    // the corners with the smallest and largest coordinates, both inclusive
+    min: Point3,
+    max: Point3
 }
 
 impl Cuboid {
     // Attempts to create a new cuboid
     // returns None if the dimensions are invalid
-    fn new(x_min: i32, x_max: i32, y_min: i32, y_max: i32, z_min: i32, z_max: i32) -> Option<Self> {
-        if x_min > x_max || y_min > y_max || z_min > z_max {
+    fn new(min: Point3, max: Point3) -> Option<Self> {
+        if min.x > max.x || min.y > max.y || min.z > max.z {
             return None;
         }
-        Some(Cuboid { x_min, x_max, y_min, y_max, z_min, z_max })
+        Some(Cuboid { min, max })
     }
 
     // Ranges are inclusive, an x range of 1 to 4 has a length of 4 (not 3)
-    // so we add 1 to each dimention to accurately calculate volume
-    fn volume(&self) -> usize {
-        (self.x_max - self.x_min + 1) as usize
-        * (self.y_max - self.y_min + 1) as usize
-        * (self.z_max - self.z_min + 1) as usize
+    // so we add 1 to each dimention to accurately calculate volume.
+    // A side can be up to 2^32 long, which doesn't fit in an i32, and the volume can be up to 2^96
+    fn volume(&self) -> u128 {
+        let length = |min: i32, max: i32| (i64::from(max) - i64::from(min) + 1) as u128;
+        length(self.min.x, self.max.x) * length(self.min.y, self.max.y) * length(self.min.z, self.max.z)
     }
 
     // two cuboids intersect with each other if, for each dimension,
     // the smallest maximum point is greater than the largest minimum point.
     // Inspired by https://stackoverflow.com/a/5556796
     fn intersects(&self, other: &Cuboid) -> bool {
-        return cmp::min(self.x_max, other.x_max) >= cmp::max(self.x_min, other.x_min)
-            && cmp::min(self.y_max, other.y_max) >= cmp::max(self.y_min, other.y_min)
-            && cmp::min(self.z_max, other.z_max) >= cmp::max(self.z_min, other.z_min);
+        Cuboid::new(self.min.max(&other.min), self.max.min(&other.max)).is_some()
     }
 
     // Given two cuboids, subtract the intersecting area of the other cube from self
@@ -68,86 +68,49 @@ impl Cuboid {
     // If the two cuboids do not intersect, just return a vector containing self.
     // This splits self up along each possible intersecting dimension, a total of 6 possible slices.
     // Not all slices will be valid, depending on how the two cuboids intersect;
-    // invalid slices are filtered out of the vector
+    // invalid slices are filtered out of the vector.
+    // There is nothing past i32::MIN or i32::MAX, so the slice on that side is invalid too
     fn subtract(&self, other: &Cuboid) -> Vec<Cuboid> {
         if !self.intersects(other) {
             return vec![self.to_owned()];
         }
+        // corners of the intersection
+        let (inner_min, inner_max) = (self.min.max(&other.min), self.max.min(&other.max));
+        let below = |n: i32| n.checked_sub(1);
+        let above = |n: i32| n.checked_add(1);
         [
-            Cuboid::new(
-                self.x_min,
-                other.x_min - 1,
-                self.y_min,
-                self.y_max,
-                self.z_min,
-                self.z_max
-            ),
-            Cuboid::new(
-                other.x_max + 1,
-                self.x_max,
-                self.y_min,
-                self.y_max,
-                self.z_min,
-                self.z_max
-            ),
-            Cuboid::new(
-                cmp::max(self.x_min, other.x_min),
-                cmp::min(self.x_max, other.x_max),
-                self.y_min,
-                other.y_min -1,
-                self.z_min,
-                self.z_max
-            ),
-            Cuboid::new(
-                cmp::max(self.x_min, other.x_min),
-                cmp::min(self.x_max, other.x_max),
-                other.y_max + 1,
-                self.y_max,
-                self.z_min,
-                self.z_max
-            ),
-            Cuboid::new(
-                cmp::max(self.x_min, other.x_min),
-                cmp::min(self.x_max, other.x_max),
-                cmp::max(self.y_min, other.y_min),
-                cmp::min(self.y_max, other.y_max),
-                self.z_min,
-                other.z_min - 1
-            ),
-            Cuboid::new(
-                cmp::max(self.x_min, other.x_min),
-                cmp::min(self.x_max, other.x_max),
-                cmp::max(self.y_min, other.y_min),
-                cmp::min(self.y_max, other.y_max),
-                other.z_max + 1,
-                self.z_max
-            ),
+            below(other.min.x).and_then(|x| Cuboid::new(self.min, Point3 { x, ..self.max })),
+            above(other.max.x).and_then(|x| Cuboid::new(Point3 { x, ..self.min }, self.max)),
+            below(other.min.y).and_then(|y| Cuboid::new(Point3 { x: inner_min.x, ..self.min }, Point3 { x: inner_max.x, y, ..self.max })),
+            above(other.max.y).and_then(|y| Cuboid::new(Point3 { x: inner_min.x, y, ..self.min }, Point3 { x: inner_max.x, ..self.max })),
+            below(other.min.z).and_then(|z| Cuboid::new(Point3 { z: self.min.z, ..inner_min }, Point3 { z, ..inner_max })),
+            above(other.max.z).and_then(|z| Cuboid::new(Point3 { z, ..inner_min }, Point3 { z: self.max.z, ..inner_max })),
         ]
         .into_iter()
-        .filter_map(|c| c)
+        .flatten()
         .collect()
     }
 }
 
 // Part 1: brute force
 // runs in about 1.5 seconds
-pub fn cubes_on_50(steps: &Vec<Step>) -> usize {
-    let filtered_steps: Vec<_> = steps.into_iter().filter(|step| 
-        step.cuboid.x_min >= -50 && step.cuboid.x_max <= 50 && 
-        step.cuboid.y_min >= -50 && step.cuboid.y_max <= 50 &&
-        step.cuboid.z_min >= -50 && step.cuboid.z_max <= 50
+pub fn cubes_on_50(steps: &[Step]) -> usize {
+    let filtered_steps: Vec<_> = steps.iter().filter(|step| 
+        step.cuboid.min.x >= -50 && step.cuboid.max.x <= 50 && 
+        step.cuboid.min.y >= -50 && step.cuboid.max.y <= 50 &&
+        step.cuboid.min.z >= -50 && step.cuboid.max.z <= 50
     ).collect();
     // use a set to represent grid spaces that are on
-    let mut on: HashSet<(i32,i32,i32)> = HashSet::new();
+    let mut on: HashSet<Point3> = HashSet::new();
     for step in filtered_steps {
-        for x in step.cuboid.x_min..=step.cuboid.x_max {
-            for y in step.cuboid.y_min..=step.cuboid.y_max {
-                for z in step.cuboid.z_min..=step.cuboid.z_max {
+        for x in step.cuboid.min.x..=step.cuboid.max.x {
+            for y in step.cuboid.min.y..=step.cuboid.max.y {
+                for z in step.cuboid.min.z..=step.cuboid.max.z {
                     if step.on {
-                        on.insert((x,y,z));
+                        on.insert(Point3::new(x, y, z));
                     }
                     else {
-                        on.remove(&(x,y,z));
+                        on.remove(&Point3::new(x, y, z));
                     }
                 }
             }
@@ -162,15 +125,17 @@ pub fn cubes_on_50(steps: &Vec<Step>) -> usize {
 //      Compare the cuboid of the new step to the existing list of cuboids
 //          If they intersect, split the existing one into component cuboids *that don't intersect*
 //          If the step is "on", add the new cuboid
-// Add up the volumes of the list on cuboids to determine the number of "on" spaces
-pub fn all_cubes_on(steps: &Vec<Step>) -> usize {
+// Add up the volumes of the list on cuboids to determine the number of "on" spaces.
+// The cuboids don't overlap, so the total is at most the 2^96 cubes there are room for
+pub fn all_cubes_on(steps: &[Step]) -> u128 {
     let mut on_cuboids: Vec<Cuboid> = Vec::new();
 
     for step in steps {
-        let mut sliced_cuboids: Vec<Cuboid> = Vec::new();
-        for existing in on_cuboids {
-            sliced_cuboids.extend(existing.subtract(&step.cuboid));
-        }
+        // every existing cuboid is sliced on its own, so with the parallel feature they are done at the same time
+        #[cfg(not(feature = "parallel"))]
+        let sliced_cuboids = on_cuboids.iter().flat_map(|existing| existing.subtract(&step.cuboid)).collect();
+        #[cfg(feature = "parallel")]
+        let sliced_cuboids = on_cuboids.par_iter().flat_map_iter(|existing| existing.subtract(&step.cuboid)).collect();
         on_cuboids = sliced_cuboids;
         if step.on {
             on_cuboids.push(step.cuboid.clone());
@@ -182,40 +147,66 @@ pub fn all_cubes_on(steps: &Vec<Step>) -> usize {
         .sum()
 }
 
-fn parse_input(input: &str) -> Vec<Step> {
-    input.lines().map(|line| parse_step(line)).collect()
+pub fn parse_input(input: &str) -> Result<Vec<Step>> {
+    error::lines(input).map(|line| parse_step(&line)).collect()
 }
 
-fn parse_step(line: &str) -> Step {
-    let step: Vec<&str> = line.trim().split(" ").collect();
-    let on = match step[0] {
+// "on x=-20..26,y=-36..17,z=-47..7"
+fn parse_step(line: &Line) -> Result<Step> {
+    let invalid = || line.error("day22", "expected a step like \"on x=10..12,y=10..12,z=10..12\"");
+    let (on, cuboid) = line.text.split_once(" ").ok_or_else(invalid)?;
+    let on = match on {
         "on" => true,
         "off" => false,
-        _ => panic!("Invalid step command")
+        _ => return Err(line.error_in("day22", on, "expected \"on\" or \"off\""))
     };
-    let coords: Vec<Vec<i32>> = step[1].split(",")
-        .map(|coord| coord.split("=").last().unwrap())
-        .map(|range| range.split("..").map(|val| val.parse().unwrap()).collect())
-        .collect();
-
-    Step {
-        on,
-        cuboid: Cuboid {
-            x_min: coords[0][0],
-            x_max: coords[0][1],
-            y_min: coords[1][0],
-            y_max: coords[1][1],
-            z_min: coords[2][0],
-            z_max: coords[2][1]
-        }
+    if cuboid.split(",").count() != 3 {
+        return Err(invalid());
     }
+    let coords: Vec<(i32, i32)> = cuboid.split(",").zip(["x=", "y=", "z="])
+        .map(|(coord, axis)| {
+            let range = || {
+                let (min, max) = coord.strip_prefix(axis)?.split_once("..")?;
+                Some((min.parse().ok()?, max.parse().ok()?))
+            };
+            range().ok_or_else(|| line.error_in("day22", coord, format!("expected a range like \"{}10..12\"", axis)))
+        })
+        .collect::<Result<_>>()?;
+
+    let min = Point3::new(coords[0].0, coords[1].0, coords[2].0);
+    let max = Point3::new(coords[0].1, coords[1].1, coords[2].1);
+    let cuboid = Cuboid::new(min, max)
+        .ok_or_else(|| line.error_in("day22", cuboid, "a range is backwards, the smaller number goes first"))?;
+    Ok(Step { on, cuboid })
 }
 
-pub fn read_steps() -> Vec<Step> {
-    let input = fs::read_to_string("src/day22/steps.txt").expect("missing steps.txt");
+pub fn read_steps(path: &str) -> Result<Vec<Step>> {
+    let input = error::read_input(path)?;
     parse_input(&input)
 }
 
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = Vec<Step>;
+    type Output = Answer;
+
+    const TITLE: &'static str = "Reactor Reboot";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day22/steps.txt";
+
+    fn parse(input: &str) -> Result<Vec<Step>> {
+        parse_input(input)
+    }
+
+    fn solve_part1(steps: &Vec<Step>) -> Answer {
+        cubes_on_50(steps).into()
+    }
+
+    fn solve_part2(steps: &Vec<Step>) -> Answer {
+        all_cubes_on(steps).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,7 +232,7 @@ mod tests {
             on x=-49..-5,y=-3..45,z=-29..18
             off x=18..30,y=-20..-8,z=-3..13
             on x=-41..9,y=-7..43,z=-33..15";
-        parse_input(input)
+        parse_input(input).unwrap()
     }
 
     #[test]
@@ -252,11 +243,35 @@ mod tests {
 
     #[test]
     fn test_intersects() {
-        let c1 = Cuboid::new(0, 10, 0, 10, 0, 10).unwrap();
-        let c2 = Cuboid::new(5, 20, -5, 5, 5, 10).unwrap();
-        assert_eq!(true, c1.intersects(&c2));
-        let c3 = Cuboid::new(5, 20, -5, 5, 20, 50).unwrap();
-        assert_eq!(false, c1.intersects(&c3));
+        let c1 = Cuboid::new(Point3::new(0, 0, 0), Point3::new(10, 10, 10)).unwrap();
+        let c2 = Cuboid::new(Point3::new(5, -5, 5), Point3::new(20, 5, 10)).unwrap();
+        assert!(c1.intersects(&c2));
+        let c3 = Cuboid::new(Point3::new(5, -5, 20), Point3::new(20, 5, 50)).unwrap();
+        assert!(!c1.intersects(&c3));
+        assert!(parse_input("on x=10..5,y=0..1,z=0..1").is_err());
+    }
+
+    #[test]
+    fn test_steps_json() {
+        let steps = parse_input("off x=-48..-32,y=26..41,z=-47..-37").unwrap();
+        let json = serde_json::to_string(&steps).unwrap();
+        assert_eq!("[{\"on\":false,\"cuboid\":{\"min\":{\"x\":-48,\"y\":26,\"z\":-47},\"max\":{\"x\":-32,\"y\":41,\"z\":-37}}}]", json);
+
+        // the steps read back from json turn on the same cubes
+        let json = serde_json::to_string(&get_test_data()).unwrap();
+        let steps: Vec<Step> = serde_json::from_str(&json).unwrap();
+        assert_eq!(590784, all_cubes_on(&steps));
+    }
+
+    #[test]
+    fn test_whole_space() {
+        let everything = format!("on x={min}..{max},y={min}..{max},z={min}..{max}", min = i32::MIN, max = i32::MAX);
+        assert_eq!(1 << 96, all_cubes_on(&parse_input(&everything).unwrap()));
+
+        // turning off the corners slices right up against the edges
+        let corners = format!("{}\noff x={min}..{min},y={min}..{min},z={min}..{min}\noff x={max}..{max},y={max}..{max},z={max}..{max}",
+            everything, min = i32::MIN, max = i32::MAX);
+        assert_eq!((1 << 96) - 2, all_cubes_on(&parse_input(&corners).unwrap()));
     }
 
     #[test]
@@ -327,7 +342,7 @@ mod tests {
             off x=-70369..-16548,y=22648..78696,z=-1892..86821
             on x=-53470..21291,y=-120233..-33476,z=-44150..38147
             off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
-        let test_data = parse_input(input);
+        let test_data = parse_input(input).unwrap();
         assert_eq!(2758514936282235, all_cubes_on(&test_data));
     }
 }
@@ -15,14 +15,25 @@ Moving costs are different for each amphipod, A=1, B=10, C=100, D=1000
 Part 1: given a starting puzzle with 2 spaces in each room, what is the lowest energy cost solution?
 
 Part 2: given a puzzle with 4 spaces in each room, what is the lowest energy cost solution?
+
+Every move is made on a copy of the burrow. With the arena feature, copies the search is done with
+go back on a free list and get reused, so a move doesn't allocate new Vecs for the hallway and rooms.
 */
 
-use std::collections::HashSet;
+use std::sync::atomic::{AtomicI32, Ordering};
+#[cfg(feature = "arena")]
+use std::cell::RefCell;
 use std::fmt;
 use std::cmp;
+use crate::progress::Progress;
+use crate::error::{self, AdventError, Result};
+use crate::solution::Solver;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
 // Each amphipod type represented as an enum
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Amphipod {
     A, B, C, D
 }
@@ -58,12 +69,38 @@ impl fmt::Debug for Amphipod {
 const ENTRY_SPACES: [usize; 4] = [2,4,6,8];
 
 // The Burrow struct represents the state of the puzzle
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Burrow {
     hallway: Vec<Option<Amphipod>>,
     rooms: Vec<Vec<Option<Amphipod>>>
 }
 
+// Spare burrows to copy into, one list per thread so the parallel search doesn't share it
+#[cfg(feature = "arena")]
+thread_local! {
+    static SPARE_BURROWS: RefCell<Vec<Burrow>> = const { RefCell::new(Vec::new()) };
+}
+
+impl Burrow {
+    // A copy of the burrow to make a move in
+    fn copy(&self) -> Burrow {
+        #[cfg(feature = "arena")]
+        if let Some(mut spare) = SPARE_BURROWS.with_borrow_mut(Vec::pop) {
+            // clone_from copies into the Vecs the spare already has
+            spare.hallway.clone_from(&self.hallway);
+            spare.rooms.clone_from(&self.rooms);
+            return spare;
+        }
+        self.clone()
+    }
+
+    // Done with a copy, so the next one can reuse it
+    fn recycle(self) {
+        #[cfg(feature = "arena")]
+        SPARE_BURROWS.with_borrow_mut(|spare| spare.push(self));
+    }
+}
+
 impl Burrow {
 
     // Returns the index of the hallway space for the destination room of the given aphipod
@@ -94,7 +131,7 @@ impl Burrow {
             hallway: vec![None; 11],
             rooms: initial.into_iter()
                 .map(|room| room.into_iter()
-                    .map(|a| Some(a))
+                    .map(Some)
                     .collect()
                 )
                 .collect()
@@ -118,13 +155,13 @@ impl Burrow {
         if !self.rooms[3].iter().all(|space| space == &Some(Amphipod::D)) {
             return false;
         }
-        return true;
+        true
     }
 
     // Return the destination room of the given amphipod
     // the room is a vector with each value representing what occupies each space in the room:
     // None for empty, Some(_) for the Amphipod in that space
-    fn get_room(&self, amphipod: &Amphipod) -> &Vec<Option<Amphipod>> {
+    fn get_room(&self, amphipod: &Amphipod) -> &[Option<Amphipod>] {
         match amphipod {
             Amphipod::A => &self.rooms[0],
             Amphipod::B => &self.rooms[1],
@@ -147,12 +184,9 @@ impl Burrow {
         // If a D is in the hallway blocking the other three rooms
         // and a non-D is in the D room, and there is no space to the right
         // then we are stuck and cannot solve
-        if self.hallway[9].is_some()
-                && self.hallway[7] == Some(Amphipod::D)
-                && self.rooms[3].iter().any(|space| space.is_some() && space != &Some(Amphipod::D)) {
-        }
+        if self.hallway[9].is_some() && self.hallway[7] == Some(Amphipod::D) { self.rooms[3].iter().any(|space| space.is_some() && space != &Some(Amphipod::D)); }
 
-        return false;
+        false
     }
 
     // If all amphipods could immidiately move to the correct room,
@@ -207,7 +241,7 @@ impl Burrow {
             }
         }
 
-        return cost;
+        cost
     }
     
 }
@@ -215,7 +249,7 @@ impl Burrow {
 // Some helpers to print out the burrow into a human readable format
 impl fmt::Debug for Burrow {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let h = self.hallway.iter().map(|space| format_space(space)).collect::<Vec<_>>().join("");
+        let h = self.hallway.iter().map(format_space).collect::<Vec<_>>().join("");
         let mut lines: Vec<String> = Vec::new();
         for i in 0..self.rooms[0].len() {
             lines.push(format!("  #{:?}#{:?}#{:?}#{:?}#", 
@@ -240,21 +274,25 @@ fn format_space(space: &Option<Amphipod>) -> String {
 // Parts 1 and 2
 // find the lowest energy solution. Takes around 25 seconds for each puzzle.
 // Use a DFS with pruning to evaluate all possible legal moves
+// With the parallel feature, the moves into the hallway are searched at the same time
 pub fn lowest_energy_solution(burrow: &Burrow) -> i32 {
-    let mut costs: HashSet<i32> = HashSet::new();
-    next_move(burrow, 0, &mut costs);
-    costs.into_iter().min().unwrap()
+    // the lowest energy of any solution found so far, shared by the whole search
+    let best = AtomicI32::new(i32::MAX);
+    let progress = Progress::spinner("states explored");
+    next_move(burrow, 0, &best, &progress);
+    let best = best.into_inner();
+    assert!(best < i32::MAX, "the burrow can't be solved");
+    best
 }
 
 // Main recursive driver function
 // evaluates all moves from the given burrow state, but recursively depth first
-fn next_move(burrow: &Burrow, energy: i32, completed_cost: &mut HashSet<i32>) {
-    if let Some(min) = completed_cost.iter().min() {
-        // naively estimate how much energy it would take to solve from the current state
-        // if we already have a solution with less energy, we can stop this DFS path now
-        if *min <= energy + burrow.naive_solve_energy() {
-            return;
-        }
+fn next_move(burrow: &Burrow, energy: i32, best: &AtomicI32, progress: &Progress) {
+    progress.inc();
+    // naively estimate how much energy it would take to solve from the current state
+    // if we already have a solution with less energy, we can stop this DFS path now
+    if best.load(Ordering::Relaxed) <= energy + burrow.naive_solve_energy() {
+        return;
     }
 
     // Check for some known unsolvable states
@@ -285,7 +323,7 @@ fn next_move(burrow: &Burrow, energy: i32, completed_cost: &mut HashSet<i32>) {
                 end = i-1;
             }
             for space in start..=end {
-                if let Some(_) = burrow.hallway[space] {
+                if burrow.hallway[space].is_some() {
                     clear_path = false;
                     break;
                 }
@@ -305,16 +343,18 @@ fn next_move(burrow: &Burrow, energy: i32, completed_cost: &mut HashSet<i32>) {
 
             // calculate movement cost
             let move_cost = ((i as i32 - entryway as i32).abs() + farthest_open as i32 + 1) * amphipod.energy();
-            // Clone the burrow and make the moves
-            let mut next_burrow = burrow.clone();
+            // Copy the burrow and make the moves
+            let mut next_burrow = burrow.copy();
             next_burrow.hallway[i] = None;
             next_burrow.rooms[Burrow::room_index(amphipod)][farthest_open] = Some(amphipod.clone());
 
             if next_burrow.is_complete() {
-                completed_cost.insert(energy + move_cost);
-                return;
+                log::debug!("found a solution using {} energy", energy + move_cost);
+                best.fetch_min(energy + move_cost, Ordering::Relaxed);
+            } else {
+                next_move(&next_burrow, energy + move_cost, best, progress);
             }
-            next_move(&next_burrow, energy + move_cost, completed_cost);
+            next_burrow.recycle();
             return;
         }
     }
@@ -333,7 +373,7 @@ fn next_move(burrow: &Burrow, energy: i32, completed_cost: &mut HashSet<i32>) {
                 // If the amphipod is blocked from exiting the room, skip it
                 let mut clear_exit = true;
                 for i in 0..space {
-                    if let Some(_) = &room[i] {
+                    if room[i].is_some() {
                         clear_exit = false;
                         break;
                     }
@@ -368,74 +408,132 @@ fn next_move(burrow: &Burrow, energy: i32, completed_cost: &mut HashSet<i32>) {
                         // cost
                         let move_cost = (space as i32 + 1 + (entryway as i32 - destination_entry as i32).abs() + farthest_open as i32 + 1) * amphipod.energy();
 
-                        // Clone the burrow and make the moves
-                        let mut next_burrow = burrow.clone();
+                        // Copy the burrow and make the moves
+                        let mut next_burrow = burrow.copy();
                         next_burrow.rooms[Burrow::room_index(&amphipod_type)][space] = None;
                         next_burrow.rooms[Burrow::room_index(amphipod)][farthest_open] = Some(amphipod.clone());
                         if next_burrow.is_complete() {
-                            completed_cost.insert(energy + move_cost);
-                            return;
+                            log::debug!("found a solution using {} energy", energy + move_cost);
+                            best.fetch_min(energy + move_cost, Ordering::Relaxed);
+                        } else {
+                            next_move(&next_burrow, energy + move_cost, best, progress);
                         }
-                        next_move(&next_burrow, energy + move_cost, completed_cost);
+                        next_burrow.recycle();
                         return;
                     }
                 }
 
                 // Now evaluate all possible moves into the hallway
-                // Go left until we are blocked. Recurse for each valid movement
-                for i in (0..entryway).rev() {
-                    if let Some(_) = burrow.hallway[i] {
-                        break;
-                    }
+                // Go left until we are blocked, then right until we are blocked.
+                // Recurse for each valid movement
+                let left = (0..entryway).rev().take_while(|&i| burrow.hallway[i].is_none());
+                let right = (entryway+1..burrow.hallway.len()).take_while(|&i| burrow.hallway[i].is_none());
+                let moves: Vec<(Burrow, i32)> = left.chain(right)
                     // cannot land on an entry space
-                    if ENTRY_SPACES.contains(&i) {
-                        continue;
-                    }
-
-                    let cost = (entryway - i + space + 1) as i32 * amphipod.energy();
-                    let mut next_burrow = burrow.clone();
-                    next_burrow.rooms[Burrow::room_index(&amphipod_type)][space] = None;
-                    next_burrow.hallway[i] = Some(amphipod.clone());
-                    next_move(&next_burrow, energy + cost, completed_cost);
-                }
+                    .filter(|i| !ENTRY_SPACES.contains(i))
+                    .map(|i| {
+                        let cost = (entryway.abs_diff(i) + space + 1) as i32 * amphipod.energy();
+                        let mut next_burrow = burrow.copy();
+                        next_burrow.rooms[Burrow::room_index(&amphipod_type)][space] = None;
+                        next_burrow.hallway[i] = Some(amphipod.clone());
+                        (next_burrow, energy + cost)
+                    })
+                    .collect();
+                #[cfg(not(feature = "parallel"))]
+                moves.iter().for_each(|(next_burrow, energy)| next_move(next_burrow, *energy, best, progress));
+                #[cfg(feature = "parallel")]
+                moves.par_iter().for_each(|(next_burrow, energy)| next_move(next_burrow, *energy, best, progress));
+                moves.into_iter().for_each(|(next_burrow, _)| next_burrow.recycle());
+            }
+        }
+    }
+}
 
-                // Go right until we are blocked. Recurse for each valid movement
-                for i in entryway+1..burrow.hallway.len() {
-                    if let Some(_) = burrow.hallway[i] {
-                        break;
-                    }
-                    // cannot land on an entry space
-                    if ENTRY_SPACES.contains(&i) {
-                        continue;
-                    }
+// Part 2 unfolds the diagram, inserting these two rows between the first and second rows of each room
+const UNFOLDED_ROWS: [[Amphipod; 4]; 2] = [
+    [Amphipod::D, Amphipod::C, Amphipod::B, Amphipod::A],
+    [Amphipod::D, Amphipod::B, Amphipod::A, Amphipod::C]
+];
+
+// Read the burrow diagram. Each line with amphipods in it is one row across all 4 rooms.
+// Returns the rows from top to bottom, see rooms_from_rows to turn them into the Burrow::new format
+pub fn read_burrow(path: &str) -> Result<Vec<Vec<Amphipod>>> {
+    let input = error::read_input(path)?;
+    parse_burrow_rows(&input)
+}
 
-                    let cost = (i - entryway + space + 1) as i32 * amphipod.energy();
-                    let mut next_burrow = burrow.clone();
-                    next_burrow.rooms[Burrow::room_index(&amphipod_type)][space] = None;
-                    next_burrow.hallway[i] = Some(amphipod.clone());
-                    next_move(&next_burrow, energy + cost, completed_cost);
-                }
+// The folded diagram has 2 rows of 4 amphipods, with 2 of each kind
+pub fn parse_burrow_rows(input: &str) -> Result<Vec<Vec<Amphipod>>> {
+    let mut rows: Vec<Vec<Amphipod>> = Vec::new();
+    for line in error::lines(input) {
+        let mut row = Vec::new();
+        for (i, c) in line.text.char_indices() {
+            match c {
+                'A' => row.push(Amphipod::A),
+                'B' => row.push(Amphipod::B),
+                'C' => row.push(Amphipod::C),
+                'D' => row.push(Amphipod::D),
+                '#' | '.' | ' ' => (),
+                _ => return Err(line.error_in("day23", &line.text[i..i + c.len_utf8()], format!("unexpected '{}'", c)))
             }
         }
+        if row.is_empty() {
+            continue;
+        }
+        if row.len() != 4 || rows.len() == 2 {
+            return Err(line.error("day23", "the diagram should have 2 rows of 4 amphipods"));
+        }
+        rows.push(row);
+    }
+    if rows.len() != 2 {
+        return Err(AdventError::parse("day23", "the diagram should have 2 rows of 4 amphipods"));
     }
+    for kind in [Amphipod::A, Amphipod::B, Amphipod::C, Amphipod::D] {
+        if rows.iter().flatten().filter(|&amphipod| *amphipod == kind).count() != 2 {
+            return Err(AdventError::parse("day23", "there should be 2 of each amphipod: A, B, C and D"));
+        }
+    }
+    Ok(rows)
+}
+
+// Swap rows of the diagram to the room by room layout expected by Burrow::new
+fn rooms_from_rows(rows: &[Vec<Amphipod>]) -> Vec<Vec<Amphipod>> {
+    (0..4).map(|room| rows.iter().map(|row| row[room].clone()).collect())
+        .collect()
 }
 
-pub fn part_1_start() -> Burrow {
-    let init = vec![vec![Amphipod::B, Amphipod::B],
-        vec![Amphipod::A, Amphipod::C],
-        vec![Amphipod::A, Amphipod::D],
-        vec![Amphipod::D, Amphipod::C]];
+pub fn part_1_start(rows: &[Vec<Amphipod>]) -> Burrow {
+    Burrow::new(rooms_from_rows(rows))
+}
 
-    Burrow::new(init)
+pub fn part_2_start(rows: &[Vec<Amphipod>]) -> Burrow {
+    let mut unfolded = rows.to_vec();
+    for (i, row) in UNFOLDED_ROWS.iter().enumerate() {
+        unfolded.insert(1 + i, row.to_vec());
+    }
+    Burrow::new(rooms_from_rows(&unfolded))
 }
 
-pub fn part_2_start() -> Burrow {
-    let init = vec![vec![Amphipod::B, Amphipod::D, Amphipod::D, Amphipod::B],
-        vec![Amphipod::A, Amphipod::C, Amphipod::B, Amphipod::C],
-        vec![Amphipod::A, Amphipod::B, Amphipod::A, Amphipod::D],
-        vec![Amphipod::D, Amphipod::A, Amphipod::C, Amphipod::C]];
+pub struct Day23;
 
-    Burrow::new(init)
+impl Solver for Day23 {
+    type Input = Vec<Vec<Amphipod>>;
+    type Output = i32;
+
+    const TITLE: &'static str = "Amphipod";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day23/burrow.txt";
+
+    fn parse(input: &str) -> Result<Vec<Vec<Amphipod>>> {
+        parse_burrow_rows(input)
+    }
+
+    fn solve_part1(rows: &Vec<Vec<Amphipod>>) -> i32 {
+        lowest_energy_solution(&part_1_start(rows))
+    }
+
+    fn solve_part2(rows: &Vec<Vec<Amphipod>>) -> i32 {
+        lowest_energy_solution(&part_2_start(rows))
+    }
 }
 
 #[cfg(test)]
@@ -463,5 +561,21 @@ mod tests {
         let burrow = Burrow::new(init);
         assert_eq!(44169, lowest_energy_solution(&burrow));
     }
+
+    #[test]
+    fn test_unfold_diagram() {
+        let diagram = "#############
+            #...........#
+            ###B#C#B#D###
+              #A#D#C#A#
+              #########";
+        let rows = parse_burrow_rows(diagram).unwrap();
+        let burrow = part_2_start(&rows);
+        let expected = vec![vec![Amphipod::B, Amphipod::D, Amphipod::D, Amphipod::A],
+            vec![Amphipod::C, Amphipod::C, Amphipod::B, Amphipod::D],
+            vec![Amphipod::B, Amphipod::B, Amphipod::A, Amphipod::C],
+            vec![Amphipod::D, Amphipod::A, Amphipod::C, Amphipod::A]];
+        assert_eq!(Burrow::new(expected).rooms, burrow.rooms);
+    }
 }
 
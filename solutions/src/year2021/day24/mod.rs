@@ -12,7 +12,8 @@ Part 1: what is the largest valid model number?
 Part 2: what is the smallest valid model number?
 */
 
-use std::fs;
+use crate::error::{self, Result};
+use crate::solution::Solver;
 
 // Track each instruction with the command (add), the target register, and the optional value
 pub struct Instruction {
@@ -22,7 +23,7 @@ pub struct Instruction {
 }
 
 // ALU struct mutates with each instruction executed
-struct ALU {
+struct Alu {
     w: i64,
     x: i64,
     y: i64,
@@ -30,10 +31,10 @@ struct ALU {
     input: Box<dyn Iterator<Item=i64>>
 }
 
-impl ALU {
+impl Alu {
     // define the ALU by the input - represented by an iterator
-    fn new(input: Box<dyn Iterator<Item=i64>>) -> ALU {
-        ALU {
+    fn new(input: Box<dyn Iterator<Item=i64>>) -> Alu {
+        Alu {
             w: 0, x: 0, y: 0, z: 0, input
         }
     }
@@ -42,11 +43,11 @@ impl ALU {
         let target = self.dimension(&instruction.target);
         let result = match &instruction.command[..] {
             "inp" => self.input.next().unwrap(),
-            "add" => target + self.dimension(&instruction.operand.as_ref().unwrap()),
-            "mul" => target * self.dimension(&instruction.operand.as_ref().unwrap()),
-            "div" => target / self.dimension(&instruction.operand.as_ref().unwrap()),
-            "mod" => target % self.dimension(&instruction.operand.as_ref().unwrap()),
-            "eql" => if target == self.dimension(&instruction.operand.as_ref().unwrap()) { 1 } else { 0 },
+            "add" => target + self.dimension(instruction.operand.as_ref().unwrap()),
+            "mul" => target * self.dimension(instruction.operand.as_ref().unwrap()),
+            "div" => target / self.dimension(instruction.operand.as_ref().unwrap()),
+            "mod" => target % self.dimension(instruction.operand.as_ref().unwrap()),
+            "eql" => if target == self.dimension(instruction.operand.as_ref().unwrap()) { 1 } else { 0 },
             _ => panic!("Invalid command: {}", instruction.command) 
         };
         match &instruction.target[..] {
@@ -130,37 +131,83 @@ Final rules are as follows:
     i13 must be i2 + 7
     i14 must be 1
 */
-pub fn validate_modal_number(modal_number: &str, instructions: &Vec<Instruction>) -> bool {
+pub fn validate_modal_number(modal_number: &str, instructions: &[Instruction]) -> bool {
     let input: Vec<i64> = modal_number.chars().map(|c| c.to_digit(10).unwrap() as i64).collect();
-    let mut alu = ALU::new(Box::new(input.into_iter()));
-    execute_instructions(&mut alu, &instructions);
-    return alu.z == 0;
+    let mut alu = Alu::new(Box::new(input.into_iter()));
+    execute_instructions(&mut alu, instructions);
+    alu.z == 0
 }
 
-fn execute_instructions(alu: &mut ALU, instructions: &Vec<Instruction>) {
+fn execute_instructions(alu: &mut Alu, instructions: &[Instruction]) {
     for instruction in instructions {
         alu.execute(instruction);
     }
 }
 
-fn parse_instructions(input: &str) -> Vec<Instruction> {
-    input.lines()
+// "inp w" or "add x 12", the operand is another register or a number
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>> {
+    error::lines(input)
         .map(|line| {
-            let parts:Vec<_> = line.trim().split(" ").collect();
-            Instruction{
+            let parts:Vec<_> = line.text.split(" ").collect();
+            let register = |value: &str| ["w", "x", "y", "z"].contains(&value);
+            if !matches!(parts[..], ["inp", _] | ["add" | "mul" | "div" | "mod" | "eql", _, _]) {
+                return Err(line.error("day24", "expected an ALU instruction like \"add x 12\""));
+            }
+            if !register(parts[1]) {
+                return Err(line.error_in("day24", parts[1], "expected a register: w, x, y or z"));
+            }
+            if let Some(&operand) = parts.get(2).filter(|&&operand| !register(operand) && operand.parse::<i64>().is_err()) {
+                return Err(line.error_in("day24", operand, "expected a register or a number"));
+            }
+            Ok(Instruction{
                 command: parts[0].to_string(),
                 target: parts[1].to_string(),
                 operand: parts.get(2).map(|val| val.to_string())
-            }
+            })
         })
         .collect()
 }
 
-pub fn read_instructions() -> Vec<Instruction> {
-    let input = fs::read_to_string("src/day24/instructions.txt").expect("missing instructions.txt");
+pub fn read_instructions(path: &str) -> Result<Vec<Instruction>> {
+    let input = error::read_input(path)?;
     parse_instructions(&input)
 }
 
+// The model numbers were solved by hand (see validate_modal_number)
+// so the "solution" is to double check them against the ALU
+const LARGEST_MODEL_NUMBER: &str = "92928914999991";
+const SMALLEST_MODEL_NUMBER: &str = "91811211611981";
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = Vec<Instruction>;
+    type Output = String;
+
+    const TITLE: &'static str = "Arithmetic Logic Unit";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day24/instructions.txt";
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        parse_instructions(input)
+    }
+
+    fn solve_part1(instructions: &Vec<Instruction>) -> String {
+        checked_model_number(LARGEST_MODEL_NUMBER, instructions)
+    }
+
+    fn solve_part2(instructions: &Vec<Instruction>) -> String {
+        checked_model_number(SMALLEST_MODEL_NUMBER, instructions)
+    }
+}
+
+pub fn checked_model_number(modal_number: &str, instructions: &[Instruction]) -> String {
+    if validate_modal_number(modal_number, instructions) {
+        modal_number.to_string()
+    } else {
+        format!("{} is not valid", modal_number)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,13 +218,13 @@ mod tests {
             inp x
             mul z 3
             eql z x";
-        let instructions = parse_instructions(input);
+        let instructions = parse_instructions(input).unwrap();
 
-        let mut alu = ALU::new(Box::new(vec![22,66].into_iter()));
+        let mut alu = Alu::new(Box::new(vec![22,66].into_iter()));
         execute_instructions(&mut alu, &instructions);
         assert_eq!(1, alu.z);
 
-        let mut alu = ALU::new(Box::new(vec![22,51].into_iter()));
+        let mut alu = Alu::new(Box::new(vec![22,51].into_iter()));
         execute_instructions(&mut alu, &instructions);
         assert_eq!(0, alu.z);
     }
@@ -195,9 +242,9 @@ mod tests {
             mod x 2
             div w 2
             mod w 2";
-        let instructions = parse_instructions(input);
+        let instructions = parse_instructions(input).unwrap();
 
-        let mut alu = ALU::new(Box::new(vec![5].into_iter()));
+        let mut alu = Alu::new(Box::new(vec![5].into_iter()));
         execute_instructions(&mut alu, &instructions);
         assert_eq!(1, alu.z);
         assert_eq!(0, alu.y);
@@ -0,0 +1,205 @@
+/*
+Day 25: Sea Cucumber
+
+Sea cucumbers are either left ">" or down "v".
+For each step, a left sea cucumber moves left if the space left of it is empty.
+A down sea cucumber moves down if the space below it is empty.
+Both left and down wrap around to 0 when the reach the left/bottom edge.
+
+All left cucumbers evaluate at the same time, then move.
+Then all down cucumbers evaluate at the same time, then move.
+
+Part 1: What is the number of the first step when no sea cucumbers move?
+
+With the simd feature the solver moves the sea cucumbers 64 at a time, packed into bits (see packed.rs).
+ */
+
+pub mod packed;
+
+use crate::algorithms::{Cycle, CycleFinder};
+use crate::animate::Animation;
+use crate::grid::Grid;
+use crate::error::{self, Result};
+use crate::solution::Solver;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Location {
+    Left, Down, Empty
+}
+
+impl Location {
+    fn from_char(c: &char) -> Option<Location> {
+        match c {
+            'v' => Some(Location::Down),
+            '>' => Some(Location::Left),
+            '.' => Some(Location::Empty),
+            _ => None
+        }
+    } 
+}
+
+// Part 1: loop until there is no movement
+// The puzzle's sea cucumbers always stop, a grid where they never do gets reported instead of looping forever
+pub fn find_stable_step(grid: &Grid<Location>) -> usize {
+    let stable = if cfg!(feature = "simd") { packed::stable_step(grid) } else { stable_step(grid) };
+    stable.unwrap_or_else(|cycle| {
+        panic!("the sea cucumbers never stop, from step {} the grid repeats every {} steps", cycle.start, cycle.length)
+    })
+}
+
+// Err if the grid comes back to a layout it had before while they're still moving.
+// The cycle finder keeps one grid to compare against, so this costs a comparison each step
+fn stable_step(grid: &Grid<Location>) -> std::result::Result<usize, Cycle> {
+    let mut grid = grid.clone();
+    let mut animation = Animation::start();
+    let mut cycles = CycleFinder::new();
+    let mut step = 1;
+    loop {
+        let moved = do_step(&mut grid);
+        log::debug!("step {}: {} sea cucumbers moved", step, moved);
+        animation.frame(|| draw_grid(&grid, step, moved));
+        if moved == 0 {
+            return Ok(step);
+        }
+        if let Some(length) = cycles.check(&grid) {
+            // the finder notices a repeat somewhere in the first time round the cycle, not right at its start
+            return Err(Cycle { start: step - length, length });
+        }
+        step += 1;
+    }
+}
+
+// Evaluates the grid at the end of the step.
+// This mutates the grid in place
+// Returns the number of sea cucumbers that moved
+fn do_step(grid: &mut Grid<Location>) -> usize {
+    
+    // First evaluate the left, find all the left facing cucumbers that will move this step
+    let left_changes: Vec<(usize, usize)> = grid.positions()
+        .filter(|&(row, col)| grid[(row, col)] == Location::Left && grid[(row, next_left(col, grid))] == Location::Empty)
+        .collect();
+    // move all the left facing cucumbers that are eligible
+    for &(r,c) in left_changes.iter() {
+        let left = next_left(c, grid);
+        grid[(r, c)] = Location::Empty;
+        grid[(r, left)] = Location::Left;
+    }
+
+    // Now evaluate the down sea cucumbers
+    let down_changes: Vec<(usize, usize)> = grid.positions()
+        .filter(|&(row, col)| grid[(row, col)] == Location::Down && grid[(next_down(row, grid), col)] == Location::Empty)
+        .collect();
+    // move down sea cucumbers that are eligible
+    for &(r,c) in down_changes.iter() {
+        let down = next_down(r, grid);
+        grid[(r, c)] = Location::Empty;
+        grid[(down, c)] = Location::Down;
+    }
+    left_changes.len() + down_changes.len()
+}
+
+fn draw_grid(grid: &Grid<Location>, step: usize, moved: usize) -> String {
+    let rows: Vec<String> = grid.rows()
+        .map(|row| row.iter().map(|location| match location {
+            Location::Left => '>',
+            Location::Down => 'v',
+            Location::Empty => '.'
+        }).collect())
+        .collect();
+    format!("step {}: {} moved\n\n{}", step, moved, rows.join("\n"))
+}
+
+fn next_left(col: usize, grid: &Grid<Location>) -> usize {
+    let next = col + 1;
+    if grid.width() <= next {
+        return 0;
+    }
+    next
+}
+
+fn next_down(row: usize, grid: &Grid<Location>) -> usize {
+    let next = row + 1;
+    if grid.height() <= next {
+        return 0;
+    }
+    next
+}
+
+
+pub fn parse_input(input: &str) -> Result<Grid<Location>> {
+    Grid::parse("day25", input, |c| Location::from_char(&c))
+}
+
+pub fn read_grid(path: &str) -> Result<Grid<Location>> {
+    let input = error::read_input(path)?;
+    parse_input(&input)
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Grid<Location>;
+    type Output = String;
+
+    const TITLE: &'static str = "Sea Cucumber";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day25/grid.txt";
+
+    fn parse(input: &str) -> Result<Grid<Location>> {
+        parse_input(input)
+    }
+
+    fn solve_part1(grid: &Grid<Location>) -> String {
+        find_stable_step(grid).to_string()
+    }
+
+    // There is no part 2 on the last day
+    fn solve_part2(_grid: &Grid<Location>) -> String {
+        "Merry Christmas!".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_location_step() {
+        let input = "...>...
+            .......
+            ......>
+            v.....>
+            ......>
+            .......
+            ..vvv..";
+        let mut grid = parse_input(input).unwrap();
+        let moves = do_step(&mut grid);
+        assert_eq!(5, moves);
+        assert_eq!(Location::Down, grid[(0, 2)]);
+        assert_eq!(Location::Down, grid[(0, 3)]);
+        assert_eq!(Location::Left, grid[(0, 4)]);
+    }
+
+    #[test]
+    fn test_find_stable_step() {
+        let input = "v...>>.vv>
+            .vv>>.vv..
+            >>.>v>...v
+            >>v>>.>.v.
+            v>v.vv.v..
+            >.>>..v...
+            .vv..>.>v.
+            v.v..>>v.v
+            ....v..v.>";
+        let grid = parse_input(input).unwrap();
+        assert_eq!(58, find_stable_step(&grid));
+    }
+
+    #[test]
+    fn test_never_stable() {
+        // a full row of one sea cucumber with one gap keeps going round
+        let grid = parse_input(">>>.").unwrap();
+        let cycle = stable_step(&grid).unwrap_err();
+        assert_eq!(4, cycle.length);
+        assert_eq!(2, stable_step(&parse_input(">.>.").unwrap()).unwrap_err().length);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,151 @@
+/*
+The sea cucumbers packed into bits, 64 columns to a u64, used by the solver with the simd feature.
+
+Each row is two bitsets, the cucumbers facing east and the ones facing south.
+A whole word of cucumbers moves at once: the east facing ones that can move are the ones where
+the next column is empty, which is the row's empty spaces shifted back by a column.
+The south facing ones compare against the row below in the same way, without any shifting.
+Bits past the width of the grid are always 0.
+*/
+
+use crate::algorithms::{Cycle, CycleFinder};
+use crate::grid::Grid;
+use super::Location;
+
+#[derive(Clone, PartialEq, Eq)]
+struct Herd {
+    width: usize,
+    east: Vec<Vec<u64>>,
+    south: Vec<Vec<u64>>
+}
+
+impl Herd {
+    fn new(grid: &Grid<Location>) -> Herd {
+        let words = grid.width().div_ceil(64);
+        let mut east = vec![vec![0; words]; grid.height()];
+        let mut south = vec![vec![0; words]; grid.height()];
+        for ((row, col), location) in grid.enumerate() {
+            match location {
+                Location::Left => east[row][col / 64] |= 1 << (col % 64),
+                Location::Down => south[row][col / 64] |= 1 << (col % 64),
+                Location::Empty => ()
+            }
+        }
+        Herd { width: grid.width(), east, south }
+    }
+
+    // Moves the east facing herd, then the south facing herd. Returns how many moved
+    fn step(&mut self) -> usize {
+        let mut moved = 0;
+        for (east, south) in self.east.iter_mut().zip(&self.south) {
+            let occupied: Vec<u64> = east.iter().zip(south).map(|(e, s)| e | s).collect();
+            let next_occupied = from_next(&occupied, self.width);
+            let movers: Vec<u64> = east.iter().zip(&next_occupied).map(|(e, o)| e & !o).collect();
+            let arrived = to_next(&movers, self.width);
+            for i in 0..east.len() {
+                east[i] = (east[i] & !movers[i]) | arrived[i];
+            }
+            moved += count(&movers);
+        }
+
+        // every row checks the row below before any of them move
+        let height = self.south.len();
+        let movers: Vec<Vec<u64>> = (0..height).map(|row| {
+            let below = (row + 1) % height;
+            (0..self.south[row].len())
+                .map(|i| self.south[row][i] & !(self.east[below][i] | self.south[below][i]))
+                .collect()
+        }).collect();
+        for (row, row_movers) in movers.iter().enumerate() {
+            let below = (row + 1) % height;
+            for (i, m) in row_movers.iter().enumerate() {
+                self.south[row][i] &= !m;
+                self.south[below][i] |= m;
+            }
+            moved += count(row_movers);
+        }
+        moved
+    }
+}
+
+// Bit c is bit c + 1 of bits, wrapping around at the width
+fn from_next(bits: &[u64], width: usize) -> Vec<u64> {
+    let mut shifted: Vec<u64> = (0..bits.len())
+        .map(|i| bits[i] >> 1 | bits.get(i + 1).map_or(0, |next| next << 63))
+        .collect();
+    let last = width - 1;
+    shifted[last / 64] |= (bits[0] & 1) << (last % 64);
+    shifted
+}
+
+// Bit c + 1 is bit c of bits, wrapping around at the width
+fn to_next(bits: &[u64], width: usize) -> Vec<u64> {
+    let last = width - 1;
+    let mut shifted: Vec<u64> = (0..bits.len())
+        .map(|i| bits[i] << 1 | if i > 0 { bits[i - 1] >> 63 } else { 0 })
+        .collect();
+    // the bit shifted past the width goes round to column 0
+    let overflow = (bits[last / 64] >> (last % 64)) & 1;
+    if !width.is_multiple_of(64) {
+        shifted[last / 64] &= !(1 << (width % 64));
+    }
+    shifted[0] |= overflow;
+    shifted
+}
+
+fn count(bits: &[u64]) -> usize {
+    bits.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+// The same as super::stable_step, without the animation
+pub fn stable_step(grid: &Grid<Location>) -> Result<usize, Cycle> {
+    let mut herd = Herd::new(grid);
+    let mut cycles = CycleFinder::new();
+    let mut step = 1;
+    loop {
+        let moved = herd.step();
+        log::debug!("step {}: {} sea cucumbers moved", step, moved);
+        if moved == 0 {
+            return Ok(step);
+        }
+        if let Some(length) = cycles.check(&herd) {
+            return Err(Cycle { start: step - length, length });
+        }
+        step += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{do_step, parse_input};
+
+    #[test]
+    fn test_shifts() {
+        // 70 columns, so the row is split over 2 words. Columns 0, 5 and 69 are set
+        let bits = [1 | 1 << 5, 1 << 5];
+        assert_eq!(vec![1 << 4, 1 << 4 | 1 << 5], from_next(&bits, 70));
+        assert_eq!(vec![1 | 1 << 1 | 1 << 6, 0], to_next(&bits, 70));
+    }
+
+    #[test]
+    fn test_same_as_grid() {
+        let input = "v...>>.vv>
+            .vv>>.vv..
+            >>.>v>...v
+            >>v>>.>.v.
+            v>v.vv.v..
+            >.>>..v...
+            .vv..>.>v.
+            v.v..>>v.v
+            ....v..v.>";
+        let mut grid = parse_input(input).unwrap();
+        let mut herd = Herd::new(&grid);
+        for _ in 0..10 {
+            assert_eq!(do_step(&mut grid), herd.step());
+            assert!(herd == Herd::new(&grid));
+        }
+        assert_eq!(Ok(58), stable_step(&parse_input(input).unwrap()));
+        assert_eq!(4, stable_step(&parse_input(">>>.").unwrap()).unwrap_err().length);
+    }
+}
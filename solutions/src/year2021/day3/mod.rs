@@ -0,0 +1,299 @@
+/*
+Day 3: Binary Diagnostic
+
+Input is a list of numbers in binary code
+
+Part 1: For each bit of all of the numbers, find the most common bit (1 or 0)
+For the value of gamma, set the value of that bit to the most common.
+For the value of epsilon, set the value for that bit to be the least common.
+Retun gamma times epsilon.
+
+Part2: For each bit starting on the left, find the most common, then keep only the
+binary numbers that have that most common value as the bit for that place in the number.
+Once the list of numbers is down to 1, that is the oxygen value. In case of a tie, use 1.
+Use the same process except finding the least common bit for the co2 value. In case of a tie, use 0.
+Return oxygen times co2.
+*/
+
+use std::io::BufRead;
+use serde::{Deserialize, Serialize};
+use crate::error::{self, AdventError, Line, Result};
+use crate::solution::Solver;
+
+// The numbers behind the two answers, power is gamma * epsilon and life support is oxygen * co2
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostics {
+    pub gamma: u64,
+    pub epsilon: u64,
+    pub oxygen: u64,
+    pub co2: u64
+}
+
+impl Diagnostics {
+    pub fn power(&self) -> u128 {
+        u128::from(self.gamma) * u128::from(self.epsilon)
+    }
+
+    pub fn life_support(&self) -> u128 {
+        u128::from(self.oxygen) * u128::from(self.co2)
+    }
+}
+
+pub fn diagnostics(diagnostic: &[String]) -> Diagnostics {
+    let (gamma, epsilon) = rates(diagnostic);
+    let (oxygen, co2) = ratings(diagnostic);
+    Diagnostics { gamma, epsilon, oxygen, co2 }
+}
+
+// Every number has the same number of digits, parsing makes sure of it
+fn width(diagnostic: &[String]) -> usize {
+    diagnostic.first().map_or(0, String::len)
+}
+
+fn digit(line: &str, place: usize) -> u8 {
+    line.as_bytes()[place]
+}
+
+// A column that's all 0s or all 1s has that digit as the most common,
+// and a tie goes to 1
+fn most_common_digit(diagnostic: &[String], place: usize) -> u8 {
+    let ones = diagnostic.iter().filter(|line| digit(line, place) == b'1').count();
+    if ones * 2 >= diagnostic.len() { b'1' } else { b'0' }
+}
+
+// (gamma, epsilon)
+pub fn rates(diagnostic: &[String]) -> (u64, u64) {
+    let mut ones = vec![0; width(diagnostic)];
+    for number in diagnostic {
+        count_ones(&mut ones, number);
+    }
+    rates_from_counts(&ones, diagnostic.len())
+}
+
+// The same as rates(), adding up the 1s in each column as the report is read,
+// so only the counts are kept and not the numbers
+pub fn read_rates(reader: &mut dyn BufRead, path: &str) -> Result<(u64, u64)> {
+    let mut width = None;
+    let mut ones = vec![];
+    let mut numbers = 0;
+    for number in error::stream_lines(reader, path, |line| parse_number(line, &mut width)) {
+        let number = number?;
+        ones.resize(number.len(), 0);
+        count_ones(&mut ones, &number);
+        numbers += 1;
+    }
+    match numbers {
+        0 => Err(AdventError::parse("day3", "the report has no numbers")),
+        _ => Ok(rates_from_counts(&ones, numbers))
+    }
+}
+
+fn count_ones(ones: &mut [usize], number: &str) {
+    for (place, digit) in number.bytes().enumerate() {
+        ones[place] += usize::from(digit == b'1');
+    }
+}
+
+// The most common digit in each column is 1 if at least half of the numbers have a 1 there
+fn rates_from_counts(ones: &[usize], numbers: usize) -> (u64, u64) {
+    let mut gamma = 0;
+    let mut epsilon = 0;
+    for &count in ones {
+        let most_common = count * 2 >= numbers;
+        gamma = gamma << 1 | u64::from(most_common);
+        epsilon = epsilon << 1 | u64::from(!most_common);
+    }
+    (gamma, epsilon)
+}
+
+pub fn power(diagnostic: &[String]) -> u128 {
+    let (gamma, epsilon) = rates(diagnostic);
+    u128::from(gamma) * u128::from(epsilon)
+}
+
+// Keep the numbers with the bit criteria's digit in each place, left to right, until there's one left.
+// If every number left has the same digit in a place, there's nothing to choose between so they all stay,
+// and if they're all the same number (the report can have duplicates) it's that one
+fn rating(diagnostic: &[String], criteria: impl Fn(&[String], usize) -> u8) -> u64 {
+    let mut numbers = diagnostic.to_vec();
+    for place in 0..width(diagnostic) {
+        if numbers.len() <= 1 {
+            break;
+        }
+        let keep = criteria(&numbers, place);
+        if numbers.iter().any(|line| digit(line, place) == keep) {
+            numbers.retain(|line| digit(line, place) == keep);
+        }
+    }
+    u64::from_str_radix(&numbers[0], 2).unwrap()
+}
+
+// (oxygen, co2)
+pub fn ratings(diagnostic: &[String]) -> (u64, u64) {
+    let oxygen = rating(diagnostic, most_common_digit);
+    let co2 = rating(diagnostic, |numbers, place| match most_common_digit(numbers, place) {
+        b'1' => b'0',
+        _ => b'1'
+    });
+    (oxygen, co2)
+}
+
+pub fn life_support(diagnostic: &[String]) -> u128 {
+    let (oxygen, co2) = ratings(diagnostic);
+    u128::from(co2) * u128::from(oxygen)
+}
+
+// Reads the file a line at a time
+pub fn read_diagnostic(path: &str) -> Result<Vec<String>> {
+    Day3::read(&mut error::open_input(path)?, path)
+}
+
+pub fn parse_diagnostic(input: &str) -> Result<Vec<String>> {
+    let mut width = None;
+    not_empty(error::lines(input).map(|line| parse_number(line, &mut width)).collect())
+}
+
+// The ratings need at least one number to end up with
+fn not_empty(diagnostic: Result<Vec<String>>) -> Result<Vec<String>> {
+    diagnostic.and_then(|numbers| match numbers.is_empty() {
+        true => Err(AdventError::parse("day3", "the report has no numbers")),
+        false => Ok(numbers)
+    })
+}
+
+// The numbers are kept in a u64, so their products fit in a u128
+const MAX_WIDTH: usize = 64;
+
+// Every line is a binary number, all the same length as the first one (width, once it's been seen)
+fn parse_number(line: Line, width: &mut Option<usize>) -> Result<String> {
+    if line.text.is_empty() {
+        return Err(line.error("day3", "expected a binary number"));
+    }
+    if let Some((i, c)) = line.text.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+        return Err(line.error_in("day3", &line.text[i..i + c.len_utf8()], "expected a binary digit"));
+    }
+    if line.text.len() > MAX_WIDTH {
+        return Err(line.error("day3", format!("expected at most {} digits, found {}", MAX_WIDTH, line.text.len())));
+    }
+    let width = *width.get_or_insert(line.text.len());
+    if line.text.len() != width {
+        return Err(line.error("day3", format!("expected {} digits like the first line, found {}", width, line.text.len())));
+    }
+    Ok(line.text.to_string())
+}
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Input = Vec<String>;
+    type Output = u128;
+
+    const TITLE: &'static str = "Binary Diagnostic";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day3/diag.txt";
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        parse_diagnostic(input)
+    }
+
+    fn read(reader: &mut dyn BufRead, path: &str) -> Result<Vec<String>> {
+        let mut width = None;
+        not_empty(error::stream_lines(reader, path, |line| parse_number(line, &mut width)).collect())
+    }
+
+    fn solve_part1(diagnostic: &Vec<String>) -> u128 {
+        power(diagnostic)
+    }
+
+    fn solve_part2(diagnostic: &Vec<String>) -> u128 {
+        life_support(diagnostic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> Vec<String> {
+        let test = "00100
+            11110
+            10110
+            10111
+            10101
+            01111
+            00111
+            11100
+            10000
+            11001
+            00010
+            01010";
+        test.lines().map(|line| line.trim().to_string()).collect()
+    }
+
+    #[test]
+    fn test_power() {
+        let diag = get_test_data();
+        assert_eq!(198, power(&diag));
+    }
+
+    #[test]
+    fn test_life_support() {
+        let diag = get_test_data();
+        assert_eq!(230, life_support(&diag));
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let diagnostics = diagnostics(&get_test_data());
+        assert_eq!(Diagnostics { gamma: 22, epsilon: 9, oxygen: 23, co2: 10 }, diagnostics);
+        assert_eq!((198, 230), (diagnostics.power(), diagnostics.life_support()));
+        assert_eq!(r#"{"gamma":22,"epsilon":9,"oxygen":23,"co2":10}"#, serde_json::to_string(&diagnostics).unwrap());
+    }
+
+    #[test]
+    fn test_read_rates() {
+        let report = get_test_data().join("\n");
+        assert_eq!((22, 9), read_rates(&mut report.as_bytes(), "report").unwrap());
+        assert_eq!((6, 1), read_rates(&mut "110\n100\n100\n110\n".as_bytes(), "report").unwrap());
+        assert!(read_rates(&mut "".as_bytes(), "report").is_err());
+        assert!(read_rates(&mut "110\n1000".as_bytes(), "report").is_err());
+    }
+
+    #[test]
+    fn test_same_digits() {
+        // the first column is all 1s and the last all 0s
+        let diag: Vec<String> = ["110", "100", "100", "110"].iter().map(|line| line.to_string()).collect();
+        assert_eq!(6, power(&diag));
+        // oxygen keeps 110s (a tie in the middle), co2 keeps 100s, and neither runs out of numbers
+        assert_eq!(6 * 4, life_support(&diag));
+        // epsilon is 0 with every column all 1s
+        assert_eq!(0, power(&["1111".to_string()]));
+        assert_eq!(15 * 15, life_support(&["1111".to_string()]));
+    }
+
+    #[test]
+    fn test_wide_report() {
+        // 40 digits, the first 20 columns are mostly 1s and the last 20 mostly 0s
+        let wide = ["1".repeat(40), "1".repeat(20) + &"0".repeat(20), "0".repeat(40)];
+        assert_eq!((0xff_fff0_0000, 0xf_ffff), rates(&wide));
+        assert_eq!(0xff_fff0_0000 * 0xf_ffff, power(&wide));
+        let report = parse_diagnostic(&format!("{}\n{}\n{}", "10".repeat(24), "01".repeat(24), "11".repeat(24))).unwrap();
+        let diagnostics = diagnostics(&report);
+        assert_eq!(u64::MAX >> 16, diagnostics.gamma);
+        assert_eq!(0, diagnostics.epsilon);
+        assert_eq!(u64::MAX >> 16, diagnostics.oxygen);
+        assert_eq!(0x5555_5555_5555, diagnostics.co2);
+        // every column is a tie, so gamma is all 1s and epsilon all 0s
+        let widest = [format!("1{}", "0".repeat(63)), format!("0{}", "1".repeat(63))];
+        assert_eq!((u64::MAX, 0), rates(&widest));
+        assert_eq!(u128::from(1u64 << 63) * u128::from(u64::MAX >> 1), life_support(&widest));
+        assert!(parse_diagnostic(&"1".repeat(65)).unwrap_err().to_string().contains("expected at most 64 digits, found 65"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("invalid day3 input: the report has no numbers", parse_diagnostic("").unwrap_err().to_string());
+        assert!(parse_diagnostic("101\n\n011").unwrap_err().to_string().starts_with("invalid day3 input on line 2"));
+        assert!(parse_diagnostic("101\n0111").unwrap_err().to_string().contains("expected 3 digits like the first line, found 4"));
+        assert!(Day3::read(&mut "".as_bytes(), "report").is_err());
+    }
+}
@@ -0,0 +1,401 @@
+/*
+Day 4: Giant Squid
+
+Play a game of bingo with a giant squid.
+You are given a list of numbers in the order they will be called and a list of bingo boards.
+
+Part 1: Find the board that will win first.
+Return the sum of all numbers on the board that were not called, multiplied by the last number called.
+
+Part 2: Let the squid win by picking the board that wins last.
+Return the score from part 1 of that last board when it wins.
+
+If more than one board wins on the same draw, the answer is the score of whichever comes first in the input.
+*/
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use crate::error::{self, AdventError, Line, Result};
+use crate::solution::Solver;
+
+#[derive(Clone)]
+pub struct Tile {
+    number: i32,
+    called: bool
+}
+
+impl Tile {
+    pub fn new(number: i32) -> Tile {
+        Tile {number, called: false}
+    }
+
+    pub fn number(&self) -> i32 {
+        self.number
+    }
+
+    pub fn is_called(&self) -> bool {
+        self.called
+    }
+
+    pub fn mark(&mut self) {
+        self.called = true;
+    }
+}
+
+impl fmt::Debug for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.called {
+            write!(f, "*{:2}*", self.number)
+        } else {
+            write!(f, "{:4}", self.number)
+        }
+    }
+}
+
+// Along with the tiles, how many have been called in each row and column,
+// so marking a tile can tell straight away if it finished either one
+#[derive(Debug, Clone)]
+pub struct Board {
+    board: Vec<Vec<Tile>>,
+    row_called: Vec<usize>,
+    col_called: Vec<usize>,
+    won: bool
+}
+
+impl Board {
+    fn new(board: Vec<Vec<Tile>>) -> Board {
+        let width = board[0].len();
+        let mut new = Board { row_called: vec![0; board.len()], col_called: vec![0; width], board, won: false };
+        for row in 0..new.board.len() {
+            for col in 0..width {
+                if new.board[row][col].called {
+                    new.count_called(row, col);
+                }
+            }
+        }
+        new
+    }
+
+    pub fn rows(&self) -> &[Vec<Tile>] {
+        &self.board
+    }
+
+    // Mark the drawn number if it's on the board, returns whether it was
+    pub fn mark(&mut self, draw: i32) -> bool {
+        for row in 0..self.board.len() {
+            for col in 0..self.board[row].len() {
+                if self.board[row][col].number == draw {
+                    self.mark_at(row, col);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    // Marking the same tile twice doesn't count it twice
+    fn mark_at(&mut self, row: usize, col: usize) {
+        if !self.board[row][col].called {
+            self.board[row][col].mark();
+            self.count_called(row, col);
+        }
+    }
+
+    fn count_called(&mut self, row: usize, col: usize) {
+        self.row_called[row] += 1;
+        self.col_called[col] += 1;
+        if self.row_called[row] == self.col_called.len() || self.col_called[col] == self.row_called.len() {
+            self.won = true;
+        }
+    }
+
+    // A whole row or a whole column has been called
+    pub fn is_winner(&self) -> bool {
+        self.won
+    }
+
+    pub fn sum_unmarked(&self) -> i32 {
+        self.board.iter()
+            .map(|row| row.iter()
+                .filter(|tile| !tile.called)
+                .map(|tile| tile.number)
+                .sum::<i32>()
+            )
+            .sum()
+    }
+}
+
+// Where each number is: (board, row, col) for every board it's on, in the order of the boards.
+// A number on the same board twice only counts the first time, like Board::mark
+fn draw_index(boards: &[Board]) -> HashMap<i32, Vec<(usize, usize, usize)>> {
+    let mut index: HashMap<i32, Vec<(usize, usize, usize)>> = HashMap::new();
+    for (b, board) in boards.iter().enumerate() {
+        for (row, tiles) in board.board.iter().enumerate() {
+            for (col, tile) in tiles.iter().enumerate() {
+                let places = index.entry(tile.number).or_default();
+                if places.last().is_none_or(|&(last, _, _)| last != b) {
+                    places.push((b, row, col));
+                }
+            }
+        }
+    }
+    index
+}
+
+// A board winning: which one it is (its place in the input), the draw that finished it
+// (its place in the list of draws, and the number), and its score then
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    pub turn: usize,
+    pub draw: i32,
+    pub score: i32
+}
+
+// Play every draw, and return the boards in the order they win.
+// Boards that never win are left out. Boards that win on the same draw are all there,
+// in the order they are in the input, see first_winners and last_winners
+pub fn winning_order(boards: &[Board], draws: &[i32]) -> Vec<Win> {
+    let mut boards = boards.to_vec();
+    let index = draw_index(&boards);
+    let mut wins = Vec::new();
+    for (turn, &draw) in draws.iter().enumerate() {
+        for &(b, row, col) in index.get(&draw).into_iter().flatten() {
+            if boards[b].is_winner() {
+                continue;
+            }
+            boards[b].mark_at(row, col);
+            if boards[b].is_winner() {
+                wins.push(Win { board: b, turn, draw, score: boards[b].sum_unmarked() * draw });
+            }
+        }
+        if wins.len() == boards.len() {
+            break;
+        }
+    }
+    wins
+}
+
+// Every board that wins on the first draw that any board does
+pub fn first_winners(wins: &[Win]) -> &[Win] {
+    let tied = wins.iter().take_while(|win| win.turn == wins[0].turn).count();
+    &wins[..tied]
+}
+
+// Every board that wins on the last draw that any board does
+pub fn last_winners(wins: &[Win]) -> &[Win] {
+    let tied = wins.iter().rev().take_while(|win| Some(win.turn) == wins.last().map(|last| last.turn)).count();
+    &wins[wins.len() - tied..]
+}
+
+// The answer for the winners of one draw. When boards tie it's the one that comes first
+// in the input, for both parts, and the debug log says which boards tied
+fn tie_break(winners: &[Win]) -> i32 {
+    if winners.len() > 1 {
+        let boards: Vec<String> = winners.iter().map(|win| win.board.to_string()).collect();
+        log::debug!("boards {} all win on draw {}, using board {}", boards.join(", "), winners[0].draw, winners[0].board);
+    }
+    winners.first().map_or(0, |win| win.score)
+}
+
+// Each board is its own section of the input
+fn parse_boards(sections: &[Vec<Line>]) -> Result<Vec<Board>> {
+    sections.iter().map(|board| parse_board(board)).collect()
+}
+
+// A board can be any size, as long as every row is as long as the first
+fn parse_board(lines: &[Line]) -> Result<Board> {
+    let board: Vec<Vec<Tile>> = lines.iter()
+        .map(|line| line.text.split_whitespace().map(|num| parse_number(line, num).map(Tile::new)).collect())
+        .collect::<Result<_>>()?;
+    let width = board.first().map_or(0, Vec::len);
+    if width == 0 {
+        return Err(AdventError::parse("day4", "expected a board, with a row of numbers on each line"));
+    }
+    if let Some((line, row)) = lines.iter().zip(&board).find(|(_, row)| row.len() != width) {
+        return Err(line.error("day4", format!("expected {} numbers like the board's first row, found {}", width, row.len())));
+    }
+    Ok(Board::new(board))
+}
+
+// One board on its own, the rows of numbers with nothing between them
+impl FromStr for Board {
+    type Err = AdventError;
+
+    fn from_str(text: &str) -> Result<Board> {
+        parse_board(&error::lines(text.trim()).collect::<Vec<_>>())
+    }
+}
+
+fn parse_number(line: &Line, num: &str) -> Result<i32> {
+    num.parse().map_err(|_| line.error_in("day4", num, "expected a number"))
+}
+
+// The first line is the list of draws, then a blank line, then the boards
+pub fn parse_input(input: &str) -> Result<(Vec<Board>, Vec<i32>)> {
+    let sections = error::sections(input);
+    let (draws, boards) = sections.split_first()
+        .ok_or_else(|| AdventError::parse("day4", "the input is empty"))?;
+    let draws = draws.iter()
+        .flat_map(|line| line.text.split(',').map(move |num| parse_number(line, num)))
+        .collect::<Result<_>>()?;
+    Ok((parse_boards(boards)?, draws))
+}
+
+pub fn read_input(path: &str) -> Result<(Vec<Board>, Vec<i32>)> {
+    let input = error::read_input(path)?;
+    parse_input(&input)
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Input = (Vec<Board>, Vec<i32>);
+    type Output = i32;
+
+    const TITLE: &'static str = "Giant Squid";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day4/bingo.txt";
+
+    fn parse(input: &str) -> Result<(Vec<Board>, Vec<i32>)> {
+        parse_input(input)
+    }
+
+    fn solve_part1((boards, draws): &(Vec<Board>, Vec<i32>)) -> i32 {
+        tie_break(first_winners(&winning_order(boards, draws)))
+    }
+
+    fn solve_part2((boards, draws): &(Vec<Board>, Vec<i32>)) -> i32 {
+        tie_break(last_winners(&winning_order(boards, draws)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_score(boards: &[Board], draws: &[i32]) -> i32 {
+        tie_break(first_winners(&winning_order(boards, draws)))
+    }
+
+    fn last_score(boards: &[Board], draws: &[i32]) -> i32 {
+        tie_break(last_winners(&winning_order(boards, draws)))
+    }
+
+    fn get_test_data() -> (Vec<Board>, Vec<i32>) {
+        let boards = "22 13 17 11  0
+            8  2 23  4 24
+            21  9 14 16  7
+            6 10  3 18  5
+            1 12 20 15 19
+
+            3 15  0  2 22
+            9 18 13 17  5
+            19  8  7 25 23
+            20 11 10 24  4
+            14 21 16 12  6
+
+            14 21 17 24  4
+            10 16 15  9 19
+            18  8 23 26 20
+            22 11 13  6  5
+            2  0 12  3  7";
+    
+        let draws = vec![7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1];
+        (parse_boards(&error::sections(boards)).unwrap(), draws)
+    }
+
+    #[test]
+    fn test_first_winner() {
+        let (boards, draws) = get_test_data();
+        assert_eq!(4512, first_score(&boards, &draws));
+    }
+
+    #[test]
+    fn test_last_winner() {
+        let (boards, draws) = get_test_data();
+        assert_eq!(1924, last_score(&boards, &draws));
+    }
+
+    #[test]
+    fn test_winning_order() {
+        let (boards, draws) = get_test_data();
+        let wins = winning_order(&boards, &draws);
+        assert_eq!(Win { board: 2, turn: 11, draw: 24, score: 4512 }, wins[0]);
+        assert_eq!(Win { board: 0, turn: 13, draw: 16, score: 137 * 16 }, wins[1]);
+        assert_eq!(Win { board: 1, turn: 14, draw: 13, score: 1924 }, wins[2]);
+        assert_eq!(3, wins.len());
+        // the first board never finishes a row or column
+        assert_eq!(vec![2], winning_order(&boards, &draws[..12]).iter().map(|win| win.board).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_ties() {
+        // the first two boards both finish a row on 3, then the last two both finish on 9
+        let boards = parse_boards(&error::sections("1 3\n5 6\n\n3 2\n7 8\n\n4 9\n7 5\n\n9 4\n5 6")).unwrap();
+        let wins = winning_order(&boards, &[1, 2, 3, 4, 9]);
+        let board = |wins: &[Win]| wins.iter().map(|win| win.board).collect::<Vec<_>>();
+        assert_eq!(vec![0, 1, 2, 3], board(&wins));
+        assert_eq!(vec![0, 1], board(first_winners(&wins)));
+        assert_eq!(vec![2, 3], board(last_winners(&wins)));
+        assert_eq!((5 + 6) * 3, first_score(&boards, &[1, 2, 3, 4, 9]));
+        assert_eq!((7 + 5) * 9, last_score(&boards, &[1, 2, 3, 4, 9]));
+        // no winners at all
+        assert!(first_winners(&[]).is_empty() && last_winners(&[]).is_empty());
+        assert_eq!(0, last_score(&boards, &[7]));
+    }
+
+    #[test]
+    fn test_other_sizes() {
+        let small = parse_boards(&error::sections("1 2 3\n4 5 6\n7 8 9\n\n10 11 12\n13 14 15\n16 17 18")).unwrap();
+        // the second board's middle column
+        assert_eq!((10 + 12 + 13 + 15 + 16 + 18) * 17, first_score(&small, &[11, 14, 17]));
+        // the first board's top row finishes after that
+        assert_eq!((4 + 5 + 6 + 7 + 8 + 9) * 3, last_score(&small, &[11, 14, 17, 1, 2, 3]));
+
+        let numbers: Vec<String> = (0..49).map(|number| number.to_string()).collect();
+        let large = numbers.chunks(7).map(|row| row.join(" ")).collect::<Vec<_>>().join("\n");
+        let large = parse_boards(&error::sections(&large)).unwrap();
+        // the last column is 6, 13, ... 48, and a row of 7 isn't finished with 6 numbers
+        let column: Vec<i32> = (0..7).map(|row| row * 7 + 6).collect();
+        assert_eq!(((0..49).sum::<i32>() - column.iter().sum::<i32>()) * 48, first_score(&large, &column));
+        assert_eq!(0, first_score(&large, &[0, 1, 2, 3, 4, 5]));
+
+        let rectangle = parse_boards(&error::sections("1 2 3 4\n5 6 7 8")).unwrap();
+        assert_eq!((1 + 2 + 3 + 5 + 6 + 7) * 8, first_score(&rectangle, &[4, 8]));
+    }
+
+    #[test]
+    fn test_board() {
+        let mut board: Board = "14 21 17\n10 16 15\n18  8 23".parse().unwrap();
+        assert!(board.mark(16));
+        assert!(!board.mark(99));
+        assert!(board.mark(21) && board.mark(8));
+        assert!(board.is_winner());
+        assert_eq!(14 + 17 + 10 + 15 + 18 + 23, board.sum_unmarked());
+        assert!(board.rows()[1][1].is_called());
+        assert_eq!(10, board.rows()[1][0].number());
+
+        assert_eq!("invalid day4 input: expected a board, with a row of numbers on each line", "".parse::<Board>().unwrap_err().to_string());
+        assert!("1 2\n\n3 4".parse::<Board>().unwrap_err().to_string().starts_with("invalid day4 input on line 2"));
+        assert!("1 2\n3 x".parse::<Board>().unwrap_err().to_string().contains("column 3: expected a number"));
+    }
+
+    #[test]
+    fn test_draw_index() {
+        let boards = parse_boards(&error::sections("1 2\n3 1\n\n4 5\n6 2")).unwrap();
+        let index = draw_index(&boards);
+        assert_eq!(vec![(0, 0, 0)], index[&1]);
+        assert_eq!(vec![(0, 0, 1), (1, 1, 1)], index[&2]);
+        assert_eq!(None, index.get(&7));
+        // drawing a number twice doesn't finish a row on its own
+        assert_eq!(0, first_score(&boards, &[4, 4, 4]));
+        assert_eq!((6 + 2) * 4, first_score(&boards, &[7, 5, 4]));
+    }
+
+    #[test]
+    fn test_uneven_rows() {
+        let error = parse_input("1,2\n\n1 2 3\n4 5\n6 7 8").unwrap_err();
+        assert!(error.to_string().starts_with("invalid day4 input on line 4: expected 3 numbers like the board's first row, found 2"));
+    }
+}
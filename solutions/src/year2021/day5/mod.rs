@@ -0,0 +1,355 @@
+/*
+Day 5: Hydrothermal Venture
+
+Puzzle input is a list of line segments like "9,4 -> 3,4".
+Some of these line segments overlap each other.
+
+Part 1: Using only straight horizontal or straight vertical lines,
+count the number of points with multiple "vents"
+
+Part 2: Now use all lines including diagonals.
+All diaganals in the puzzle are 45 degree angled slopes.
+*/
+
+use std::collections::HashMap;
+use std::cmp;
+use std::str::FromStr;
+use crate::error::{self, AdventError, PartResult, Result};
+use crate::geometry::{ParsePointError, Point2};
+use crate::grid::Grid;
+use crate::solution::Solver;
+use crate::viz::{Render, Rgb};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Debug, PartialEq)]
+pub struct LineSegment {
+    p1: Point2,
+    p2: Point2
+}
+
+impl LineSegment {
+    // Every point on the line, from p1 to p2.
+    // Lines are horizontal, vertical or at 45 degrees, so each step is one in x and/or y
+    // (the sign of the difference between the end points is the step to take)
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let step = (self.p2 - self.p1).signum();
+        let length = cmp::max((self.p2.x - self.p1.x).abs(), (self.p2.y - self.p1.y).abs());
+        let start = self.p1;
+        (0..=length).map(move |i| start + step * i)
+    }
+
+    fn is_straight(&self) -> bool {
+        self.p1.x == self.p2.x || self.p1.y == self.p2.y
+    }
+}
+
+/**
+ * Part 1. Only the horizontal and vertical lines
+ */
+pub fn count_straight_overlaps(lines: &[LineSegment]) -> usize {
+    let straight: Vec<&LineSegment> = lines.iter().filter(|ls| ls.is_straight()).collect();
+    count_overlaps(&straight)
+}
+
+/*
+ * Part 2. The same with every line, diagonals included
+ */
+pub fn count_all_overlaps(lines: &[LineSegment]) -> usize {
+    let all: Vec<&LineSegment> = lines.iter().collect();
+    count_overlaps(&all)
+}
+
+// Grids with up to this many cells are counted in a Vec, 8MB of u16s.
+// The puzzle input is about 1000x1000, so only made up inputs that spread out further use the map
+const DENSE_LIMIT: usize = 1 << 22;
+
+// The number of points that more than one line goes through
+fn count_overlaps(lines: &[&LineSegment]) -> usize {
+    match bounds(lines) {
+        Some((min, max)) if cells(min, max) <= DENSE_LIMIT => count_dense(lines, min, max),
+        Some(_) => count_sparse(lines),
+        None => 0
+    }
+}
+
+// The corners of the box around every line, the smallest x and y and the largest
+fn bounds(lines: &[&LineSegment]) -> Option<(Point2, Point2)> {
+    let ends = lines.iter().flat_map(|ls| [ls.p1, ls.p2]);
+    ends.fold(None, |bounds, point| match bounds {
+        None => Some((point, point)),
+        Some((min, max)) => Some((Point2::new(min.x.min(point.x), min.y.min(point.y)), Point2::new(max.x.max(point.x), max.y.max(point.y))))
+    })
+}
+
+fn cells(min: Point2, max: Point2) -> usize {
+    let width = (i64::from(max.x) - i64::from(min.x) + 1) as usize;
+    let height = (i64::from(max.y) - i64::from(min.y) + 1) as usize;
+    width.saturating_mul(height)
+}
+
+// Count the lines through each point in a flat grid covering the bounding box.
+// Only whether a count gets past 1 matters, so it stops going up at u16::MAX rather than overflowing
+fn count_dense(lines: &[&LineSegment], min: Point2, max: Point2) -> usize {
+    let cells = cells(min, max);
+    let grid = |lines: &[&LineSegment]| dense_grid(lines, min, max, cells);
+    // With the parallel feature each thread counts a share of the lines in a grid of its own,
+    // and then the grids are added together
+    #[cfg(not(feature = "parallel"))]
+    let grid = grid(lines);
+    #[cfg(feature = "parallel")]
+    let grid = lines.par_chunks(chunk_size(lines)).map(grid)
+        .reduce_with(|mut total, grid| {
+            total.iter_mut().zip(grid).for_each(|(total, count)| *total = total.saturating_add(count));
+            total
+        })
+        .unwrap_or_default();
+    grid.iter().filter(|&&count| count > 1).count()
+}
+
+fn dense_grid(lines: &[&LineSegment], min: Point2, max: Point2, cells: usize) -> Vec<u16> {
+    let width = (max.x - min.x + 1) as usize;
+    let mut grid = vec![0u16; cells];
+    for ls in lines {
+        for point in ls.points() {
+            let count = &mut grid[(point.y - min.y) as usize * width + (point.x - min.x) as usize];
+            *count = count.saturating_add(1);
+        }
+    }
+    grid
+}
+
+// Use a map with the "Point" as the key to count occurences of that point,
+// for lines spread out too far to have a cell for every point in between
+fn count_sparse(lines: &[&LineSegment]) -> usize {
+    vent_map(lines).values().filter(|&&count| count > 1).count()
+}
+
+// Split up between the threads like count_dense with the parallel feature
+fn vent_map(lines: &[&LineSegment]) -> HashMap<Point2, usize> {
+    #[cfg(not(feature = "parallel"))]
+    let grid = sparse_grid(lines);
+    #[cfg(feature = "parallel")]
+    let grid = lines.par_chunks(chunk_size(lines)).map(sparse_grid)
+        .reduce_with(|mut total, grid| {
+            for (point, count) in grid {
+                *total.entry(point).or_insert(0) += count;
+            }
+            total
+        })
+        .unwrap_or_default();
+    grid
+}
+
+fn sparse_grid(lines: &[&LineSegment]) -> HashMap<Point2, usize> {
+    let mut grid: HashMap<Point2, usize> = HashMap::new();
+    for ls in lines {
+        for point in ls.points() {
+            *grid.entry(point).or_insert(0) += 1;
+        }
+    }
+    grid
+}
+
+// One chunk of lines for each thread, so there's one grid per thread to add up at the end
+#[cfg(feature = "parallel")]
+fn chunk_size(lines: &[&LineSegment]) -> usize {
+    lines.len().div_ceil(rayon::current_num_threads()).max(1)
+}
+
+// How many lines go through each point that has a vent, diagonal lines included
+pub fn vent_counts(lines: &[LineSegment]) -> HashMap<Point2, usize> {
+    vent_map(&lines.iter().collect::<Vec<_>>())
+}
+
+// The points where lines overlap, with how many lines go through each one.
+// Part 2 is how many there are
+pub fn overlap_points(lines: &[LineSegment]) -> HashMap<Point2, usize> {
+    let mut counts = vent_counts(lines);
+    counts.retain(|_, &mut count| count > 1);
+    counts
+}
+
+// The heatmap is at most this many cells across, so the picture stays a reasonable size
+const HEATMAP_SIZE: i32 = 200;
+
+// How many vents there are in each part of the ocean floor, from black where there are none
+// through red and yellow to white for the most. The input is about 1000x1000,
+// so each cell of the picture is a square of points, adding up the vents in them
+pub fn draw_heatmap(lines: &[LineSegment]) -> Grid<Rgb> {
+    let all: Vec<&LineSegment> = lines.iter().collect();
+    let Some((min, max)) = bounds(&all) else {
+        return Grid::filled(0, 0, Rgb::BLACK);
+    };
+    let scale = cmp::max(max.x - min.x, max.y - min.y) / HEATMAP_SIZE + 1;
+    let width = ((max.x - min.x) / scale + 1) as usize;
+    let height = ((max.y - min.y) / scale + 1) as usize;
+    let mut density = Grid::filled(width, height, 0);
+    for (point, count) in vent_map(&all) {
+        density[(((point.y - min.y) / scale) as usize, ((point.x - min.x) / scale) as usize)] += count;
+    }
+    let most = density.positions().map(|position| density[position]).max().unwrap_or(0).max(1);
+    Grid::from_fn(width, height, |position| heat(density[position] as f64 / most as f64))
+}
+
+// 0 is black and 1 is white, going through red then yellow
+fn heat(fraction: f64) -> Rgb {
+    let channel = |start: f64| ((fraction * 3.0 - start).clamp(0.0, 1.0) * 255.0).round() as u8;
+    Rgb(channel(0.0), channel(1.0), channel(2.0))
+}
+
+pub fn read_data(path: &str) -> Result<Vec<LineSegment>> {
+    let input = error::read_input(path)?;
+    parse_data(&input[..])
+}
+
+// Each line looks like "0,9 -> 5,9"
+pub fn parse_data(data: &str) -> Result<Vec<LineSegment>> {
+    error::lines(data)
+        .map(|line| line.parse_with("day5", split_segment))
+        .collect()
+}
+
+// One line on its own, without a line of the input to point at in the error
+impl FromStr for LineSegment {
+    type Err = AdventError;
+
+    fn from_str(text: &str) -> Result<LineSegment> {
+        error::parse_text("day5", text, split_segment)
+    }
+}
+
+// The line, or the part of the text that's wrong and what's wrong with it.
+// points() only steps along horizontal, vertical and 45 degree lines, so any other slope is an error
+fn split_segment(text: &str) -> PartResult<'_, LineSegment> {
+    let (p1, p2) = text.split_once(" -> ").ok_or((text, "expected a line like \"0,9 -> 5,9\"".to_string()))?;
+    let segment = LineSegment { p1: parse_point(p1)?, p2: parse_point(p2)? };
+    let (dx, dy) = (segment.p2.x - segment.p1.x, segment.p2.y - segment.p1.y);
+    if dx != 0 && dy != 0 && dx.abs() != dy.abs() {
+        return Err((text, "expected a horizontal, vertical or 45 degree line".to_string()));
+    }
+    Ok(segment)
+}
+
+// Points the error at the coordinate that isn't a number, if that's what's wrong
+fn parse_point(text: &str) -> PartResult<'_, Point2> {
+    text.parse().map_err(|error| {
+        let part = match &error {
+            ParsePointError::Number(bad) => text.split(',').map(str::trim).find(|coordinate| coordinate == bad).unwrap_or(text),
+            ParsePointError::Coordinates { .. } => text
+        };
+        (part, error.to_string())
+    })
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Input = Vec<LineSegment>;
+    type Output = usize;
+
+    const TITLE: &'static str = "Hydrothermal Venture";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day5/lines.txt";
+
+    fn parse(input: &str) -> Result<Vec<LineSegment>> {
+        parse_data(input)
+    }
+
+    fn solve_part1(lines: &Vec<LineSegment>) -> usize {
+        count_straight_overlaps(lines)
+    }
+
+    fn solve_part2(lines: &Vec<LineSegment>) -> usize {
+        count_all_overlaps(lines)
+    }
+
+    fn render(lines: &Vec<LineSegment>) -> Option<Box<dyn Render>> {
+        Some(Box::new(draw_heatmap(lines)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_data() -> Vec<LineSegment> {
+        let data = "0,9 -> 5,9
+            8,0 -> 0,8
+            9,4 -> 3,4
+            2,2 -> 2,1
+            7,0 -> 7,4
+            6,4 -> 2,0
+            0,9 -> 2,9
+            3,4 -> 1,4
+            0,0 -> 8,8
+            5,5 -> 8,2";
+        parse_data(data).unwrap()
+    }
+
+    #[test]
+    fn test_straight_overlaps() {
+        let lines = test_data();
+        assert_eq!(5, count_straight_overlaps(&lines));
+    }
+
+    #[test]
+    fn test_all_overlaps() {
+        let lines = test_data();
+        assert_eq!(12, count_all_overlaps(&lines));
+    }
+
+    #[test]
+    fn test_overlap_points() {
+        let lines = test_data();
+        let overlaps = overlap_points(&lines);
+        assert_eq!(12, overlaps.len());
+        assert_eq!(Some(&3), overlaps.get(&Point2::new(4, 4)));
+        assert_eq!(Some(&2), overlaps.get(&Point2::new(0, 9)));
+        assert_eq!(None, overlaps.get(&Point2::new(8, 0)));
+        assert_eq!(Some(&1), vent_counts(&lines).get(&Point2::new(8, 0)));
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let heatmap = draw_heatmap(&test_data());
+        assert_eq!((10, 10), (heatmap.width(), heatmap.height()));
+        // (4, 4) has the most lines through it, and (9, 0) has none
+        assert_eq!(Rgb::WHITE, heatmap[(4, 4)]);
+        assert_eq!(Rgb::BLACK, heatmap[(0, 9)]);
+        assert_eq!(Rgb(255, 255, 0), heatmap[(9, 0)]);
+        // big inputs are scaled down
+        let big = draw_heatmap(&parse_data("0,0 -> 999,999").unwrap());
+        assert_eq!((200, 200), (big.width(), big.height()));
+        assert_eq!(Rgb::WHITE, big[(100, 100)]);
+        assert_eq!(Rgb::BLACK, big[(0, 100)]);
+        assert_eq!(0, draw_heatmap(&[]).width());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(LineSegment { p1: Point2::new(0, 9), p2: Point2::new(5, 9) }, " 0,9 -> 5,9".parse().unwrap());
+        assert_eq!("invalid day5 input: expected a line like \"0,9 -> 5,9\"", "0,9 5,9".parse::<LineSegment>().unwrap_err().to_string());
+        let error = parse_data("0,9 -> 5,9\n8,0 -> 0,x").unwrap_err().to_string();
+        assert_eq!("invalid day5 input on line 2, column 10: expected a number for a coordinate, found \"x\"\n  2 | 8,0 -> 0,x\n    |          ^", error);
+        let error = parse_data("8,0,1 -> 0,8").unwrap_err().to_string();
+        assert!(error.starts_with("invalid day5 input on line 1, column 1: expected 2 coordinates separated by commas, found 3"));
+        let error = parse_data("0,9 -> 5,9\n0,0 -> 3,1").unwrap_err().to_string();
+        assert_eq!("invalid day5 input on line 2, column 1: expected a horizontal, vertical or 45 degree line\n  2 | 0,0 -> 3,1\n    | ^^^^^^^^^^", error);
+        assert!("0,0 -> 3,1".parse::<LineSegment>().is_err());
+    }
+
+    #[test]
+    fn test_backends() {
+        let lines = test_data();
+        let all: Vec<&LineSegment> = lines.iter().collect();
+        assert_eq!(Some((Point2::new(0, 0), Point2::new(9, 9))), bounds(&all));
+        assert_eq!(12, count_dense(&all, Point2::new(0, 0), Point2::new(9, 9)));
+        assert_eq!(12, count_sparse(&all));
+        // too far apart for a dense grid
+        let far = parse_data("0,0 -> 5,0\n3,0 -> 3,9\n-300000,0 -> 300000,0").unwrap();
+        let far: Vec<&LineSegment> = far.iter().collect();
+        assert!(cells(bounds(&far).unwrap().0, bounds(&far).unwrap().1) > DENSE_LIMIT);
+        assert_eq!(6, count_overlaps(&far));
+        assert_eq!(0, count_overlaps(&[]));
+    }
+}
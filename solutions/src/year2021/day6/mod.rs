@@ -0,0 +1,315 @@
+/*
+Day 6: Lanternfish
+
+Lanternfish populations grow exponentially - each fish spawns a new fish every 7 days.
+Newly born fish take an extra 2 days to spawn.
+Each fish starts at a different point in their lifecycle.
+Initial state: 3,4,3,1,2
+After  1 day:  2,3,2,0,1
+After  2 days: 1,2,1,6,0,8
+After  3 days: 0,1,0,5,6,7,8
+
+Part 1: What is the total fish population at 80 days.
+Part 2: What is the total fish population at 256 days
+
+The lifecycle can be changed (population_with), for other things that grow the same way.
+*/
+use serde::Serialize;
+use crate::error::{self, Result};
+use crate::series::Series;
+use crate::solution::Solver;
+
+/**
+ * Both parts: only the number of fish with each timer matters, not which fish is which.
+ *      Keep a count for each timer value from 0 to 8. Each day every count moves down one timer,
+ *      which is rotating the array, and the fish at 0 go to 8 (their new fish) and to 6 (themselves).
+ *      O(days), so any number of days is quick.
+ * The brute force part 1 and the memoized recursion part 2 started with are in the tests, to check this against.
+ */
+pub fn population(fish: &[i32], days: usize) -> u128 {
+    population_with(fish, days, Lifecycle::LANTERNFISH)
+}
+
+// How often a fish spawns, and how much longer a new fish takes before its first.
+// The timers go from 0 to interval + maturation - 1, a fish that spawns goes back to interval - 1
+// and the new fish starts at the top
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lifecycle {
+    pub interval: usize,
+    pub maturation: usize
+}
+
+impl Lifecycle {
+    // Every 7 days, and 2 more for a new fish, so timers from 0 to 8
+    pub const LANTERNFISH: Lifecycle = Lifecycle { interval: 7, maturation: 2 };
+
+    fn timers(&self) -> usize {
+        self.interval + self.maturation
+    }
+}
+
+// population() for anything that grows like the lanternfish, with its own lifecycle.
+// Panics if the population gets too big for a u128, see checked_population
+pub fn population_with(fish: &[i32], days: usize, lifecycle: Lifecycle) -> u128 {
+    checked_population(fish, days, lifecycle)
+        .unwrap_or_else(|| panic!("the population is too big for a u128 after {} days", days))
+}
+
+// The population grows exponentially, past a u64 in about 500 days for the lanternfish
+// and past a u128 in about 1000. None if it gets too big
+pub fn checked_population(fish: &[i32], days: usize, lifecycle: Lifecycle) -> Option<u128> {
+    let mut timers = count_timers(fish, lifecycle);
+    for _ in 0..days {
+        next_day(&mut timers, lifecycle)?;
+    }
+    total(&timers)
+}
+
+// The population on one day, in total and how many fish have each timer value.
+// serde writes the counts as plain numbers, past 2^53 JavaScript rounds them (--series writes strings instead)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Census {
+    pub day: usize,
+    pub total: u128,
+    pub timers: Vec<u128>
+}
+
+// The population on every day from the start (day 0) to days, for a growth curve.
+// Panics like population_with if it gets too big
+pub fn timeline(fish: &[i32], days: usize, lifecycle: Lifecycle) -> Vec<Census> {
+    let mut timers = count_timers(fish, lifecycle);
+    let mut censuses = Vec::with_capacity(days + 1);
+    for day in 0..=days {
+        let moved = if day > 0 { next_day(&mut timers, lifecycle) } else { Some(()) };
+        let total = moved.and_then(|_| total(&timers))
+            .unwrap_or_else(|| panic!("the population is too big for a u128 after {} days", day));
+        censuses.push(Census { day, total, timers: timers.clone() });
+    }
+    censuses
+}
+
+// A timeline as a Series for --series, a row per day: the day, the total, then the count for each timer
+pub fn timeline_series(timeline: &[Census]) -> Series {
+    let timers = timeline.first().map_or(0, |census| census.timers.len());
+    let columns = ["day".to_string(), "total".to_string()].into_iter()
+        .chain((0..timers).map(|timer| format!("timer{}", timer)))
+        .collect();
+    let rows = timeline.iter()
+        .map(|census| [census.day as u128, census.total].into_iter().chain(census.timers.iter().copied()).collect())
+        .collect();
+    Series { columns, rows }
+}
+
+fn count_timers(fish: &[i32], lifecycle: Lifecycle) -> Vec<u128> {
+    assert!(lifecycle.interval > 0, "a fish has to wait at least a day between spawning");
+    let mut timers = vec![0u128; lifecycle.timers()];
+    for &f in fish {
+        let timer = usize::try_from(f).ok().filter(|&timer| timer < timers.len())
+            .unwrap_or_else(|| panic!("timer {} is outside the lifecycle, which goes from 0 to {}", f, timers.len() - 1));
+        timers[timer] += 1;
+    }
+    timers
+}
+
+// None if the fish with one timer value overflow
+fn next_day(timers: &mut [u128], lifecycle: Lifecycle) -> Option<()> {
+    timers.rotate_left(1);
+    timers[lifecycle.interval - 1] = timers[lifecycle.interval - 1].checked_add(timers[lifecycle.timers() - 1])?;
+    Some(())
+}
+
+fn total(timers: &[u128]) -> Option<u128> {
+    timers.iter().try_fold(0u128, |total, &count| total.checked_add(count))
+}
+
+pub fn read_input(path: &str) -> Result<Vec<i32>> {
+    let fish = error::read_input(path)?;
+    parse_input(&fish)
+}
+
+// Each fish is a timer from 0 to 8
+pub fn parse_input(fish: &str) -> Result<Vec<i32>> {
+    error::lines(fish).filter(|line| !line.text.is_empty())
+        .flat_map(|line| line.text.split(",").map(move |f| f.parse().ok().filter(|timer| (0..=8).contains(timer))
+            .ok_or_else(|| line.error_in("day6", f, "expected a timer from 0 to 8"))))
+        .collect()
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Input = Vec<i32>;
+    type Output = u128;
+
+    const TITLE: &'static str = "Lanternfish";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day6/fish.txt";
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        parse_input(input)
+    }
+
+    fn solve_part1(fish: &Vec<i32>) -> u128 {
+        population(fish, 80)
+    }
+
+    fn solve_part2(fish: &Vec<i32>) -> u128 {
+        population(fish, 256)
+    }
+
+    // The population on every day up to part 2's
+    fn series(fish: &Vec<i32>) -> Option<Series> {
+        Some(timeline_series(&timeline(fish, 256, Lifecycle::LANTERNFISH)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::*;
+
+    /**
+     * The first part 1: Brute force (~350ms)
+     *      loop one day at a time, updating the counters for each fish
+     *      and add new fish when required.
+     */
+    fn calc_growth(fish: &[i32], days: usize) -> usize {
+        calc_growth_with(fish, days, Lifecycle::LANTERNFISH)
+    }
+
+    fn calc_growth_with(fish: &[i32], days: usize, lifecycle: Lifecycle) -> usize {
+        let mut fish = fish.to_vec();
+        for _ in 0..days {
+            // use index for loop because mutating vector values inside a for-each is very hard
+            for i in 0..fish.len() {
+                fish[i] -= 1;
+                if fish[i] < 0 {
+                    fish[i] = lifecycle.interval as i32 - 1;
+                    fish.push(lifecycle.timers() as i32 - 1);
+                }
+            }
+        }
+        fish.len()
+    }
+
+    /**
+     * The first part 2: smarter way using recursion and memoization (~2ms)
+     * recursively call the total_fish function - depth first traversal of fish population
+     *      when a new fish is created, call total_fish for the new fish (using updated days value)
+     *      use a memoization map to prevent repeat calculations
+     *          Key is a tuple (fish value, days remaining)
+     *          value is the total number of fish that will exist at the end
+     */ 
+    fn model_growth(fish: &[i32], days: i32) -> usize {
+        let mut total = 0;
+        let mut memo: HashMap<(i32, i32), usize> = HashMap::new();
+        for &f in fish {
+            total += total_fish(f, days, &mut memo);
+        }
+        total
+    }
+
+    fn total_fish(initial_fish: i32, days: i32, memo: &mut HashMap<(i32, i32), usize>) -> usize {
+        if let Some(total) = memo.get(&(initial_fish, days)) {
+            return *total;
+        }
+        let mut total = 1;
+        let mut days_left = days;
+        let mut fish = initial_fish;
+        while fish < days_left {
+            // new fish created after 0, when the fish rolls back to 6
+            days_left = days_left - fish - 1;
+            fish = 6;
+            total += total_fish(8, days_left, memo);
+        }
+        memo.insert((initial_fish, days), total);
+        total
+    }
+
+    #[test]
+    fn test_calc_growth() {
+        let init = vec![3,4,3,1,2];
+        assert_eq!(26, calc_growth(&init, 18));
+        assert_eq!(5934, calc_growth(&init, 80));
+    }
+
+    #[test]
+    fn test_model_growth() {
+        let init = vec![3,4,3,1,2];
+        assert_eq!(26984457539, model_growth(&init, 256));
+    }
+
+    #[test]
+    fn test_population() {
+        let init = vec![3,4,3,1,2];
+        assert_eq!(26, population(&init, 18));
+        assert_eq!(5934, population(&init, 80));
+        assert_eq!(26984457539, population(&init, 256));
+        let fish = vec![0,1,2,3,4,5,6,7,8,8,0];
+        for days in 0..60 {
+            assert_eq!(calc_growth(&fish, days) as u128, population(&fish, days));
+            assert_eq!(model_growth(&fish, days as i32) as u128, population(&fish, days));
+        }
+    }
+
+    #[test]
+    fn test_other_lifecycles() {
+        // spawning every day with no wait for the new fish doubles them every day
+        let every_day = Lifecycle { interval: 1, maturation: 0 };
+        assert_eq!(3 << 10, population_with(&[0, 0, 0], 10, every_day));
+        // spawning every day, with a day's wait for a new fish, is the fibonacci sequence
+        let fibonacci = Lifecycle { interval: 1, maturation: 1 };
+        assert_eq!(vec![1, 1, 2, 3, 5, 8], (0..6).map(|days| population_with(&[1], days, fibonacci)).collect::<Vec<_>>());
+        for lifecycle in [every_day, fibonacci, Lifecycle { interval: 3, maturation: 4 }, Lifecycle { interval: 10, maturation: 1 }] {
+            let fish = [0, lifecycle.timers() as i32 - 1, 0];
+            for days in 0..16 {
+                assert_eq!(calc_growth_with(&fish, days, lifecycle) as u128, population_with(&fish, days, lifecycle));
+            }
+        }
+    }
+
+    #[test]
+    fn test_timeline() {
+        let timeline = timeline(&[3,4,3,1,2], 18, Lifecycle::LANTERNFISH);
+        assert_eq!(19, timeline.len());
+        assert_eq!(Census { day: 0, total: 5, timers: vec![0, 1, 1, 2, 1, 0, 0, 0, 0] }, timeline[0]);
+        // after 2 days: 1,2,1,6,0,8
+        assert_eq!(Census { day: 2, total: 6, timers: vec![1, 2, 1, 0, 0, 0, 1, 0, 1] }, timeline[2]);
+        assert_eq!(26, timeline[18].total);
+        assert!(timeline.windows(2).all(|days| days[0].total <= days[1].total));
+
+        assert_eq!(r#"{"day":0,"total":5,"timers":[0,1,1,2,1,0,0,0,0]}"#, serde_json::to_string(&timeline[0]).unwrap());
+
+        let series = timeline_series(&timeline[..2]);
+        assert_eq!(vec!["day", "total", "timer0", "timer1", "timer2", "timer3", "timer4", "timer5", "timer6", "timer7", "timer8"], series.columns);
+        assert_eq!(vec![vec![0, 5, 0, 1, 1, 2, 1, 0, 0, 0, 0], vec![1, 5, 1, 1, 2, 1, 0, 0, 0, 0, 0]], series.rows);
+        let series = Day6::series(&vec![3,4,3,1,2]).unwrap();
+        assert_eq!(257, series.rows.len());
+        assert_eq!(vec![256, 26984457539], series.rows[256][..2]);
+    }
+
+    #[test]
+    fn test_past_u64() {
+        let fish = [3,4,3,1,2];
+        let day = (0..).find(|&day| population(&fish, day) > u128::from(u64::MAX)).unwrap();
+        assert!(population(&fish, day - 1) <= u128::from(u64::MAX));
+        // each fish makes a new one 7 days later, and that one makes another 9 days after it was made,
+        // so the population follows P(n) = P(n - 7) + P(n - 9)
+        for day in day - 2..day + 3 {
+            assert_eq!(population(&fish, day - 7) + population(&fish, day - 9), population(&fish, day));
+        }
+        assert_eq!(None, checked_population(&fish, 2000, Lifecycle::LANTERNFISH));
+        assert!(checked_population(&fish, 900, Lifecycle::LANTERNFISH).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "the population is too big for a u128 after 2000 days")]
+    fn test_overflow() {
+        population(&[0], 2000);
+    }
+
+    #[test]
+    #[should_panic(expected = "timer 9 is outside the lifecycle, which goes from 0 to 8")]
+    fn test_timer_outside_lifecycle() {
+        population(&[9], 1);
+    }
+}
\ No newline at end of file
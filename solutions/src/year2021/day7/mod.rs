@@ -0,0 +1,302 @@
+/*
+Day 7: The Treachery of Whales
+
+To escape a giant whale, a bunch of crabs in submarines have to get to the same horizontal position.
+These submarines have limited gas, so we have to find the position that takes the least amount of gas
+for all the crab submarines to reach.
+
+Part 1: gas is computed linearly: a crab at position 5 requires 3 gas to reach position 2.
+
+Part 2: gas is computed by adding an additional unit per horizontal space moved.
+Moving from 5 -> 4 = 1, from 5 -> 3 = 1 + 2, etc. So Moving from position 5 to position 2 requires 6 gas. 
+*/
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::iter::Sum;
+use crate::error::{self, Result};
+use crate::solution::Solver;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+// Part 1: each space moved costs 1
+pub fn linear(distance: i32) -> i64 {
+    i64::from(distance)
+}
+
+// Part 2: each space costs one more than the last.
+// 1+2+3+4..n == (n * (n+1)) / 2
+pub fn triangular(distance: i32) -> i64 {
+    let distance = i64::from(distance);
+    distance * (distance + 1) / 2
+}
+
+// The gas for every crab to get to position, with cost giving the gas to move a distance.
+// Added up in an i64, a fleet of a few million crabs can take more than an i32 holds
+pub fn total_cost(subs: &[i32], position: i32, cost: impl Fn(i32) -> i64 + Sync) -> i64 {
+    chunked_sum(subs, |subs| subs.iter().fold(0, |acc, sub| acc + cost((sub - position).abs())))
+}
+
+// Crabs added up by each thread at a time with the parallel feature.
+// Handing work to another thread costs more than adding up the puzzle's 1000 crabs,
+// so only fleets bigger than a chunk are split up
+#[cfg(feature = "parallel")]
+const CHUNK: usize = 1 << 14;
+
+// Adds up sum() over the whole slice. With the parallel feature a big slice is cut into chunks
+// and the threads add up a chunk at a time
+fn chunked_sum<T: Sync, S: Sum + Send>(items: &[T], sum: impl Fn(&[T]) -> S + Sync) -> S {
+    #[cfg(feature = "parallel")]
+    if items.len() > CHUNK {
+        return items.par_chunks(CHUNK).map(&sum).sum();
+    }
+    sum(items)
+}
+
+/**
+ * The least gas it takes for the crabs to line up, with any cost for moving a distance.
+ * Only positions between the crabs furthest apart can be cheapest.
+ * As long as each space moved costs at least as much as the one before (the cost is convex, like both parts),
+ * the total gas goes down to the cheapest position and back up again after it, so ternary search finds it:
+ *      Try two positions a third of the way in from each end.
+ *      The cheapest position can't be past the more expensive of the two, so that third is dropped.
+ *      If they cost the same, the cheapest is between them, or they're both on the flat bottom
+ *      and there may be cheapest positions before them too, so only the last third is dropped.
+ *      Once there are only a few positions left, try them all.
+ * Returns (position, gas). If more than one position is cheapest, it's the lowest of them.
+ * None if there are no crabs.
+ */
+pub fn best_alignment(subs: &[i32], cost: impl Fn(i32) -> i64 + Sync) -> Option<(i32, i64)> {
+    let (&min, &max) = (subs.iter().min()?, subs.iter().max()?);
+    Some(ternary_search(min, max, subs.len(), |position| total_cost(subs, position, &cost)))
+}
+
+// The cheapest (position, gas) from min to max, for a gas that goes down and then back up.
+// With the parallel feature and more than a chunk of crabs (or positions in a fleet),
+// the two positions tried each time are worked out at the same time
+#[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+fn ternary_search<T: Ord + Send>(min: i32, max: i32, crabs: usize, gas: impl Fn(i32) -> T + Sync) -> (i32, T) {
+    let (mut low, mut high) = (min, max);
+    while high - low > 2 {
+        let third = (high - low) / 3;
+        let (left, right) = (low + third, high - third);
+        #[cfg(not(feature = "parallel"))]
+        let (left_gas, right_gas) = (gas(left), gas(right));
+        #[cfg(feature = "parallel")]
+        let (left_gas, right_gas) = if crabs > CHUNK {
+            rayon::join(|| gas(left), || gas(right))
+        } else {
+            (gas(left), gas(right))
+        };
+        match left_gas.cmp(&right_gas) {
+            Ordering::Less => high = right - 1,
+            Ordering::Greater => low = left + 1,
+            Ordering::Equal => high = right
+        }
+    }
+    (low..=high).map(|position| (position, gas(position))).min_by(|(_, a), (_, b)| a.cmp(b)).unwrap()
+}
+
+// Only the gas from best_alignment, 0 if there are no crabs
+pub fn cheapest_alignment(subs: &[i32], cost: impl Fn(i32) -> i64 + Sync) -> i64 {
+    best_alignment(subs, cost).map_or(0, |(_, gas)| gas)
+}
+
+// The crabs counted by position, as (position, count) sorted by position.
+// A fleet with billions of crabs is only as long as the number of different positions
+pub fn fleet(subs: &[i32]) -> Vec<(i32, u64)> {
+    let mut counts = BTreeMap::new();
+    for &sub in subs {
+        *counts.entry(sub).or_insert(0) += 1;
+    }
+    counts.into_iter().collect()
+}
+
+// total_cost for a fleet, each crab at a position costs the same
+pub fn fleet_cost(fleet: &[(i32, u64)], position: i32, cost: impl Fn(i32) -> i64 + Sync) -> i64 {
+    chunked_sum(fleet, |fleet| fleet.iter().map(|&(sub, count)| cost((sub - position).abs()) * count as i64).sum::<i64>())
+}
+
+// best_alignment for a fleet. Positions with no crabs (a count of 0) don't count.
+// The pairs don't have to be sorted, and a position can be in more than one
+pub fn best_fleet_alignment(fleet: &[(i32, u64)], cost: impl Fn(i32) -> i64 + Sync) -> Option<(i32, i64)> {
+    let mut positions = fleet.iter().filter(|(_, count)| *count > 0).map(|&(position, _)| position);
+    let first = positions.next()?;
+    let (min, max) = positions.fold((first, first), |(min, max), position| (min.min(position), max.max(position)));
+    Some(ternary_search(min, max, fleet.len(), |position| fleet_cost(fleet, position, &cost)))
+}
+
+// Part 1: the least gas for the crabs to line up when each space moved costs 1.
+// best_alignment searches the positions from the lowest crab to the highest, 0 gas if there are no crabs
+pub fn linear_gas(subs: &[i32]) -> i64 {
+    cheapest_alignment(subs, linear)
+}
+
+// Part 2: the same search, with each space costing one more than the last (triangular)
+pub fn exponential_gas(subs: &[i32]) -> i64 {
+    cheapest_alignment(subs, triangular)
+}
+
+pub fn read_input(path: &str) -> Result<Vec<i32>> {
+    let input = error::read_input(path)?;
+    parse_input(&input)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i32>> {
+    error::lines(input).filter(|line| !line.text.is_empty())
+        .flat_map(|line| line.text.split(",").map(move |x| x.parse().map_err(|_| line.error_in("day7", x, "expected a position"))))
+        .collect()
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Input = Vec<i32>;
+    type Output = i64;
+
+    const TITLE: &'static str = "The Treachery of Whales";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day7/subs.txt";
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        parse_input(input)
+    }
+
+    fn solve_part1(subs: &Vec<i32>) -> i64 {
+        linear_gas(subs)
+    }
+
+    fn solve_part2(subs: &Vec<i32>) -> i64 {
+        exponential_gas(subs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_calc() {
+        let subs = vec![16,1,2,0,4,2,7,1,2,14];
+        assert_eq!(37, total_cost(&subs, 2, linear));
+        assert_eq!(41, total_cost(&subs, 1, linear));
+        assert_eq!(71, total_cost(&subs, 10, linear));
+    }
+
+    #[test]
+    fn test_gas_exp() {
+        let subs = vec![16,1,2,0,4,2,7,1,2,14];
+        assert_eq!(206, total_cost(&subs, 2, triangular));
+        assert_eq!(168, total_cost(&subs, 5, triangular));
+    }
+
+    #[test]
+    fn test_cheapest_gas() {
+        let subs = vec![16,1,2,0,4,2,7,1,2,14];
+        assert_eq!(37, linear_gas(&subs));
+    }
+
+    #[test]
+    fn test_cheapest_exp() {
+        let subs = vec![16,1,2,0,4,2,7,1,2,14];
+        assert_eq!(168, exponential_gas(&subs));
+    }
+
+    // Every position, to check the ternary search against
+    fn scan_alignment(subs: &[i32], cost: impl Fn(i32) -> i64 + Sync) -> (i32, i64) {
+        let (min, max) = (*subs.iter().min().unwrap(), *subs.iter().max().unwrap());
+        (min..=max).map(|position| (position, total_cost(subs, position, &cost))).min_by_key(|&(_, gas)| gas).unwrap()
+    }
+
+    #[test]
+    fn test_against_scan() {
+        // a simple random number generator, so the test is the same every time
+        let mut seed: u32 = 7;
+        let mut random = |limit: u32| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) % limit
+        };
+        let costs: [fn(i32) -> i64; 4] = [linear, triangular, |distance| i64::from((distance - 3).max(0)), |distance| i64::from(distance * distance * 2)];
+        for _ in 0..50 {
+            let crabs = 1 + random(30);
+            let spread = 1 + random(200);
+            let subs: Vec<i32> = (0..crabs).map(|_| random(spread) as i32).collect();
+            for cost in costs {
+                let (position, gas) = scan_alignment(&subs, cost);
+                assert_eq!((position, gas), best_alignment(&subs, cost).unwrap(), "{:?}", subs);
+                assert_eq!((position, gas), best_fleet_alignment(&fleet(&subs), cost).unwrap(), "{:?}", subs);
+            }
+        }
+    }
+
+    #[test]
+    fn test_many_crabs() {
+        // enough crabs to be split into chunks with the parallel feature
+        let mut seed: u32 = 11;
+        let subs: Vec<i32> = (0..100_000).map(|_| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            ((seed >> 16) % 50) as i32
+        }).collect();
+        for cost in [linear, triangular] {
+            let (position, gas) = scan_alignment(&subs, cost);
+            assert_eq!(Some((position, gas)), best_alignment(&subs, cost));
+            assert_eq!(Some((position, gas)), best_fleet_alignment(&fleet(&subs), cost));
+        }
+    }
+
+    #[test]
+    fn test_gas_past_i32() {
+        // 2 million crabs spread over 0 to 1999, more gas than an i32 holds
+        let subs: Vec<i32> = (0..2_000_000).map(|crab| crab % 2000).collect();
+        assert_eq!(Some((999, 1_000_000_000)), best_alignment(&subs, linear));
+        let (position, gas) = best_alignment(&subs, triangular).unwrap();
+        assert_eq!(999, position);
+        assert_eq!(total_cost(&subs, 999, triangular), gas);
+        assert!(gas > i64::from(i32::MAX));
+        assert_eq!(gas, exponential_gas(&subs));
+    }
+
+    #[test]
+    fn test_best_alignment() {
+        let subs = vec![16,1,2,0,4,2,7,1,2,14];
+        assert_eq!(Some((2, 37)), best_alignment(&subs, linear));
+        assert_eq!(Some((5, 168)), best_alignment(&subs, triangular));
+        // anywhere from 0 to 10 costs 10, the lowest is picked
+        assert_eq!(Some((0, 10)), best_alignment(&[10, 0], linear));
+        // 3 and 4 both cost 3
+        assert_eq!(Some((3, 3)), best_alignment(&[3, 3, 4, 5], linear));
+        assert_eq!(None, best_alignment(&[], linear));
+    }
+
+    #[test]
+    fn test_fleet() {
+        let subs = vec![16,1,2,0,4,2,7,1,2,14];
+        let fleet = fleet(&subs);
+        assert_eq!(vec![(0, 1), (1, 2), (2, 3), (4, 1), (7, 1), (14, 1), (16, 1)], fleet);
+        assert_eq!(206, fleet_cost(&fleet, 2, triangular));
+        assert_eq!(Some((2, 37)), best_fleet_alignment(&fleet, linear));
+        assert_eq!(Some((5, 168)), best_fleet_alignment(&fleet, triangular));
+        // unsorted, repeated and empty positions
+        assert_eq!(Some((2, 37)), best_fleet_alignment(&[(16, 1), (2, 2), (0, 1), (1, 2), (4, 1), (100, 0), (7, 1), (2, 1), (14, 1)], linear));
+        assert_eq!(None, best_fleet_alignment(&[(3, 0)], linear));
+        assert_eq!(None, best_fleet_alignment(&[], linear));
+    }
+
+    #[test]
+    fn test_huge_fleet() {
+        // far more crabs than would fit in a Vec, or than an i32 of gas could pay for
+        let fleet = [(0, 1_000_000_000), (10, 3_000_000_000), (25, 2_000_000_000)];
+        assert_eq!(Some((10, 40_000_000_000)), best_fleet_alignment(&fleet, linear));
+        let (position, gas) = best_fleet_alignment(&fleet, triangular).unwrap();
+        assert_eq!((0..=25).map(|position| fleet_cost(&fleet, position, triangular)).min(), Some(gas));
+        assert_eq!(gas, fleet_cost(&fleet, position, triangular));
+    }
+
+    #[test]
+    fn test_custom_cost() {
+        let subs = vec![16,1,2,0,4,2,7,1,2,14];
+        // moving is free up to 3 spaces, so at 4 only the crabs at 0, 14 and 16 pay
+        assert_eq!(1 + 7 + 9, cheapest_alignment(&subs, |distance| i64::from((distance - 3).max(0))));
+        assert_eq!(0, cheapest_alignment(&[], linear));
+        assert_eq!(0, cheapest_alignment(&[5], triangular));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,605 @@
+/*
+Day 8: Seven Segment Search
+
+The seven segment displays (number segments) are out of order.
+Normally a 7 segment number is defined by:
+ aaaa
+b    c
+b    c
+ dddd
+e    f
+e    f
+ gggg
+
+Normally a 7 would be defined as "acf", but the segments are out of order and random.
+Given an input like: 
+acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf
+The first values are observed, the values after the | are what we need to decode.
+
+Part 1: We can determine some digits by length (1 = 2, 7 = 3, 4 = 4, 8 = 7).
+Return the number of times the digits 1, 4, 7, and 8 appear.
+
+Part 2: Figure out the random order for each line of the input.
+The output values are to the right side of the |
+so "cdfeb fcadb cdfeb cdbaf" would be 5353
+Add up all the outupt numbers
+*/
+
+use std::str::FromStr;
+use crate::error::{self, AdventError, PartResult, Result};
+use crate::grid::Position;
+use crate::solution::Solver;
+use crate::viz::{Render, Rgb};
+
+#[derive(Debug)]
+pub struct SevenSegmentData {
+    training: Vec<String>,
+    output: Vec<String>
+}
+
+// Part 1
+pub fn count_known_values(data: &[SevenSegmentData]) -> usize {
+    data.iter()
+        .flat_map(|d| d.output.iter())
+        .filter(|digit| digit.len() == 2 || digit.len() == 3 || digit.len() == 4 || digit.len() == 7 )
+        .count()
+}
+
+/**
+ * Part 2: work out which pattern is which digit from how the patterns overlap (see deduce)
+ *      and read off the output digits by finding each one in the patterns.
+ * Started out mostly brute force, which is in the tests to check this against. The README compares the two.
+ * Panics if a line's patterns aren't the ten digits of a seven segment display.
+ */
+pub fn decode_values(segment_data: &[SevenSegmentData]) -> i32 {
+    decode_values_with(segment_data, Decoder::Deduction)
+}
+
+// The two ways of working out the output digits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decoder {
+    // Which pattern is which digit from how they overlap, see deduce
+    Deduction,
+    // Each digit from how often its segments are used, see frequency_output
+    Frequency
+}
+
+// decode_values, picking the decoder
+pub fn decode_values_with(segment_data: &[SevenSegmentData], decoder: Decoder) -> i32 {
+    segment_data.iter()
+        .map(|data| decode_output_with(data, decoder).unwrap_or_else(|| panic!("the patterns {:?} aren't the ten digits", data.training)))
+        .sum()
+}
+
+pub fn decode_output_with(data: &SevenSegmentData, decoder: Decoder) -> Option<i32> {
+    match decoder {
+        Decoder::Deduction => decode_output(data),
+        Decoder::Frequency => frequency_output(data)
+    }
+}
+
+// The 4 output digits as a number, None if the patterns can't be worked out
+pub fn decode_output(data: &SevenSegmentData) -> Option<i32> {
+    decode(data).map(|decoded| decoded.value())
+}
+
+// One line worked out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    // The segment each wire lights up, wire a first, so ['c', ..] means wire a is segment c
+    pub mapping: [char; 7],
+    // The output digits, in order
+    pub digits: Vec<u8>
+}
+
+impl Decoded {
+    // The output digits as one number, like 5353
+    pub fn value(&self) -> i32 {
+        self.digits.iter().fold(0, |number, &digit| number * 10 + i32::from(digit))
+    }
+}
+
+// Every line worked out, None for a line whose patterns aren't the ten digits
+pub fn decode_entries(segment_data: &[SevenSegmentData]) -> Vec<Option<Decoded>> {
+    segment_data.iter().map(decode).collect()
+}
+
+/**
+ * One line, with the wiring as well as the output digits.
+ * Once deduce knows which pattern is which digit, each wire is the segment that's in exactly the same digits:
+ *      segment a is in 0, 2, 3, 5, 6, 7, 8 and 9, so the wire in those patterns and no others is wired to a.
+ * None if the patterns aren't the ten digits or an output digit isn't one of them.
+ */
+pub fn decode(data: &SevenSegmentData) -> Option<Decoded> {
+    let digits = deduce(&data.training)?;
+    let standard = DIGITS.map(wires);
+    // the digits a wire (or segment) is in, as bits
+    let in_digits = |patterns: &[u8; 10], wire: u8| (0..10)
+        .filter(|&digit| patterns[digit] & 1 << wire != 0)
+        .fold(0u16, |set, digit| set | 1 << digit);
+    let mut mapping = ['a'; 7];
+    for wire in 0..7 {
+        let segment = (0..7).find(|&segment| in_digits(&standard, segment) == in_digits(&digits, wire))?;
+        mapping[wire as usize] = (b'a' + segment) as char;
+    }
+    let output = data.output.iter()
+        .map(|value| digits.iter().position(|&pattern| pattern == wires(value)).map(|digit| digit as u8))
+        .collect::<Option<_>>()?;
+    Some(Decoded { mapping, digits: output })
+}
+
+/**
+ * Which pattern is each digit, as the wires it lights up (see wires), so digits[3] is the pattern for 3.
+ *      1, 4, 7 and 8 are the only digits with 2, 4, 3 and 7 segments.
+ *      0, 6 and 9 have 6 segments. Only 9 has all of 4's segments, and of the other two only 0 has all of 1's.
+ *      2, 3 and 5 have 5 segments. Only 3 has all of 1's segments, and of the other two 5 shares 3 with 4 and 2 only 2.
+ * The letters are scrambled but the overlaps aren't, so the same rules work on the scrambled patterns.
+ * None if the patterns aren't the ten digits.
+ */
+pub fn deduce(training: &[String]) -> Option<[u8; 10]> {
+    let patterns: Vec<u8> = training.iter().map(|pattern| wires(pattern)).collect();
+    let with_length = |length| patterns.iter().copied().filter(move |pattern| pattern.count_ones() == length);
+    let only = |length| {
+        let mut found = with_length(length);
+        found.next().filter(|_| found.next().is_none())
+    };
+    let mut digits = [0u8; 10];
+    (digits[1], digits[4], digits[7], digits[8]) = (only(2)?, only(4)?, only(3)?, only(7)?);
+    let has_all = |pattern: u8, digit: u8| pattern & digit == digit;
+    for pattern in with_length(6) {
+        let digit = if has_all(pattern, digits[4]) { 9 } else if has_all(pattern, digits[1]) { 0 } else { 6 };
+        digits[digit] = pattern;
+    }
+    for pattern in with_length(5) {
+        let digit = if has_all(pattern, digits[1]) { 3 } else if (pattern & digits[4]).count_ones() == 3 { 5 } else { 2 };
+        digits[digit] = pattern;
+    }
+    // every digit has to be found exactly once
+    let mut found = digits.to_vec();
+    found.sort_unstable();
+    found.dedup();
+    (patterns.len() == 10 && found.len() == 10 && found[0] != 0).then_some(digits)
+}
+
+// The segments of each digit on a display that isn't scrambled, DIGITS[d] for digit d
+const DIGITS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+/**
+ * Every line's output digits drawn back on seven segment displays, for --viz.
+ * Each output pattern goes through the line's mapping to get the segments it lights,
+ * so a wrong mapping shows up as something that isn't a digit. A line that can't be decoded is left dark.
+ * As a picture each digit is 4 cells across and 7 down, with a gap after each digit and each line.
+ * As text it's ASCII art, 3 characters by 3 lines a digit:
+ *   _       _
+ *  |_    | _|
+ *   _|   | _|
+ */
+pub struct Displays {
+    // The segments lit on each display (bit 0 for a up to bit 6 for g), a line's 4 digits at a time
+    lines: Vec<Vec<u8>>
+}
+
+// Digits are this big, with the gaps
+const DISPLAY_WIDTH: usize = 5;
+const DISPLAY_HEIGHT: usize = 8;
+
+pub fn draw_displays(segment_data: &[SevenSegmentData]) -> Displays {
+    let lines = segment_data.iter().map(|data| match decode(data) {
+        Some(decoded) => data.output.iter()
+            .map(|value| value.bytes().fold(0, |segments, wire| segments | 1 << (decoded.mapping[(wire - b'a') as usize] as u8 - b'a')))
+            .collect(),
+        None => vec![0; data.output.len()]
+    }).collect();
+    Displays { lines }
+}
+
+impl Displays {
+    // The segment (0 for a up to 6 for g) a cell of a digit is part of, if any
+    fn segment_at(row: usize, col: usize) -> Option<u8> {
+        let side = |left, right| match col {
+            0 => Some(left),
+            3 => Some(right),
+            _ => None
+        };
+        match row {
+            0 | 3 | 6 if (1..=2).contains(&col) => Some(row as u8 / 3 * 3),
+            1 | 2 => side(1, 2),
+            4 | 5 => side(4, 5),
+            _ => None
+        }
+    }
+}
+
+impl Render for Displays {
+    fn size(&self) -> (usize, usize) {
+        let digits = self.lines.iter().map(Vec::len).max().unwrap_or(0);
+        ((digits * DISPLAY_WIDTH).saturating_sub(1), (self.lines.len() * DISPLAY_HEIGHT).saturating_sub(1))
+    }
+
+    fn color(&self, (row, col): Position) -> Rgb {
+        let display = self.lines[row / DISPLAY_HEIGHT].get(col / DISPLAY_WIDTH);
+        let segment = Displays::segment_at(row % DISPLAY_HEIGHT, col % DISPLAY_WIDTH);
+        match (display, segment) {
+            (Some(segments), Some(segment)) if segments & 1 << segment != 0 => Rgb(255, 40, 40),
+            _ => Rgb::BLACK
+        }
+    }
+
+    // A line of ASCII art displays for each line of the input, with a blank line between them
+    fn text(&self) -> Option<String> {
+        let lit = |segments: u8, segment: u8, c: char| if segments & 1 << segment != 0 { c } else { ' ' };
+        let rows: Vec<String> = self.lines.iter().map(|displays| {
+            let row = |draw: &dyn Fn(u8) -> String| displays.iter().map(|&segments| draw(segments)).collect::<Vec<_>>().join(" ");
+            [
+                row(&|s| format!(" {} ", lit(s, 0, '_'))),
+                row(&|s| format!("{}{}{}", lit(s, 1, '|'), lit(s, 3, '_'), lit(s, 2, '|'))),
+                row(&|s| format!("{}{}{}", lit(s, 4, '|'), lit(s, 6, '_'), lit(s, 5, '|')))
+            ].map(|line| line.trim_end().to_string()).join("\n")
+        }).collect();
+        Some(rows.join("\n\n") + "\n")
+    }
+}
+
+// Across the ten digits segment a is lit 8 times, b 6, c 8, d 7, e 4, f 9 and g 7.
+// Adding those up for each segment of a digit gives a different total for every digit, SIGNATURES[d] for digit d
+const SIGNATURES: [u32; 10] = [42, 17, 34, 39, 30, 37, 41, 25, 49, 45];
+
+/**
+ * The output digits without working out the patterns at all.
+ *      The training patterns are each digit once, so counting how often each wire is used in them
+ *      gives how often its segment is used, whichever segment it is.
+ *      Each output digit is then the one whose signature is the total of its wires' counts.
+ * A count per wire and a sum per digit, so no sets or searching. None if a total isn't any digit's,
+ * but unlike deduce, patterns that aren't the ten digits can give a wrong number rather than None.
+ */
+pub fn frequency_output(data: &SevenSegmentData) -> Option<i32> {
+    let mut counts = [0u32; 7];
+    for wire in data.training.iter().flat_map(|pattern| pattern.bytes()) {
+        counts[(wire - b'a') as usize] += 1;
+    }
+    data.output.iter().try_fold(0, |number, value| {
+        let signature: u32 = value.bytes().map(|wire| counts[(wire - b'a') as usize]).sum();
+        let digit = SIGNATURES.iter().position(|&total| total == signature)?;
+        Some(number * 10 + digit as i32)
+    })
+}
+
+// A pattern as a set of wires, bit 0 for a up to bit 6 for g, so the order of the letters doesn't matter
+fn wires(pattern: &str) -> u8 {
+    pattern.bytes().fold(0, |wires, wire| wires | 1 << (wire - b'a'))
+}
+
+pub fn read_data(path: &str) -> Result<Vec<SevenSegmentData>> {
+    let data = error::read_input(path)?;
+    parse_data(&data)
+}
+
+// 10 patterns, then a |, then the 4 output digits. Segments are the letters a to g
+pub fn parse_data(data: &str) -> Result<Vec<SevenSegmentData>> {
+    error::lines(data)
+        .map(|line| line.parse_with("day8", split_entry))
+        .collect()
+}
+
+// A single display's entry, like "acedgfb cdfbe ... | cdfeb fcadb cdfeb cdbaf"
+impl FromStr for SevenSegmentData {
+    type Err = AdventError;
+
+    fn from_str(text: &str) -> Result<SevenSegmentData> {
+        error::parse_text("day8", text, split_entry)
+    }
+}
+
+// Splits at the |, the patterns on each side are checked by split_patterns
+fn split_entry(text: &str) -> PartResult<'_, SevenSegmentData> {
+    let (training, output) = text.split_once('|').ok_or((text, "expected 10 patterns, a |, then 4 digits".to_string()))?;
+    Ok(SevenSegmentData {
+        training: split_patterns(training, 10, "patterns before the |")?,
+        output: split_patterns(output, 4, "output digits after the |")?
+    })
+}
+
+// count patterns separated by spaces, each a set of segments with no letter twice.
+// A wrong count points at the whole side of the |, a bad segment at just that letter
+fn split_patterns<'a>(text: &'a str, count: usize, what: &str) -> PartResult<'a, Vec<String>> {
+    let patterns: Vec<&str> = text.split_whitespace().collect();
+    if patterns.len() != count {
+        let part = if text.trim().is_empty() { text } else { text.trim() };
+        return Err((part, format!("expected {} {}, found {}", count, what, patterns.len())));
+    }
+    for pattern in &patterns {
+        if let Some((i, c)) = pattern.char_indices().find(|&(_, c)| !('a'..='g').contains(&c)) {
+            return Err((&pattern[i..i + c.len_utf8()], "segments are the letters a to g".to_string()));
+        }
+        if let Some((i, c)) = pattern.char_indices().find(|&(i, c)| pattern[..i].contains(c)) {
+            return Err((&pattern[i..i + 1], format!("segment {} is in {} twice", c, pattern)));
+        }
+    }
+    Ok(patterns.into_iter().map(String::from).collect())
+}
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Input = Vec<SevenSegmentData>;
+    type Output = usize;
+
+    const TITLE: &'static str = "Seven Segment Search";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day8/segments.txt";
+
+    fn parse(input: &str) -> Result<Vec<SevenSegmentData>> {
+        parse_data(input)
+    }
+
+    fn solve_part1(segments: &Vec<SevenSegmentData>) -> usize {
+        count_known_values(segments)
+    }
+
+    fn solve_part2(segments: &Vec<SevenSegmentData>) -> usize {
+        decode_values(segments) as usize
+    }
+
+    fn render(segments: &Vec<SevenSegmentData>) -> Option<Box<dyn Render>> {
+        Some(Box::new(draw_displays(segments)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::collections::HashMap;
+    use super::*;
+
+    // Part 2: mostly brute force (350ms). See inline comments
+    // future note: a better way to do this is to define each number as sub and super sets:
+    //      for example, 3 is a superset of 7 with length 5
+    //      9 is a superset of 3 with length 6 (etc)
+    fn brute_force_values(segment_data: &[SevenSegmentData]) -> i32 {
+        // Define the valid seven segment rules
+        let mut digit_map: HashMap<&str, &str> = HashMap::new();
+        digit_map.insert("abcefg", "0");
+        digit_map.insert("cf", "1");
+        digit_map.insert("acdeg", "2");
+        digit_map.insert("acdfg", "3");
+        digit_map.insert("bcdf", "4");
+        digit_map.insert("abdfg", "5");
+        digit_map.insert("abdefg", "6");
+        digit_map.insert("acf", "7");
+        digit_map.insert("abcdefg", "8");
+        digit_map.insert("abcdfg", "9");
+
+        // One at a time, with a counter. So far so good.
+        let mut result = 0;
+        for data in segment_data {
+
+            // sort the scrambled codes by length - hit the easy ones first.
+            let mut training_data: Vec<&String> = data.training.iter().chain(data.output.iter()).collect();
+            training_data.sort_by_key(|s| s.len());
+
+            // Try to map the scrambled digit to a set of possible real positions it could occupy
+            // start with all possibilities and narrow down as we go
+            let mut decoder: HashMap<char, HashSet<char>> = "abcdefg".chars()
+                .fold(HashMap::new(), |mut map, c| {
+                    map.insert(c, "abcdefg".chars().collect());
+                    map
+                });
+
+            for training in &training_data {
+                // Get all the digits that our scrambled character might map to, based on the length of the value.
+                // Do a set intersection to narrow down the potential values for each scrambled digit
+                let possible_digits: HashSet<char> = digit_map.keys()
+                    .filter(|key| key.len() == training.len())
+                    .flat_map(|digit| digit.chars())
+                    .collect();
+                for random_char in training.chars() {
+                    let v = decoder.get(&random_char).unwrap();
+                    // set intersection is an interator on references
+                    // annoyingly, have to dereferenc in order to re-assign the set
+                    *decoder.get_mut(&random_char).unwrap() = v.intersection(&possible_digits).copied().collect();
+                }
+            }
+
+            // Attempts to do smarter rules based logical deductions ended in failure and frustration
+            // Let's brute force this bad boy
+            let possible_solutions = Vec::from_iter(decoder.get(&'a').unwrap().iter().map(|c| c.to_string()));
+            let possible_solutions: Vec<String> = "bcdefg".chars().fold(possible_solutions, |sol, c| {
+                let vals = decoder.get(&c).unwrap();
+                if vals.len() == 1 {
+                    sol.into_iter().map(|val| format!("{}{}", val, c)).collect()
+                } else {
+                    vals.iter().map(|v|
+                        sol.iter()
+                            .filter(|prev| !prev.contains(&v.to_string()))
+                            .map(|prev| format!("{}{}", prev, v))
+                            .collect::<Vec<_>>()
+                    ).flat_map(|nest| nest.into_iter()).collect()
+                }
+            });
+            // Above is a lot of mapping and flat mapping to get a complete enumeration of possible solutions
+            // Now we try out each solution until we find one that doesn't violate the seven segment rules
+            for solution in possible_solutions {
+                if solution.len() != 7 {
+                    continue;
+                }
+                // this line is maybe the only cool thing I did for this entire problem
+                let decoder: HashMap<char, char> = ('a'..='g').zip(solution.chars()).collect();
+                let mut valid_solution = true;
+                for test_value in &training_data {
+                    let mut decoded: Vec<&char> = test_value.chars()
+                        .map(|c| decoder.get(&c).unwrap())
+                        .collect();
+                    decoded.sort();
+                    let decoded: String = decoded.into_iter().collect();
+                    if !digit_map.contains_key(&decoded[..]) {
+                        // Decodes to something that's not a digit - try the next solution
+                        valid_solution = false;
+                        break;
+                    }
+                }
+                if valid_solution {
+                    // We did it! Lets spend 12 lines turning a string into a number
+                    let number: String = data.output.iter().map(|value| {
+                            let mut decoded: Vec<&char> = value.chars()
+                                .map(|c| decoder.get(&c).unwrap())
+                                .collect();
+                            decoded.sort();
+                            let decoded: String = decoded.into_iter().collect();
+                            *digit_map.get(&decoded[..]).unwrap()
+                        })
+                        .collect();
+                    result += number.parse::<i32>().unwrap();
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+
+    fn test_data() -> Vec<SevenSegmentData> {
+        let data = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+            edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+            fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+            fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+            aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+            fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+            dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+            bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+            egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+            gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+        parse_data(data).unwrap()
+    }
+
+    #[test]
+    fn test_known() {
+        let data = test_data();
+        assert_eq!(26, count_known_values(&data));
+    }
+
+    #[test]
+    fn test_sum_decoded() {
+        let data = test_data();
+        assert_eq!(61229, decode_values(&data));
+    }
+
+    #[test]
+    fn test_deduce() {
+        let data = parse_data("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf").unwrap();
+        let digits = deduce(&data[0].training).unwrap();
+        let expected = ["cagedb", "ab", "gcdfa", "fbcad", "eafb", "cdfbe", "cdfgeb", "dab", "acedgfb", "cefabd"];
+        assert_eq!(expected.map(wires), digits);
+        assert_eq!(Some(5353), decode_output(&data[0]));
+    }
+
+    #[test]
+    fn test_decode() {
+        let data = parse_data("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf").unwrap();
+        // the wiring from the puzzle description: d is a, e is b, a is c, f is d, g is e, b is f and c is g
+        let expected = Decoded { mapping: ['c', 'f', 'g', 'a', 'b', 'd', 'e'], digits: vec![5, 3, 5, 3] };
+        assert_eq!(Some(expected), decode(&data[0]));
+
+        let decoded = decode_entries(&test_data());
+        assert_eq!(vec![8394, 9781, 1197, 9361, 4873, 8418, 4548, 1625, 8717, 4315],
+            decoded.iter().map(|decoded| decoded.as_ref().unwrap().value()).collect::<Vec<_>>());
+        // unscrambling every pattern with the mapping gives a digit
+        for (data, decoded) in test_data().iter().zip(decoded) {
+            let mapping = decoded.unwrap().mapping;
+            for pattern in &data.training {
+                let mut segments: Vec<char> = pattern.bytes().map(|wire| mapping[(wire - b'a') as usize]).collect();
+                segments.sort_unstable();
+                assert!(DIGITS.contains(&segments.into_iter().collect::<String>().as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |text: &str| match parse_data(text) {
+            Err(AdventError::Parse { detail, context: Some(context), .. }) => (context.text, detail),
+            other => panic!("expected a parse error, got {:?}", other)
+        };
+        let line = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab";
+        assert_eq!("expected 10 patterns, a |, then 4 digits", error(line).1);
+        assert_eq!("expected 10 patterns before the |, found 9", error("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb | a b c d").1);
+        assert_eq!("expected 4 output digits after the |, found 3", error(&format!("{} | cdfeb fcadb cdfeb", line)).1);
+        assert_eq!("expected 4 output digits after the |, found 0", error(&format!("{} |", line)).1);
+        assert_eq!("segments are the letters a to g", error(&format!("{} | cdfeb fcadb cdfeh cdbaf", line)).1);
+        assert_eq!("segments are the letters a to g", error(&format!("{} | cdfeb fcadb |cdfeb cdbaf", line)).1);
+        assert_eq!("segment d is in cdfdb twice", error(&format!("{} | cdfdb fcadb cdfeb cdbaf", line)).1);
+        // the line number and text come along with the error
+        assert_eq!(format!("{} | ab", line), error(&format!("{} | ab cd ef ab\n{} | ab", line, line)).0);
+
+        let data: SevenSegmentData = format!("  {} | cdfeb fcadb cdfeb cdbaf ", line).parse().unwrap();
+        assert_eq!(Some(5353), decode_output(&data));
+        assert!(matches!("ab | cd".parse::<SevenSegmentData>(), Err(AdventError::Parse { context: None, .. })));
+    }
+
+    #[test]
+    fn test_draw_displays() {
+        let data = parse_data("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf
+            acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | acedgfb cagedb ab eafb").unwrap();
+        let displays = draw_displays(&data);
+        let art = concat!(
+            " _   _   _   _\n",
+            "|_   _| |_   _|\n",
+            " _|  _|  _|  _|\n",
+            "\n",
+            " _   _\n",
+            "|_| | |   | |_|\n",
+            "|_| |_|   |   |\n");
+        assert_eq!(Some(art.to_string()), displays.text());
+
+        assert_eq!((19, 15), displays.size());
+        let red = Rgb(255, 40, 40);
+        // the top of the 5, and its top right that isn't lit
+        assert_eq!(red, displays.color((0, 1)));
+        assert_eq!(Rgb::BLACK, displays.color((1, 3)));
+        // the gaps between digits and lines
+        assert_eq!(Rgb::BLACK, displays.color((0, 4)));
+        assert_eq!(Rgb::BLACK, displays.color((7, 1)));
+        // the 8 on the second line has every segment
+        let eight = (8..15).flat_map(|row| (0..4).map(move |col| (row, col)))
+            .filter(|&position| displays.color(position) == red).count();
+        assert_eq!(14, eight);
+    }
+
+    #[test]
+    fn test_not_digits() {
+        // two patterns the same, so there's no 9
+        let data = parse_data("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cdfgeb ab | cdfeb fcadb cdfeb cdbaf").unwrap();
+        assert_eq!(None, deduce(&data[0].training));
+        // an output digit that isn't one of the patterns
+        let data = parse_data("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdb").unwrap();
+        assert_eq!(None, decode_output(&data[0]));
+    }
+
+    #[test]
+    fn test_signatures() {
+        // the unscrambled digits are their own training patterns
+        let training: Vec<String> = DIGITS.iter().map(|digit| digit.to_string()).collect();
+        for (digit, pattern) in DIGITS.iter().enumerate() {
+            let data = SevenSegmentData { training: training.clone(), output: vec![pattern.to_string()] };
+            assert_eq!(Some(digit as i32), frequency_output(&data));
+        }
+    }
+
+    #[test]
+    fn test_frequency_decoder() {
+        let data = parse_data("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf").unwrap();
+        assert_eq!(Some(5353), frequency_output(&data[0]));
+        let data = test_data();
+        assert_eq!(61229, decode_values_with(&data, Decoder::Frequency));
+        let data = read_data("src/year2021/day8/segments.txt").unwrap();
+        for entry in &data {
+            assert_eq!(decode_output(entry), decode_output_with(entry, Decoder::Frequency));
+        }
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let data = test_data();
+        assert_eq!(brute_force_values(&data), decode_values(&data));
+        let data = read_data("src/year2021/day8/segments.txt").unwrap();
+        assert_eq!(brute_force_values(&data), decode_values(&data));
+    }
+}
@@ -0,0 +1,297 @@
+/*
+Day 9: Smoke Basin
+
+Volcanic smoke collects at low points in the depths.
+The puzzle input is a 2d grid of depths, with 0 the lowest and 9 the highest.
+
+Part 1: Find the low points in the depth, defined as a point where all adjacent
+spaces have a higher value (do not include diagonals).
+A risk level is 1 + the spaces height. Return the sum of the risk of all the low points.
+
+Part 2: A basin is all spaces that flow downward to a single low point.
+A 9 does not count as part of a basin.
+Find the 3 largest basisns and return their sizes multiplied together.
+*/
+use std::collections::HashSet;
+use crate::algorithms;
+use crate::answer::Answer;
+use crate::grid::{Grid, Position};
+use crate::error::{self, AdventError, Result};
+use crate::solution::Solver;
+use crate::viz::{Render, Rgb};
+
+// Part 1 - used a lot of helper methods to share code between parts
+// Find the low points, add 1, then sum the values
+pub fn count_low_points(grid: &Grid<i32>) -> i32 {
+    find_low_points(grid).iter()
+        .map(|&point| grid[point] + 1)
+        .sum()
+}
+
+// Start from the low points, and each low point defines a unique basin
+// (we are assuming this is true, and it is true for this problem)
+// Flood fill outward from each point to find the basin (see basin_map)
+// Once all basins are defined, count the length and multiply the 3 highest
+// A grid with fewer than 3 basins has no product, the answer says how many there are instead
+pub fn find_basins(grid: &Grid<i32>) -> Answer {
+    let sizes = sorted_basin_sizes(grid);
+    match largest_basins(&sizes, 3) {
+        Some(product) => product.into(),
+        None => format!("part 2 needs 3 basins, there are {}", sizes.len()).into()
+    }
+}
+
+// The size of every basin, biggest first
+pub fn sorted_basin_sizes(grid: &Grid<i32>) -> Vec<usize> {
+    let mut lengths = basin_sizes(&basin_map(grid));
+    lengths.sort_unstable_by(|a, b| b.cmp(a));
+    lengths
+}
+
+// The k biggest basins' sizes multiplied together, from sorted_basin_sizes.
+// None if there are fewer than k basins
+pub fn largest_basins(sorted_sizes: &[usize], k: usize) -> Option<usize> {
+    sorted_sizes.get(..k).map(|largest| largest.iter().product())
+}
+
+// Which basin each space is in, None for the 9s. The basins are numbered from 0
+// in the order of their low points, row by row. If two low points flow into the same basin
+// (not in the puzzle input), the basin is numbered for the first and the second doesn't get a number
+pub fn basin_map(grid: &Grid<i32>) -> Grid<Option<usize>> {
+    let mut map = Grid::filled(grid.width(), grid.height(), None);
+    let mut basins = 0;
+    for low_point in find_low_points(grid) {
+        if map[low_point].is_some() {
+            continue;
+        }
+        // the basin spreads to every adjacent space that is not a 9 (highest possible hight)
+        let basin: HashSet<Position> = algorithms::flood_fill(low_point, |&space| grid.neighbors4(space), |&space| grid[space] != 9);
+        for space in basin {
+            map[space] = Some(basins);
+        }
+        basins += 1;
+    }
+    map
+}
+
+// How many spaces are in each basin of a basin_map, by basin number
+pub fn basin_sizes(map: &Grid<Option<usize>>) -> Vec<usize> {
+    let mut sizes = vec![0; map.iter().flatten().max().map_or(0, |&basin| basin + 1)];
+    for &basin in map.iter().flatten() {
+        sizes[basin] += 1;
+    }
+    sizes
+}
+
+// A picture of the basins for --viz: each basin in its own color with its low point marked,
+// and the ridges of 9s that separate them in dark grey
+pub struct Basins {
+    map: Grid<Option<usize>>,
+    low_points: HashSet<Position>
+}
+
+const RIDGE: Rgb = Rgb(48, 48, 48);
+
+pub fn draw_basins(grid: &Grid<i32>) -> Basins {
+    Basins { map: basin_map(grid), low_points: find_low_points(grid).into_iter().collect() }
+}
+
+// Basins next to each other in the numbering get hues far apart on the color wheel,
+// going round by the golden angle each time so the colors don't repeat
+fn basin_color(basin: usize) -> Rgb {
+    let hue = (basin as f64 * 137.508) % 360.0 / 60.0;
+    let (value, saturation) = (0.9, 0.65);
+    let channel = |n: f64| {
+        let k = (n + hue) % 6.0;
+        let level = value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0);
+        (level * 255.0).round() as u8
+    };
+    Rgb(channel(5.0), channel(3.0), channel(1.0))
+}
+
+impl Render for Basins {
+    fn size(&self) -> (usize, usize) {
+        (self.map.width(), self.map.height())
+    }
+
+    // Low points are white
+    fn color(&self, position: Position) -> Rgb {
+        match self.map[position] {
+            _ if self.low_points.contains(&position) => Rgb::WHITE,
+            Some(basin) => basin_color(basin),
+            None => RIDGE
+        }
+    }
+
+    fn background(&self) -> Rgb {
+        RIDGE
+    }
+
+    // A letter for each basin (going round a to z then A to Z), * for the low points and # for the ridges
+    fn text(&self) -> Option<String> {
+        const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let mut text = String::new();
+        for (row, spaces) in self.map.rows().enumerate() {
+            for (col, basin) in spaces.iter().enumerate() {
+                text.push(match basin {
+                    _ if self.low_points.contains(&(row, col)) => '*',
+                    Some(basin) => LETTERS[basin % LETTERS.len()] as char,
+                    None => '#'
+                });
+            }
+            text.push('\n');
+        }
+        Some(text)
+    }
+}
+
+// Look through every space on the grid
+    // find the adjacent spaces (not diagonals)
+    // if all adjacent spaces have a higher value than the current space
+        // add the current space to a list as a tuple (row, col)
+fn find_low_points(grid: &Grid<i32>) -> Vec<Position> {
+    grid.positions()
+        .filter(|&point| grid.neighbors4(point).all(|adjacent| grid[adjacent] > grid[point]))
+        .collect()
+}
+
+pub fn read_grid(path: &str) -> Result<Grid<i32>> {
+    let input = error::read_input(path)?;
+    parse_input(&input)
+}
+
+// A row of heights from 0 to 9 per line. The grid has to be rectangular, Grid::parse
+// reports the first row that's a different length from the ones above it
+pub fn parse_input(input: &str) -> Result<Grid<i32>> {
+    let grid = Grid::parse("day9", input, |c| c.to_digit(10).map(|height| height as i32))?;
+    if grid.is_empty() {
+        return Err(AdventError::parse("day9", "expected a grid of heights"));
+    }
+    Ok(grid)
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Input = Grid<i32>;
+    type Output = Answer;
+
+    const TITLE: &'static str = "Smoke Basin";
+    const DEFAULT_INPUT: &'static str = "solutions/src/year2021/day9/grid.txt";
+
+    fn parse(input: &str) -> Result<Grid<i32>> {
+        parse_input(input)
+    }
+
+    fn solve_part1(grid: &Grid<i32>) -> Answer {
+        count_low_points(grid).into()
+    }
+
+    fn solve_part2(grid: &Grid<i32>) -> Answer {
+        find_basins(grid)
+    }
+
+    fn render(grid: &Grid<i32>) -> Option<Box<dyn Render>> {
+        Some(Box::new(draw_basins(grid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_data() -> Grid<i32> {
+        let data = "2199943210
+            3987894921
+            9856789892
+            8767896789
+            9899965678";
+        parse_input(data).unwrap()
+    }
+
+    #[test]
+    fn test_low_points() {
+        let data = test_data();
+        assert_eq!(15, count_low_points(&data));
+    }
+
+    #[test]
+    fn test_basin_lengths() {
+        let data = test_data();
+        assert_eq!(Answer::Int(1134), find_basins(&data));
+    }
+
+    #[test]
+    fn test_too_few_basins() {
+        // both 1s are low points, but nothing divides the grid so they share one basin
+        let grid = parse_input("12\n21").unwrap();
+        assert_eq!(vec![4], sorted_basin_sizes(&grid));
+        assert_eq!(Answer::Text("part 2 needs 3 basins, there are 1".to_string()), Day9::solve_part2(&grid));
+        let grid = parse_input("19\n91").unwrap();
+        assert_eq!(Answer::Text("part 2 needs 3 basins, there are 2".to_string()), find_basins(&grid));
+    }
+
+    #[test]
+    fn test_largest_basins() {
+        let sizes = sorted_basin_sizes(&test_data());
+        assert_eq!(Some(14), largest_basins(&sizes, 1));
+        assert_eq!(Some(1134), largest_basins(&sizes, 3));
+        assert_eq!(Some(1134 * 3), largest_basins(&sizes, 4));
+        assert_eq!(None, largest_basins(&sizes, 5));
+        assert_eq!(Some(1), largest_basins(&sizes, 0));
+    }
+
+    #[test]
+    fn test_draw_basins() {
+        let basins = draw_basins(&test_data());
+        let text = "a*###bbbb*\na#ccc#b#bb\n#c*ccc#d#b\nccccc#ddd#\n#c###d*ddd\n";
+        assert_eq!(Some(text.to_string()), basins.text());
+        assert_eq!((10, 5), basins.size());
+        assert_eq!(Rgb::WHITE, basins.color((0, 1)));
+        assert_eq!(RIDGE, basins.color((0, 2)));
+        assert_eq!(basin_color(0), basins.color((0, 0)));
+        // every basin has a color of its own
+        let colors: Vec<Rgb> = (0..20).map(basin_color).collect();
+        for (i, color) in colors.iter().enumerate() {
+            assert!(!colors[..i].contains(color) && *color != Rgb::WHITE && *color != RIDGE);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        match parse_input("2199943210\n3987894921\n985678989\n8767896789") {
+            Err(AdventError::Parse { detail, context: Some(context), .. }) => {
+                assert_eq!(3, context.line);
+                assert_eq!("985678989", context.text);
+                assert_eq!("the row is 9 long, the rows above are 10", detail);
+            }
+            other => panic!("expected a parse error, got {:?}", other)
+        }
+        match parse_input("2199943210\n39878x4921") {
+            Err(AdventError::Parse { detail, context: Some(context), .. }) => {
+                assert_eq!(2, context.line);
+                assert_eq!("unexpected 'x'", detail);
+            }
+            other => panic!("expected a parse error, got {:?}", other)
+        }
+        assert!(matches!(parse_input("\n\n"), Err(AdventError::Parse { context: None, .. })));
+    }
+
+    #[test]
+    fn test_basin_map() {
+        let map = basin_map(&test_data());
+        // the low points are at (0, 1), (0, 9), (2, 2) and (4, 6)
+        assert_eq!(vec![3, 9, 14, 9], basin_sizes(&map));
+        assert_eq!(Some(0), map[(1, 0)]);
+        assert_eq!(None, map[(0, 2)]);
+        assert_eq!(Some(1), map[(0, 5)]);
+        assert_eq!(Some(2), map[(3, 0)]);
+        assert_eq!(Some(3), map[(4, 9)]);
+        // every space is a 9 or in a basin
+        assert!(test_data().enumerate().all(|(position, &height)| (height == 9) == map[position].is_none()));
+        assert_eq!(vec![14, 9, 9, 3], sorted_basin_sizes(&test_data()));
+        // a basin with two low points is one basin
+        let map = basin_map(&parse_input("1291\n2222").unwrap());
+        assert_eq!(vec![7], basin_sizes(&map));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+/*
+Advent of Code 2021, one module per day.
+*/
+
+use crate::solution::{Registry, Solved};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+// Every day of 2021, in order.
+// The name is what gets typed on the command line.
+pub fn registry() -> Registry {
+    vec![
+        ("day1", Box::<Solved<day1::Day1>>::default()),
+        ("day2", Box::<Solved<day2::Day2>>::default()),
+        ("day3", Box::<Solved<day3::Day3>>::default()),
+        ("day4", Box::<Solved<day4::Day4>>::default()),
+        ("day5", Box::<Solved<day5::Day5>>::default()),
+        ("day6", Box::<Solved<day6::Day6>>::default()),
+        ("day7", Box::<Solved<day7::Day7>>::default()),
+        ("day8", Box::<Solved<day8::Day8>>::default()),
+        ("day9", Box::<Solved<day9::Day9>>::default()),
+        ("day10", Box::<Solved<day10::Day10>>::default()),
+        ("day11", Box::<Solved<day11::Day11>>::default()),
+        ("day12", Box::<Solved<day12::Day12>>::default()),
+        ("day13", Box::<Solved<day13::Day13>>::default()),
+        ("day14", Box::<Solved<day14::Day14>>::default()),
+        ("day15", Box::<Solved<day15::Day15>>::default()),
+        ("day16", Box::<Solved<day16::Day16>>::default()),
+        ("day17", Box::<Solved<day17::Day17>>::default()),
+        ("day18", Box::<Solved<day18::Day18>>::default()),
+        ("day19", Box::<Solved<day19::Day19>>::default()),
+        ("day20", Box::<Solved<day20::Day20>>::default()),
+        ("day21", Box::<Solved<day21::Day21>>::default()),
+        ("day22", Box::<Solved<day22::Day22>>::default()),
+        ("day23", Box::<Solved<day23::Day23>>::default()),
+        ("day24", Box::<Solved<day24::Day24>>::default()),
+        ("day25", Box::<Solved<day25::Day25>>::default()),
+    ]
+}
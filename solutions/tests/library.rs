@@ -0,0 +1,52 @@
+// The solutions used from outside the crate, through the library API
+use advent2021::answer::Answer;
+use advent2021::solution::{self, Solver};
+use advent2021::year2021::{day1, day13, day16};
+
+#[test]
+fn test_day_functions() {
+    let depths = day1::parse_depths("199\n200\n208\n210\n200\n207\n240\n269\n260\n263").unwrap();
+    assert_eq!(7, day1::count_increases(&depths));
+    assert_eq!(5, day1::count_rolling(&depths));
+    assert_eq!(16, day16::parse_hex_packet("8A004A801A8002F478").unwrap().count_version());
+}
+
+#[test]
+fn test_solver() {
+    assert_eq!((7, 5), solution::solve::<day1::Day1>("199\n200\n208\n210\n200\n207\n240\n269\n260\n263").unwrap());
+    // the same input saved on Windows, with a byte order mark and a blank line at the end
+    assert_eq!((7, 5), solution::solve::<day1::Day1>("\u{feff}199\r\n200\r\n208\r\n210\r\n200\r\n207\r\n240\r\n269\r\n260\r\n263\r\n\r\n").unwrap());
+    let packet = day16::Day16::parse("9C0141080250320F1802104A08").unwrap();
+    assert_eq!(Answer::BigInt(1), day16::Day16::solve_part2(&packet));
+    assert_eq!("Transparent Origami", day13::Day13::TITLE);
+}
+
+#[test]
+fn test_registry() {
+    let mut registry = solution::registry(2021).unwrap();
+    assert_eq!(25, registry.len());
+    let (name, day1) = &mut registry[0];
+    assert_eq!("day1", *name);
+    // input paths are from the top of the workspace, and the tests run in the solutions directory
+    day1.parse(&format!("../{}", day1.example_input())).unwrap();
+    assert_eq!("7", day1.part1().to_string());
+}
+
+#[test]
+fn test_embedded_inputs() {
+    let input = solution::embedded_input("solutions/src/year2021/day1/example.txt");
+    if cfg!(feature = "embed-inputs") {
+        assert!(input.unwrap().starts_with("199"));
+    } else {
+        assert!(input.is_none());
+    }
+}
+
+#[test]
+fn test_solve_input() {
+    let depths = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+    assert_eq!(Answer::Int(5), solution::solve_input(2021, "day1", 2, depths).unwrap());
+    assert_eq!("2021 has no day26 part 1", solution::solve_input(2021, "day26", 1, depths).unwrap_err().to_string());
+    assert!(solution::solve_input(2021, "day1", 3, depths).is_err());
+    assert!(solution::solve_input(2021, "day1", 1, "199\nfish").is_err());
+}